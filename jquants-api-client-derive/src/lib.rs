@@ -0,0 +1,335 @@
+//! Derive macros for jquants-api-client.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Field, Fields, GenericArgument, Ident, PathArguments,
+    Type,
+};
+
+/// Derive `HasPaginationKey`, `HasItems` and `MergePage` for a paginated response.
+///
+/// Mark the list of items with `#[paginated(items)]` and the pagination key with `#[paginated(pagination_key)]`.
+/// The items field must be a `Vec<T>` and the pagination key field must be an `Option<String>`.
+///
+/// `MergePage` keeps the pages in their original order.
+/// If there are no pages, it returns an empty response whose other fields are `Default::default()`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Debug, Clone, PartialEq, Deserialize, Paginated)]
+/// pub struct IndicesResponse {
+///     #[paginated(items)]
+///     pub indices: Vec<IndexItem>,
+///     #[paginated(pagination_key)]
+///     pub pagination_key: Option<String>,
+/// }
+/// ```
+#[proc_macro_derive(Paginated, attributes(paginated))]
+pub fn derive_paginated(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_paginated(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_paginated(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "Paginated can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "Paginated can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut items_field = None;
+    let mut key_field = None;
+    for field in fields {
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("paginated"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("items") {
+                    items_field = Some(field);
+                    Ok(())
+                } else if meta.path.is_ident("pagination_key") {
+                    key_field = Some(field);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `items` or `pagination_key`"))
+                }
+            })?;
+        }
+    }
+    let items_field = items_field
+        .ok_or_else(|| syn::Error::new_spanned(name, "missing `#[paginated(items)]` field"))?;
+    let key_field = key_field.ok_or_else(|| {
+        syn::Error::new_spanned(name, "missing `#[paginated(pagination_key)]` field")
+    })?;
+
+    let items = field_ident(items_field);
+    let key = field_ident(key_field);
+    let item_type = vec_item_type(&items_field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(
+            &items_field.ty,
+            "`#[paginated(items)]` field must be a Vec<T>",
+        )
+    })?;
+    let other_fields = fields
+        .iter()
+        .map(field_ident)
+        .filter(|ident| *ident != items && *ident != key);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::jquants_api_client::HasPaginationKey for #name #ty_generics #where_clause {
+            fn get_pagination_key(&self) -> Option<&str> {
+                self.#key.as_deref()
+            }
+        }
+
+        impl #impl_generics ::jquants_api_client::HasItems for #name #ty_generics #where_clause {
+            type Item = #item_type;
+
+            fn items(&self) -> &[Self::Item] {
+                &self.#items
+            }
+
+            fn into_items(self) -> Vec<Self::Item> {
+                self.#items
+            }
+        }
+
+        impl #impl_generics ::jquants_api_client::MergePage for #name #ty_generics #where_clause {
+            fn merge_page(
+                page: Result<Vec<Self>, ::jquants_api_client::JQuantsError>,
+            ) -> Result<Self, ::jquants_api_client::JQuantsError> {
+                let mut pages = page?.into_iter();
+                let mut merged = match pages.next() {
+                    Some(first) => first,
+                    None => Self {
+                        #items: Vec::new(),
+                        #key: None,
+                        #(#other_fields: Default::default(),)*
+                    },
+                };
+                for p in pages {
+                    merged.#items.extend(p.#items);
+                }
+                merged.#key = None;
+
+                Ok(merged)
+            }
+        }
+    })
+}
+
+/// Derive typed accessors for the date, time and decimal fields of a response item.
+///
+/// The fields keep the values of the API, and the accessors are generated behind the features of
+/// `jquants-api-client`, so enabling a feature does not change the types of the fields.
+/// With the `chrono-types` feature, a field of `ResponseDate` or `ResponseTime` gets
+/// `<field>_parsed()`, which parses it into `chrono::NaiveDate` or `chrono::NaiveTime`.
+/// With the `decimal` feature, a field of `ResponseDecimal` gets `<field>_decimal()`,
+/// which converts it into `rust_decimal::Decimal`.
+/// The accessor of an `Option` field returns `Result<Option<_>, _>`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Debug, Clone, PartialEq, Deserialize, ResponseAccessors)]
+/// pub struct TradingCalendarItem {
+///     #[serde(rename = "Date")]
+///     pub date: ResponseDate,
+/// }
+///
+/// let date: chrono::NaiveDate = item.date_parsed()?;
+/// ```
+#[proc_macro_derive(ResponseAccessors)]
+pub fn derive_response_accessors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_response_accessors(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_response_accessors(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "ResponseAccessors can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "ResponseAccessors can only be derived for structs",
+            ))
+        }
+    };
+
+    let accessors = fields.iter().filter_map(|field| {
+        let (kind, optional) = accessor_kind(&field.ty)?;
+        let ident = field_ident(field);
+        let Accessor {
+            feature,
+            suffix,
+            value,
+            error,
+            parse,
+        } = kind.accessor();
+        let accessor = Ident::new(&format!("{ident}_{suffix}"), ident.span());
+        let doc = format!("Get `{ident}` as [`{value}`].");
+        let value: TokenStream2 = format!("::{value}").parse().unwrap();
+        let error: TokenStream2 = format!("::{error}").parse().unwrap();
+        let parse: TokenStream2 = format!("::jquants_api_client::{parse}").parse().unwrap();
+
+        Some(if optional {
+            quote! {
+                #[cfg(feature = #feature)]
+                #[doc = #doc]
+                pub fn #accessor(&self) -> ::core::result::Result<::core::option::Option<#value>, #error> {
+                    self.#ident.as_ref().map(|value| #parse(value)).transpose()
+                }
+            }
+        } else {
+            quote! {
+                #[cfg(feature = #feature)]
+                #[doc = #doc]
+                pub fn #accessor(&self) -> ::core::result::Result<#value, #error> {
+                    #parse(&self.#ident)
+                }
+            }
+        })
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#accessors)*
+        }
+    })
+}
+
+/// Kind of the field that gets an accessor.
+enum AccessorKind {
+    Date,
+    Time,
+    Decimal,
+}
+
+/// Accessor of a kind of field.
+struct Accessor {
+    /// Feature of `jquants-api-client` that enables the accessor.
+    feature: &'static str,
+    /// Suffix of the accessor name.
+    suffix: &'static str,
+    /// Type of the value returned by the accessor.
+    value: &'static str,
+    /// Type of the error returned by the accessor.
+    error: &'static str,
+    /// Function that parses the field, relative to `jquants_api_client`.
+    parse: &'static str,
+}
+
+impl AccessorKind {
+    fn accessor(&self) -> Accessor {
+        match self {
+            AccessorKind::Date => Accessor {
+                feature: "chrono-types",
+                suffix: "parsed",
+                value: "chrono::NaiveDate",
+                error: "chrono::ParseError",
+                parse: "api::shared::types::date_time::parse_date",
+            },
+            AccessorKind::Time => Accessor {
+                feature: "chrono-types",
+                suffix: "parsed",
+                value: "chrono::NaiveTime",
+                error: "chrono::ParseError",
+                parse: "api::shared::types::date_time::parse_time",
+            },
+            AccessorKind::Decimal => Accessor {
+                feature: "decimal",
+                suffix: "decimal",
+                value: "rust_decimal::Decimal",
+                error: "rust_decimal::Error",
+                parse: "api::shared::types::decimal::to_decimal",
+            },
+        }
+    }
+}
+
+/// Get the kind of `T` or `Option<T>`, and whether it is an `Option`.
+fn accessor_kind(ty: &Type) -> Option<(AccessorKind, bool)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let kind = if segment.ident == "ResponseDate" {
+        AccessorKind::Date
+    } else if segment.ident == "ResponseTime" {
+        AccessorKind::Time
+    } else if segment.ident == "ResponseDecimal" {
+        AccessorKind::Decimal
+    } else if segment.ident == "Option" {
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        let GenericArgument::Type(ty) = args.args.first()? else {
+            return None;
+        };
+        let (kind, _) = accessor_kind(ty)?;
+        return Some((kind, true));
+    } else {
+        return None;
+    };
+    Some((kind, false))
+}
+
+fn field_ident(field: &Field) -> &Ident {
+    field
+        .ident
+        .as_ref()
+        .expect("named fields always have an identifier")
+}
+
+/// Get `T` of `Vec<T>`.
+fn vec_item_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
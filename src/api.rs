@@ -13,6 +13,7 @@ pub mod indicies;
 pub mod listed_issue_info;
 pub mod morning_session_stock_prices;
 pub mod options_prices;
+pub mod query;
 pub mod shared;
 pub mod short_sale_by_sector;
 pub mod topic_prices;
@@ -21,10 +22,19 @@ pub mod trading_calendar;
 pub mod weekly_margin_trading_outstandings;
 
 use shared::{
-    auth::{get_id_token_from_api, get_refresh_token_from_api},
+    auth::{
+        get_id_token_from_api, get_refresh_token_from_api,
+        secret::SecretString,
+        token_status::{TokenKind, TokenRefreshAttempt, TokenRefreshResult, TokenStatus},
+    },
+    deserialize_utils::from_json_str,
     responses::error_response::JQuantsErrorResponse,
+    unknown_variants::{self, UnknownVariantMode, UnknownVariantReport},
+};
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
 };
-use std::{fmt, sync::Arc};
 use tokio::sync::RwLock;
 
 use crate::error::JQuantsError;
@@ -48,24 +58,43 @@ fn build_url(path: &str) -> String {
     format!("{}/{}", BASE_URL, path)
 }
 
+/// Concatenate the base URL, the path and the query string serialized from `params`.
+///
+/// Serializing the params fails only for an invalid date or issue code.
+pub(crate) fn build_request_url(
+    path: &str,
+    params: &(impl Serialize + ?Sized),
+) -> Result<String, JQuantsError> {
+    let url = build_url(path);
+    let query = serde_urlencoded::to_string(params)
+        .map_err(|e| JQuantsError::InvalidQueryParameter(e.to_string()))?;
+    if query.is_empty() {
+        Ok(url)
+    } else {
+        Ok(format!("{url}?{query}"))
+    }
+}
+
 /// J-Quants API client trait
 pub trait JQuantsPlanClient: Clone {
     /// Create a new client from an API client.
     fn new(api_client: JQuantsApiClient) -> Self;
 
     /// Create a new client from a refresh token.
-    fn new_from_refresh_token(refresh_token: String) -> Self {
-        let api_client = JQuantsApiClient::new_from_refresh_token(refresh_token);
+    fn new_from_refresh_token(refresh_token: impl Into<SecretString>) -> Self {
+        let api_client = JQuantsApiClient::new_from_refresh_token(refresh_token.into());
         Self::new(api_client)
     }
 
     /// Create a new client from an account.
     fn new_from_account(
-        mailaddress: &str,
-        password: &str,
+        mailaddress: impl Into<SecretString>,
+        password: impl Into<SecretString>,
     ) -> impl std::future::Future<Output = Result<Self, JQuantsError>> + Send {
-        async {
-            let api_client = JQuantsApiClient::new_from_account(mailaddress, password).await?;
+        let mailaddress = mailaddress.into();
+        let password = password.into();
+        async move {
+            let api_client = JQuantsApiClient::new_from_account(&mailaddress, &password).await?;
             Ok(Self::new(api_client))
         }
     }
@@ -74,7 +103,7 @@ pub trait JQuantsPlanClient: Clone {
     fn get_api_client(&self) -> &JQuantsApiClient;
 
     /// Get a current refresh token.
-    fn get_current_refresh_token(&self) -> impl std::future::Future<Output = String> + Send {
+    fn get_current_refresh_token(&self) -> impl std::future::Future<Output = SecretString> + Send {
         let api_client = self.get_api_client().clone();
         async move {
             api_client
@@ -87,17 +116,29 @@ pub trait JQuantsPlanClient: Clone {
         }
     }
 
+    /// Get the authentication state of the client.
+    ///
+    /// The returned status never contains secret values.
+    fn token_status(&self) -> impl std::future::Future<Output = TokenStatus> + Send {
+        let api_client = self.get_api_client().clone();
+        async move { api_client.inner.token_set.read().await.status() }
+    }
+
     /// Get a new refresh token from an account.
     /// But don't update the ID token in the client.
     ///
     /// Use `refresh_refresh_token` if you want to update the refresh token in the client.
     fn get_refresh_token_from_api(
         &self,
-        mail_address: &str,
-        password: &str,
-    ) -> impl std::future::Future<Output = Result<String, JQuantsError>> + Send {
+        mail_address: impl Into<SecretString>,
+        password: impl Into<SecretString>,
+    ) -> impl std::future::Future<Output = Result<SecretString, JQuantsError>> + Send {
         let api_client = self.get_api_client().clone();
-        async move { get_refresh_token_from_api(&api_client.inner.client, mail_address, password).await }
+        let mail_address = mail_address.into();
+        let password = password.into();
+        async move {
+            get_refresh_token_from_api(&api_client.inner.client, &mail_address, &password).await
+        }
     }
 
     /// Get a new ID token from a refresh token.
@@ -106,23 +147,26 @@ pub trait JQuantsPlanClient: Clone {
     /// Use `refresh_id_token` if you want to update the ID token in the client.
     fn get_id_token_from_api(
         &self,
-        refresh_token: &str,
-    ) -> impl std::future::Future<Output = Result<String, JQuantsError>> + Send {
+        refresh_token: impl Into<SecretString>,
+    ) -> impl std::future::Future<Output = Result<SecretString, JQuantsError>> + Send {
         let api_client = self.get_api_client().clone();
-        async move { get_id_token_from_api(&api_client.inner.client, refresh_token).await }
+        let refresh_token = refresh_token.into();
+        async move { get_id_token_from_api(&api_client.inner.client, &refresh_token).await }
     }
 
     /// Renew the refresh token in the client.
     fn reset_refresh_token(
         &self,
-        mail_address: &str,
-        password: &str,
+        mail_address: impl Into<SecretString>,
+        password: impl Into<SecretString>,
     ) -> impl std::future::Future<Output = Result<(), JQuantsError>> + Send {
         let api_client = self.get_api_client().clone();
+        let mail_address = mail_address.into();
+        let password = password.into();
         async move {
             api_client
                 .inner
-                .reset_refresh_token(mail_address, password)
+                .reset_refresh_token(&mail_address, &password)
                 .await
         }
     }
@@ -133,14 +177,41 @@ pub trait JQuantsPlanClient: Clone {
         async move { api_client.inner.reset_id_token().await }
     }
 
+    /// Set how unknown variants of enums in responses are handled.
+    ///
+    /// The mode is shared with the clones of this client.
+    fn set_unknown_variant_mode(&self, mode: UnknownVariantMode) {
+        *lock(&self.get_api_client().inner.unknown_variant_mode) = mode;
+    }
+
+    /// Get the unknown variants collected in [`UnknownVariantMode::Diagnostics`].
+    fn unknown_variant_report(&self) -> UnknownVariantReport {
+        lock(&self.get_api_client().inner.unknown_variant_report).clone()
+    }
+
+    /// Take the unknown variants collected in [`UnknownVariantMode::Diagnostics`],
+    /// leaving an empty report in the client.
+    fn take_unknown_variant_report(&self) -> UnknownVariantReport {
+        std::mem::take(&mut *lock(
+            &self.get_api_client().inner.unknown_variant_report,
+        ))
+    }
+
     /// Reauthenticate with a new refresh token and a new id token.
     fn reauthenticate(
         &self,
-        mail_address: &str,
-        password: &str,
+        mail_address: impl Into<SecretString>,
+        password: impl Into<SecretString>,
     ) -> impl std::future::Future<Output = Result<(), JQuantsError>> + Send {
         let api_client = self.get_api_client().clone();
-        async move { api_client.inner.reset_tokens(mail_address, password).await }
+        let mail_address = mail_address.into();
+        let password = password.into();
+        async move {
+            api_client
+                .inner
+                .reset_tokens(&mail_address, &password)
+                .await
+        }
     }
 }
 
@@ -151,14 +222,17 @@ pub struct JQuantsApiClient {
 }
 impl JQuantsApiClient {
     /// Create a new client from a refresh token.
-    fn new_from_refresh_token(refresh_token: String) -> Self {
+    fn new_from_refresh_token(refresh_token: SecretString) -> Self {
         Self {
             inner: Arc::new(JQuantsApiClientRef::new_from_refresh_token(refresh_token)),
         }
     }
 
     /// Create a new client from an account.
-    async fn new_from_account(mailaddress: &str, password: &str) -> Result<Self, JQuantsError> {
+    async fn new_from_account(
+        mailaddress: &SecretString,
+        password: &SecretString,
+    ) -> Result<Self, JQuantsError> {
         let client_ref = JQuantsApiClientRef::new_from_account(mailaddress, password).await?;
         Ok(Self {
             inner: Arc::new(client_ref),
@@ -174,22 +248,33 @@ pub(crate) struct JQuantsApiClientRef {
     client: Client,
     /// Refresh token and ID token
     token_set: Arc<RwLock<TokenSet>>,
+    /// How unknown variants of enums in responses are handled
+    unknown_variant_mode: Mutex<UnknownVariantMode>,
+    /// Unknown variants collected in the diagnostics mode
+    unknown_variant_report: Mutex<UnknownVariantReport>,
 }
 
 impl JQuantsApiClientRef {
     /// Create a new client from a refresh token.
-    fn new_from_refresh_token(refresh_token: String) -> Self {
+    fn new_from_refresh_token(refresh_token: SecretString) -> Self {
         Self {
             client: Client::new(),
             token_set: Arc::new(RwLock::new(TokenSet {
                 refresh_token,
+                refresh_token_obtained_at: None,
                 id_token: None,
+                last_refresh: None,
             })),
+            unknown_variant_mode: Mutex::default(),
+            unknown_variant_report: Mutex::default(),
         }
     }
 
     /// Create a new client from an account.
-    async fn new_from_account(mailaddress: &str, password: &str) -> Result<Self, JQuantsError> {
+    async fn new_from_account(
+        mailaddress: &SecretString,
+        password: &SecretString,
+    ) -> Result<Self, JQuantsError> {
         let client = Client::new();
         let refresh_token = get_refresh_token_from_api(&client, mailaddress, password).await?;
         let new_id_token = get_id_token_from_api(&client, &refresh_token).await?;
@@ -200,28 +285,37 @@ impl JQuantsApiClientRef {
             client,
             token_set: Arc::new(RwLock::new(TokenSet {
                 refresh_token,
+                refresh_token_obtained_at: Some(Local::now()),
                 id_token: Some(id_token_wrapper),
+                last_refresh: None,
             })),
+            unknown_variant_mode: Mutex::default(),
+            unknown_variant_report: Mutex::default(),
         })
     }
 
     /// Get a new refresh token from an account.
     async fn reset_refresh_token(
         &self,
-        mail_address: &str,
-        password: &str,
+        mail_address: &SecretString,
+        password: &SecretString,
     ) -> Result<(), JQuantsError> {
         tracing::debug!("Starting reset a refresh token process.");
 
         match get_refresh_token_from_api(&self.client, mail_address, password).await {
             Ok(new_refresh_token) => {
                 let mut token_set_write = self.token_set.write().await;
-                token_set_write.refresh_token = new_refresh_token;
+                token_set_write.set_refresh_token(new_refresh_token);
+                token_set_write.record_refresh(TokenKind::RefreshToken, Ok(()));
                 tracing::debug!("Refresh token refreshed successfully.");
                 Ok(())
             }
             Err(e) => {
                 tracing::error!("Failed to refresh a refresh token: {:?}", e);
+                self.token_set
+                    .write()
+                    .await
+                    .record_refresh(TokenKind::RefreshToken, Err(&e));
                 Err(e)
             }
         }
@@ -236,11 +330,16 @@ impl JQuantsApiClientRef {
             Ok(new_id_token) => {
                 let mut token_set_write = self.token_set.write().await;
                 token_set_write.id_token = Some(IdTokenWrapper::new(new_id_token));
+                token_set_write.record_refresh(TokenKind::IdToken, Ok(()));
                 tracing::debug!("ID token refreshed successfully.");
                 Ok(())
             }
             Err(e) => {
                 tracing::error!("Failed to refresh ID token: {:?}", e);
+                self.token_set
+                    .write()
+                    .await
+                    .record_refresh(TokenKind::IdToken, Err(&e));
                 Err(e)
             }
         }
@@ -266,24 +365,39 @@ impl JQuantsApiClientRef {
     }
 
     /// Reauthenticate with a new refresh token and a new id token.
-    async fn reset_tokens(&self, mail_address: &str, password: &str) -> Result<(), JQuantsError> {
+    async fn reset_tokens(
+        &self,
+        mail_address: &SecretString,
+        password: &SecretString,
+    ) -> Result<(), JQuantsError> {
         tracing::debug!("Starting re-authentication process.");
 
         // 再認証して新しいrefresh_tokenとid_tokenを取得
-        let new_refresh_token = get_refresh_token_from_api(&self.client, mail_address, password)
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to obtain new refresh token: {:?}", e);
-                e
-            })?;
+        let new_refresh_token =
+            match get_refresh_token_from_api(&self.client, mail_address, password).await {
+                Ok(new_refresh_token) => new_refresh_token,
+                Err(e) => {
+                    tracing::error!("Failed to obtain new refresh token: {:?}", e);
+                    self.token_set
+                        .write()
+                        .await
+                        .record_refresh(TokenKind::RefreshToken, Err(&e));
+                    return Err(e);
+                }
+            };
         tracing::debug!("Successfully obtained new refresh token.");
 
-        let new_id_token = get_id_token_from_api(&self.client, &new_refresh_token)
-            .await
-            .map_err(|e| {
+        let new_id_token = match get_id_token_from_api(&self.client, &new_refresh_token).await {
+            Ok(new_id_token) => new_id_token,
+            Err(e) => {
                 tracing::error!("Failed to obtain new ID token: {:?}", e);
-                e
-            })?;
+                self.token_set
+                    .write()
+                    .await
+                    .record_refresh(TokenKind::IdToken, Err(&e));
+                return Err(e);
+            }
+        };
         tracing::debug!("Successfully obtained new ID token.");
 
         let expires_at = Local::now() + chrono::Duration::hours(24);
@@ -293,8 +407,9 @@ impl JQuantsApiClientRef {
         });
         {
             let mut token_set_write = self.token_set.write().await;
-            token_set_write.refresh_token = new_refresh_token;
+            token_set_write.set_refresh_token(new_refresh_token);
             token_set_write.id_token = new_id_token_wrapper;
+            token_set_write.record_refresh(TokenKind::IdToken, Ok(()));
         }
 
         tracing::debug!("Re-authentication process process completed successfully.");
@@ -310,8 +425,7 @@ impl JQuantsApiClientRef {
         path: &str,
         params: impl Serialize,
     ) -> Result<T, JQuantsError> {
-        let url = format!("{BASE_URL}/{}", path);
-        let request = self.client.get(&url).query(&params);
+        let request = self.client.get(build_request_url(path, &params)?);
 
         self.common_send_and_refresh_token_if_needed::<T>(request)
             .await
@@ -345,7 +459,11 @@ impl JQuantsApiClientRef {
                 .id_token
                 .clone()
         };
-        let request = request.header("Authorization", &format!("Bearer {id_token}"));
+        let authorization = id_token.to_bearer_header().map_err(|_| {
+            tracing::error!("ID token is not a valid header value.");
+            JQuantsError::BugError("ID token is not a valid header value".to_string())
+        })?;
+        let request = request.header(reqwest::header::AUTHORIZATION, authorization);
 
         if let Some(url) = request
             .try_clone()
@@ -362,21 +480,9 @@ impl JQuantsApiClientRef {
         tracing::debug!("Received response with status: {}", status);
 
         if status.is_success() {
-            match serde_json::from_str::<T>(&text) {
-                Ok(data) => {
-                    tracing::debug!("Successfully parsed response.");
-                    Ok(data)
-                }
-                Err(_) => {
-                    tracing::error!("Failed to parse response");
-                    Err(JQuantsError::InvalidResponseFormat {
-                        status_code: status.as_u16(),
-                        body: text,
-                    })
-                }
-            }
+            self.parse_response(status.as_u16(), text)
         } else {
-            match serde_json::from_str::<JQuantsErrorResponse>(&text) {
+            match from_json_str::<JQuantsErrorResponse>(&text) {
                 Ok(error_response) => match status {
                     reqwest::StatusCode::UNAUTHORIZED => {
                         tracing::warn!(
@@ -396,11 +502,12 @@ impl JQuantsApiClientRef {
                         })
                     }
                 },
-                Err(_) => {
+                Err(source) => {
                     tracing::error!("Invalid response format. Status code: {}", status.as_u16());
                     Err(JQuantsError::InvalidResponseFormat {
                         status_code: status.as_u16(),
                         body: text,
+                        source,
                     })
                 }
             }
@@ -408,29 +515,127 @@ impl JQuantsApiClientRef {
     }
 }
 
+impl JQuantsApiClientRef {
+    /// Parse a successful response according to the unknown variant mode.
+    fn parse_response<T: DeserializeOwned + fmt::Debug>(
+        &self,
+        status_code: u16,
+        text: String,
+    ) -> Result<T, JQuantsError> {
+        let mode = *lock(&self.unknown_variant_mode);
+        let (result, report) = match mode {
+            UnknownVariantMode::Lenient => {
+                (from_json_str::<T>(&text), UnknownVariantReport::default())
+            }
+            UnknownVariantMode::Strict | UnknownVariantMode::Diagnostics => {
+                unknown_variants::collect(|| from_json_str::<T>(&text))
+            }
+        };
+
+        let data = match result {
+            Ok(data) => data,
+            Err(source) => {
+                tracing::error!("Failed to parse response: {source}");
+                return Err(JQuantsError::InvalidResponseFormat {
+                    status_code,
+                    body: text,
+                    source,
+                });
+            }
+        };
+
+        if !report.is_empty() {
+            match mode {
+                UnknownVariantMode::Strict => {
+                    tracing::error!("Unknown variants in response: {report}");
+                    return Err(JQuantsError::UnknownVariants(report));
+                }
+                UnknownVariantMode::Diagnostics => {
+                    tracing::warn!("Unknown variants in response: {report}");
+                    lock(&self.unknown_variant_report).merge(report);
+                }
+                UnknownVariantMode::Lenient => {}
+            }
+        }
+
+        tracing::debug!("Successfully parsed response.");
+        Ok(data)
+    }
+}
+
+/// Lock a mutex, ignoring poisoning since the values are always valid.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
 /// Token set
 ///
 /// The refresh token is valid for one week and the ID token is valid for 24 hours.
 pub(crate) struct TokenSet {
     /// Refresh token
     /// Use this token to refresh the ID token.
-    refresh_token: String,
+    refresh_token: SecretString,
+    /// Time when the refresh token was obtained by this client.
+    /// `None` if the refresh token was given from outside.
+    refresh_token_obtained_at: Option<DateTime<Local>>,
     /// ID token
     id_token: Option<IdTokenWrapper>,
+    /// Last refresh attempt
+    last_refresh: Option<TokenRefreshAttempt>,
+}
+
+impl TokenSet {
+    /// Replace the refresh token with a newly obtained one.
+    fn set_refresh_token(&mut self, refresh_token: SecretString) {
+        self.refresh_token = refresh_token;
+        self.refresh_token_obtained_at = Some(Local::now());
+    }
+
+    /// Record the result of a refresh attempt.
+    fn record_refresh(&mut self, kind: TokenKind, result: Result<(), &JQuantsError>) {
+        let result = match result {
+            Ok(()) => TokenRefreshResult::Succeeded,
+            Err(e) => TokenRefreshResult::Failed(e.to_string()),
+        };
+        self.last_refresh = Some(TokenRefreshAttempt {
+            kind,
+            attempted_at: Local::now(),
+            result,
+        });
+    }
+
+    /// Build a snapshot of the authentication state without secret values.
+    fn status(&self) -> TokenStatus {
+        let now = Local::now();
+        let id_token_expires_at = self.id_token.as_ref().map(|token| token.expires_at);
+
+        TokenStatus {
+            has_id_token: self.id_token.is_some(),
+            id_token_expires_at,
+            id_token_remaining: id_token_expires_at
+                .map(|expires_at| (expires_at - now).max(chrono::TimeDelta::zero())),
+            refresh_token_obtained_at: self.refresh_token_obtained_at,
+            refresh_token_expires_at: self
+                .refresh_token_obtained_at
+                .map(|obtained_at| obtained_at + chrono::Duration::weeks(1)),
+            last_refresh: self.last_refresh.clone(),
+        }
+    }
 }
 
 /// ID Token wrapper
 ///
 /// The ID token is valid for 24 hours.
+#[derive(Debug)]
 pub(crate) struct IdTokenWrapper {
     /// ID Token
-    id_token: String,
+    id_token: SecretString,
     /// ID Token expiration time
     expires_at: DateTime<Local>,
 }
 impl IdTokenWrapper {
     /// Create a new ID token wrapper.
-    fn new(id_token: String) -> Self {
+    fn new(id_token: SecretString) -> Self {
         let expires_at = Local::now() + chrono::Duration::hours(24);
         IdTokenWrapper {
             id_token,
@@ -447,16 +652,230 @@ impl IdTokenWrapper {
     }
 }
 
-/// Mask the ID token for security reasons.
-/// If you want to display the ID token, do so at your own risk.
-impl fmt::Debug for IdTokenWrapper {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let len = self.id_token.len();
-        let masking_id_token = "*".repeat(len);
-
-        f.debug_struct("IdTokenWrapper")
-            .field("id_token", &masking_id_token)
-            .field("expires_at", &self.expires_at)
-            .finish()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_status_from_refresh_token() {
+        let token_set = TokenSet {
+            refresh_token: SecretString::new("refresh-token"),
+            refresh_token_obtained_at: None,
+            id_token: None,
+            last_refresh: None,
+        };
+
+        let status = token_set.status();
+        assert!(!status.has_id_token);
+        assert!(!status.is_id_token_valid());
+        assert_eq!(status.id_token_expires_at, None);
+        assert_eq!(status.id_token_remaining, None);
+        assert_eq!(status.refresh_token_expires_at, None);
+        assert_eq!(status.last_refresh, None);
+    }
+
+    #[test]
+    fn test_token_status_after_refresh() {
+        let mut token_set = TokenSet {
+            refresh_token: SecretString::new("refresh-token"),
+            refresh_token_obtained_at: None,
+            id_token: None,
+            last_refresh: None,
+        };
+        token_set.set_refresh_token(SecretString::new("new-refresh-token"));
+        token_set.id_token = Some(IdTokenWrapper::new(SecretString::new("id-token")));
+        token_set.record_refresh(
+            TokenKind::IdToken,
+            Err(&JQuantsError::BugError("failure".to_string())),
+        );
+
+        let status = token_set.status();
+        assert!(status.has_id_token);
+        assert!(status.is_id_token_valid());
+        assert_eq!(
+            status.refresh_token_expires_at.unwrap() - status.refresh_token_obtained_at.unwrap(),
+            chrono::Duration::weeks(1)
+        );
+
+        let last_refresh = status.last_refresh.unwrap();
+        assert_eq!(last_refresh.kind, TokenKind::IdToken);
+        assert_eq!(
+            last_refresh.result,
+            TokenRefreshResult::Failed("BUG: failure. Please report this issue.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_response_with_unknown_variant_modes() {
+        use crate::{JQuantsFreePlanClient, TradingCalendarResponse};
+
+        let body = r#"{"trading_calendar": [
+            {"Date": "2015-04-01", "HolidayDivision": "1"},
+            {"Date": "2015-04-02", "HolidayDivision": "9"}
+        ]}"#;
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+        let inner = &client.get_api_client().inner;
+
+        let result = inner.parse_response::<TradingCalendarResponse>(200, body.to_string());
+        assert!(result.is_ok());
+        assert!(client.unknown_variant_report().is_empty());
+
+        client.set_unknown_variant_mode(UnknownVariantMode::Diagnostics);
+        for _ in 0..2 {
+            let result = inner.parse_response::<TradingCalendarResponse>(200, body.to_string());
+            assert!(result.is_ok());
+        }
+        let report = client.take_unknown_variant_report();
+        assert_eq!(report.count("HolidayDivision", "9"), 2);
+        assert!(client.unknown_variant_report().is_empty());
+
+        client.set_unknown_variant_mode(UnknownVariantMode::Strict);
+        let result = inner.parse_response::<TradingCalendarResponse>(200, body.to_string());
+        match result {
+            Err(JQuantsError::UnknownVariants(report)) => {
+                assert_eq!(report.count("HolidayDivision", "9"), 1)
+            }
+            _ => panic!("Expected UnknownVariants, got {:?}", result),
+        }
+        assert!(client.unknown_variant_report().is_empty());
+    }
+
+    #[test]
+    fn test_parse_response_reports_error_path() {
+        use crate::{JQuantsFreePlanClient, TradingCalendarResponse};
+
+        let body = r#"{"trading_calendar": [
+            {"Date": "2015-04-01", "HolidayDivision": "1"},
+            {"Date": 20150402, "HolidayDivision": "1"}
+        ]}"#;
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+        let result = client
+            .get_api_client()
+            .inner
+            .parse_response::<TradingCalendarResponse>(200, body.to_string());
+
+        match result {
+            Err(JQuantsError::InvalidResponseFormat {
+                status_code,
+                body: full,
+                source,
+            }) => {
+                assert_eq!(status_code, 200);
+                assert_eq!(full, body);
+                assert_eq!(source.path, "trading_calendar[1].Date");
+                assert!(source.message.contains("line 3"), "{}", source.message);
+            }
+            _ => panic!("Expected InvalidResponseFormat, got {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_date_fails_before_request() {
+        use crate::{DailyStockPricesApi, JQuantsBuilder, JQuantsFreePlanClient};
+
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+        let result = client
+            .get_daily_stock_prices()
+            .code("86970")
+            .from("2024-13-01")
+            .send()
+            .await;
+
+        match result {
+            Err(JQuantsError::InvalidQueryParameter(message)) => {
+                assert!(message.contains("2024-13-01"))
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_date_checkpoint_matches_send() {
+        use crate::{DailyStockPricesApi, JQuantsBuilder, JQuantsFreePlanClient, Paginatable};
+
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+        let builder = client
+            .get_daily_stock_prices()
+            .code("86970")
+            .from("2024-13-01");
+
+        let checkpoint_message = match builder.checkpoint() {
+            Err(JQuantsError::InvalidQueryParameter(message)) => message,
+            other => panic!("unexpected result: {other:?}"),
+        };
+        match builder.send().await {
+            Err(JQuantsError::InvalidQueryParameter(message)) => {
+                assert_eq!(message, checkpoint_message)
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_with_plan_client_builder() {
+        use crate::{DailyStockPricesApi, JQuantsFreePlanClient, Paginatable};
+        use futures::StreamExt;
+
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+        let results: Vec<_> = client
+            .get_daily_stock_prices()
+            .fetch_pages_stream_prefetch(2)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(JQuantsError::InvalidParameters(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_issue_code_fails_before_request() {
+        use crate::{FinancialStatementsApi, JQuantsBuilder, JQuantsFreePlanClient};
+
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+        let result = client.get_financial_statements().code("869").send().await;
+
+        match result {
+            Err(JQuantsError::InvalidQueryParameter(message)) => assert!(message.contains("869")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_parameters_fail_before_request() {
+        use crate::{
+            DailyStockPricesApi, FinancialStatementsApi, JQuantsBuilder, JQuantsFreePlanClient,
+        };
+
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+
+        let result = client.get_financial_statements().send().await;
+        match result {
+            Err(JQuantsError::InvalidParameters(message)) => {
+                assert_eq!(message, "`code` or `date` is required")
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        let result = client
+            .get_daily_stock_prices()
+            .code("86970")
+            .from("2024-08-31")
+            .to("2024-08-01")
+            .send()
+            .await;
+        assert!(matches!(result, Err(JQuantsError::InvalidParameters(_))));
+
+        let builder = client
+            .get_daily_stock_prices()
+            .code("86970")
+            .date("2024-08-01")
+            .from("2024-08-01");
+        assert!(matches!(
+            builder.validate(),
+            Err(JQuantsError::InvalidParameters(_))
+        ));
     }
 }
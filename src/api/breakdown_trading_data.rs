@@ -1,14 +1,31 @@
 //! Breakdown Trading Data API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    shared::traits::{
-        builder::JQuantsBuilder,
-        pagination::{HasPaginationKey, MergePage, Paginatable},
+    shared::{
+        traits::{
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            code_batch::CodeBatchable,
+            date_range::DateRangeShardable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
+        },
+        types::{
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
+            issue_code::{
+                serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+            },
+            query_date::{IntoQueryDate, QueryDate},
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Breakdown Trading Data API.
 #[derive(Clone, Serialize)]
@@ -17,30 +34,54 @@ pub struct BreakdownTradingDataBuilder {
     client: JQuantsApiClient,
 
     /// Issue code (e.g. "27890" or "2789")
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
     /// Starting point of data period (e.g. "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g. "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
     /// Date of data (e.g. "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for BreakdownTradingDataBuilder {
+    const PATH: &'static str = "markets/breakdown";
+}
+
 impl JQuantsBuilder<BreakdownTradingDataResponse> for BreakdownTradingDataBuilder {
     async fn send(self) -> Result<BreakdownTradingDataResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[("code", self.code.is_some()), ("date", self.date.is_some())])?;
+        validation::date_excludes_range(self.date.as_ref(), self.from.as_ref(), self.to.as_ref())?;
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<BreakdownTradingDataResponse, crate::JQuantsError> {
-        self.client.inner.get("markets/breakdown", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<BreakdownTradingDataResponse> for BreakdownTradingDataBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<BreakdownTradingDataResponse, crate::JQuantsError>>
+           + Send {
+        self.send_ref()
     }
 }
 
@@ -51,6 +92,21 @@ impl Paginatable<BreakdownTradingDataResponse> for BreakdownTradingDataBuilder {
     }
 }
 
+impl DateRangeShardable<BreakdownTradingDataResponse> for BreakdownTradingDataBuilder {
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
+impl CodeBatchable<BreakdownTradingDataResponse> for BreakdownTradingDataBuilder {
+    fn with_code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+}
+
 impl BreakdownTradingDataBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -65,26 +121,26 @@ impl BreakdownTradingDataBuilder {
     }
 
     /// Set issue code (e.g. "27890" or "2789")
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 
     /// Set starting point of data period (e.g. "20210901" or "2021-09-01")
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g. "20210907" or "2021-09-07")
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 
     /// Set date of data (e.g. "20210907" or "2021-09-07")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
@@ -102,73 +158,54 @@ pub trait BreakdownTradingDataApi: JQuantsPlanClient {
 /// Breakdown Trading Data response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/breakdown)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct BreakdownTradingDataResponse {
     /// List of breakdown trading data
+    #[paginated(items)]
     pub breakdown: Vec<BreakdownTradingDataItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for BreakdownTradingDataResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for BreakdownTradingDataResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.breakdown.extend(p.breakdown);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single breakdown trading data item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct BreakdownTradingDataItem {
     /// Trade date (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Issue code
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: IssueCode,
 
     /// Long selling trading value
     #[serde(rename = "LongSellValue")]
-    pub long_sell_value: f64,
+    pub long_sell_value: ResponseDecimal,
 
     /// Trading value of short selling (excluding new margin sell)
     #[serde(rename = "ShortSellWithoutMarginValue")]
-    pub short_sell_without_margin_value: f64,
+    pub short_sell_without_margin_value: ResponseDecimal,
 
     /// Trading value of new margin selling (sell orders that create new margin sell positions)
     #[serde(rename = "MarginSellNewValue")]
-    pub margin_sell_new_value: f64,
+    pub margin_sell_new_value: ResponseDecimal,
 
     /// Trading value of closing margin selling (sell orders that close existing margin buy positions)
     #[serde(rename = "MarginSellCloseValue")]
-    pub margin_sell_close_value: f64,
+    pub margin_sell_close_value: ResponseDecimal,
 
     /// Long buying trading value
     #[serde(rename = "LongBuyValue")]
-    pub long_buy_value: f64,
+    pub long_buy_value: ResponseDecimal,
 
     /// Trading value of new margin buying (buy orders that create new margin buy positions)
     #[serde(rename = "MarginBuyNewValue")]
-    pub margin_buy_new_value: f64,
+    pub margin_buy_new_value: ResponseDecimal,
 
     /// Trading value of closing margin buying (buy orders that close existing margin sell positions)
     #[serde(rename = "MarginBuyCloseValue")]
-    pub margin_buy_close_value: f64,
+    pub margin_buy_close_value: ResponseDecimal,
 
     /// Long selling trading volume
     #[serde(rename = "LongSellVolume")]
@@ -197,6 +234,11 @@ pub struct BreakdownTradingDataItem {
     /// Trading volume of closing margin buying (buy orders that close existing margin sell positions)
     #[serde(rename = "MarginBuyCloseVolume")]
     pub margin_buy_close_volume: f64,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(feature = "polars")]
@@ -205,7 +247,7 @@ impl BreakdownTradingDataResponse {
     pub fn into_polars(
         self,
     ) -> Result<polars::prelude::DataFrame, crate::polars_utils::IntoPolarsError> {
-        use crate::polars_utils::build_categorical_column;
+        use crate::polars_utils::{build_categorical_column, build_decimal_column};
         use polars::prelude::*;
 
         let data = self.breakdown;
@@ -226,6 +268,8 @@ impl BreakdownTradingDataResponse {
         let mut long_buy_volumes = Vec::with_capacity(data.len());
         let mut margin_buy_new_volumes = Vec::with_capacity(data.len());
         let mut margin_buy_close_volumes = Vec::with_capacity(data.len());
+        #[cfg(feature = "extra-fields")]
+        let mut extras = Vec::with_capacity(data.len());
 
         for item in data {
             dates.push(item.date);
@@ -244,21 +288,23 @@ impl BreakdownTradingDataResponse {
             long_buy_volumes.push(item.long_buy_volume);
             margin_buy_new_volumes.push(item.margin_buy_new_volume);
             margin_buy_close_volumes.push(item.margin_buy_close_volume);
+            #[cfg(feature = "extra-fields")]
+            extras.push(item.extra);
         }
 
-        let df = polars::frame::DataFrame::new(vec![
+        let columns = vec![
             Column::new("Date".into(), dates).cast(&DataType::Date)?,
             build_categorical_column("Code", codes)?,
-            Column::new("LongSellValue".into(), long_sell_values),
-            Column::new(
-                "ShortSellWithoutMarginValue".into(),
+            build_decimal_column("LongSellValue", long_sell_values),
+            build_decimal_column(
+                "ShortSellWithoutMarginValue",
                 short_sell_without_margin_values,
             ),
-            Column::new("MarginSellNewValue".into(), margin_sell_new_values),
-            Column::new("MarginSellCloseValue".into(), margin_sell_close_values),
-            Column::new("LongBuyValue".into(), long_buy_values),
-            Column::new("MarginBuyNewValue".into(), margin_buy_new_values),
-            Column::new("MarginBuyCloseValue".into(), margin_buy_close_values),
+            build_decimal_column("MarginSellNewValue", margin_sell_new_values),
+            build_decimal_column("MarginSellCloseValue", margin_sell_close_values),
+            build_decimal_column("LongBuyValue", long_buy_values),
+            build_decimal_column("MarginBuyNewValue", margin_buy_new_values),
+            build_decimal_column("MarginBuyCloseValue", margin_buy_close_values),
             Column::new("LongSellVolume".into(), long_sell_volumes),
             Column::new(
                 "ShortSellWithoutMarginVolume".into(),
@@ -269,7 +315,14 @@ impl BreakdownTradingDataResponse {
             Column::new("LongBuyVolume".into(), long_buy_volumes),
             Column::new("MarginBuyNewVolume".into(), margin_buy_new_volumes),
             Column::new("MarginBuyCloseVolume".into(), margin_buy_close_volumes),
-        ])?;
+        ];
+        #[cfg(feature = "extra-fields")]
+        let columns = columns
+            .into_iter()
+            .chain(crate::polars_utils::extra_fields_to_columns(extras))
+            .collect::<Vec<_>>();
+
+        let df = polars::frame::DataFrame::new(columns)?;
 
         Ok(df)
     }
@@ -278,6 +331,7 @@ impl BreakdownTradingDataResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_breakdown_trading_data_response() {
@@ -308,17 +362,19 @@ mod tests {
         "#;
 
         let response: BreakdownTradingDataResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = BreakdownTradingDataResponse {
             breakdown: vec![BreakdownTradingDataItem {
-                date: "2015-04-01".to_string(),
-                code: "13010".to_string(),
-                long_sell_value: 115164000.0,
-                short_sell_without_margin_value: 93561000.0,
-                margin_sell_new_value: 6412000.0,
-                margin_sell_close_value: 23009000.0,
-                long_buy_value: 185114000.0,
-                margin_buy_new_value: 35568000.0,
-                margin_buy_close_value: 17464000.0,
+                date: "2015-04-01".parse().unwrap(),
+                code: "13010".parse().unwrap(),
+                long_sell_value: "115164000.0".parse().unwrap(),
+                short_sell_without_margin_value: "93561000.0".parse().unwrap(),
+                margin_sell_new_value: "6412000.0".parse().unwrap(),
+                margin_sell_close_value: "23009000.0".parse().unwrap(),
+                long_buy_value: "185114000.0".parse().unwrap(),
+                margin_buy_new_value: "35568000.0".parse().unwrap(),
+                margin_buy_close_value: "17464000.0".parse().unwrap(),
                 long_sell_volume: 415000.0,
                 short_sell_without_margin_volume: 337000.0,
                 margin_sell_new_volume: 23000.0,
@@ -326,6 +382,8 @@ mod tests {
                 long_buy_volume: 667000.0,
                 margin_buy_new_volume: 128000.0,
                 margin_buy_close_volume: 63000.0,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -361,17 +419,19 @@ mod tests {
         "#;
 
         let response: BreakdownTradingDataResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = BreakdownTradingDataResponse {
             breakdown: vec![BreakdownTradingDataItem {
-                date: "2015-04-01".to_string(),
-                code: "13010".to_string(),
-                long_sell_value: 115164000.0,
-                short_sell_without_margin_value: 93561000.0,
-                margin_sell_new_value: 6412000.0,
-                margin_sell_close_value: 23009000.0,
-                long_buy_value: 185114000.0,
-                margin_buy_new_value: 35568000.0,
-                margin_buy_close_value: 17464000.0,
+                date: "2015-04-01".parse().unwrap(),
+                code: "13010".parse().unwrap(),
+                long_sell_value: "115164000.0".parse().unwrap(),
+                short_sell_without_margin_value: "93561000.0".parse().unwrap(),
+                margin_sell_new_value: "6412000.0".parse().unwrap(),
+                margin_sell_close_value: "23009000.0".parse().unwrap(),
+                long_buy_value: "185114000.0".parse().unwrap(),
+                margin_buy_new_value: "35568000.0".parse().unwrap(),
+                margin_buy_close_value: "17464000.0".parse().unwrap(),
                 long_sell_volume: 415000.0,
                 short_sell_without_margin_volume: 337000.0,
                 margin_sell_new_volume: 23000.0,
@@ -379,6 +439,8 @@ mod tests {
                 long_buy_volume: 667000.0,
                 margin_buy_new_volume: 128000.0,
                 margin_buy_close_volume: 63000.0,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -433,18 +495,20 @@ mod tests {
         "#;
 
         let response: BreakdownTradingDataResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = BreakdownTradingDataResponse {
             breakdown: vec![
                 BreakdownTradingDataItem {
-                    date: "2015-03-25".to_string(),
-                    code: "13010".to_string(),
-                    long_sell_value: 110000000.0,
-                    short_sell_without_margin_value: 90000000.0,
-                    margin_sell_new_value: 6000000.0,
-                    margin_sell_close_value: 22000000.0,
-                    long_buy_value: 180000000.0,
-                    margin_buy_new_value: 35000000.0,
-                    margin_buy_close_value: 17000000.0,
+                    date: "2015-03-25".parse().unwrap(),
+                    code: "13010".parse().unwrap(),
+                    long_sell_value: "110000000.0".parse().unwrap(),
+                    short_sell_without_margin_value: "90000000.0".parse().unwrap(),
+                    margin_sell_new_value: "6000000.0".parse().unwrap(),
+                    margin_sell_close_value: "22000000.0".parse().unwrap(),
+                    long_buy_value: "180000000.0".parse().unwrap(),
+                    margin_buy_new_value: "35000000.0".parse().unwrap(),
+                    margin_buy_close_value: "17000000.0".parse().unwrap(),
                     long_sell_volume: 400000.0,
                     short_sell_without_margin_volume: 330000.0,
                     margin_sell_new_volume: 22000.0,
@@ -452,17 +516,19 @@ mod tests {
                     long_buy_volume: 660000.0,
                     margin_buy_new_volume: 125000.0,
                     margin_buy_close_volume: 62000.0,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 BreakdownTradingDataItem {
-                    date: "2015-04-01".to_string(),
-                    code: "13010".to_string(),
-                    long_sell_value: 115164000.0,
-                    short_sell_without_margin_value: 93561000.0,
-                    margin_sell_new_value: 6412000.0,
-                    margin_sell_close_value: 23009000.0,
-                    long_buy_value: 185114000.0,
-                    margin_buy_new_value: 35568000.0,
-                    margin_buy_close_value: 17464000.0,
+                    date: "2015-04-01".parse().unwrap(),
+                    code: "13010".parse().unwrap(),
+                    long_sell_value: "115164000.0".parse().unwrap(),
+                    short_sell_without_margin_value: "93561000.0".parse().unwrap(),
+                    margin_sell_new_value: "6412000.0".parse().unwrap(),
+                    margin_sell_close_value: "23009000.0".parse().unwrap(),
+                    long_buy_value: "185114000.0".parse().unwrap(),
+                    margin_buy_new_value: "35568000.0".parse().unwrap(),
+                    margin_buy_close_value: "17464000.0".parse().unwrap(),
                     long_sell_volume: 415000.0,
                     short_sell_without_margin_volume: 337000.0,
                     margin_sell_new_volume: 23000.0,
@@ -470,6 +536,8 @@ mod tests {
                     long_buy_volume: 667000.0,
                     margin_buy_new_volume: 128000.0,
                     margin_buy_close_volume: 63000.0,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value1.value2.".to_string()),
@@ -487,6 +555,8 @@ mod tests {
         "#;
 
         let response: BreakdownTradingDataResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = BreakdownTradingDataResponse {
             breakdown: vec![],
             pagination_key: None,
@@ -495,7 +565,7 @@ mod tests {
         pretty_assertions::assert_eq!(response, expected_response);
     }
 
-    #[cfg(feature = "polars")]
+    #[cfg(all(feature = "polars", not(feature = "decimal")))]
     #[test]
     fn test_into_polars() {
         std::env::set_var("POLARS_FMT_MAX_COLS", "-1");
@@ -503,15 +573,15 @@ mod tests {
         let response = BreakdownTradingDataResponse {
             breakdown: vec![
                 BreakdownTradingDataItem {
-                    date: "2015-04-01".to_string(),
-                    code: "13010".to_string(),
-                    long_sell_value: 100.0,
-                    short_sell_without_margin_value: 200.0,
-                    margin_sell_new_value: 300.0,
-                    margin_sell_close_value: 400.0,
-                    long_buy_value: 500.0,
-                    margin_buy_new_value: 600.0,
-                    margin_buy_close_value: 700.0,
+                    date: "2015-04-01".parse().unwrap(),
+                    code: "13010".parse().unwrap(),
+                    long_sell_value: "100.0".parse().unwrap(),
+                    short_sell_without_margin_value: "200.0".parse().unwrap(),
+                    margin_sell_new_value: "300.0".parse().unwrap(),
+                    margin_sell_close_value: "400.0".parse().unwrap(),
+                    long_buy_value: "500.0".parse().unwrap(),
+                    margin_buy_new_value: "600.0".parse().unwrap(),
+                    margin_buy_close_value: "700.0".parse().unwrap(),
                     long_sell_volume: 800.0,
                     short_sell_without_margin_volume: 900.0,
                     margin_sell_new_volume: 1000.0,
@@ -519,17 +589,19 @@ mod tests {
                     long_buy_volume: 1200.0,
                     margin_buy_new_volume: 1300.0,
                     margin_buy_close_volume: 1400.0,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 BreakdownTradingDataItem {
-                    date: "2015-04-02".to_string(),
-                    code: "13010".to_string(),
-                    long_sell_value: 10000.0,
-                    short_sell_without_margin_value: 11000.0,
-                    margin_sell_new_value: 12000.0,
-                    margin_sell_close_value: 13000.0,
-                    long_buy_value: 14000.0,
-                    margin_buy_new_value: 15000.0,
-                    margin_buy_close_value: 16000.0,
+                    date: "2015-04-02".parse().unwrap(),
+                    code: "13010".parse().unwrap(),
+                    long_sell_value: "10000.0".parse().unwrap(),
+                    short_sell_without_margin_value: "11000.0".parse().unwrap(),
+                    margin_sell_new_value: "12000.0".parse().unwrap(),
+                    margin_sell_close_value: "13000.0".parse().unwrap(),
+                    long_buy_value: "14000.0".parse().unwrap(),
+                    margin_buy_new_value: "15000.0".parse().unwrap(),
+                    margin_buy_close_value: "16000.0".parse().unwrap(),
                     long_sell_volume: 17000.0,
                     short_sell_without_margin_volume: 18000.0,
                     margin_sell_new_volume: 19000.0,
@@ -537,6 +609,8 @@ mod tests {
                     long_buy_volume: 21000.0,
                     margin_buy_new_volume: 22000.0,
                     margin_buy_close_volume: 23000.0,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: None,
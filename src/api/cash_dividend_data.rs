@@ -1,24 +1,38 @@
 //! Cash Dividend Data (/fins/dividend) API.
 
+use std::future::Future;
+
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            code_batch::{CodeBatchResults, CodeBatchable},
+            date_range::DateRangeShardable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
         types::{
             amount_per_share::AmountPerShare,
+            date_time::{ResponseDate, ResponseTime},
             dividend::{
                 DevidendStatucCode, DividendCommemorativeSpecialCode, DividendForecastResultCode,
                 DividendInterimFinalCode,
             },
+            issue_code::{
+                serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+            },
             payable_date::PayableDate,
+            query_date::{IntoQueryDate, QueryDate},
         },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Cash Dividend Data API.
 #[derive(Clone, Serialize)]
@@ -27,33 +41,57 @@ pub struct CashDividendDataBuilder {
     client: JQuantsApiClient,
 
     /// Issue code (e.g., "27800" or "2780")
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
 
     /// Disclosure date (e.g., "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Starting point of data period (e.g., "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
 
     /// End point of data period (e.g., "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for CashDividendDataBuilder {
+    const PATH: &'static str = "fins/dividend";
+}
+
 impl JQuantsBuilder<CashDividendDataResponse> for CashDividendDataBuilder {
     async fn send(self) -> Result<CashDividendDataResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[("code", self.code.is_some()), ("date", self.date.is_some())])?;
+        validation::date_excludes_range(self.date.as_ref(), self.from.as_ref(), self.to.as_ref())?;
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<CashDividendDataResponse, crate::JQuantsError> {
-        self.client.inner.get("fins/dividend", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<CashDividendDataResponse> for CashDividendDataBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<CashDividendDataResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -64,6 +102,21 @@ impl Paginatable<CashDividendDataResponse> for CashDividendDataBuilder {
     }
 }
 
+impl DateRangeShardable<CashDividendDataResponse> for CashDividendDataBuilder {
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
+impl CodeBatchable<CashDividendDataResponse> for CashDividendDataBuilder {
+    fn with_code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+}
+
 impl CashDividendDataBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -78,26 +131,26 @@ impl CashDividendDataBuilder {
     }
 
     /// Set issue code (e.g., "27800" or "2780")
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 
     /// Set disclosure date (e.g., "20210901" or "2021-09-01")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 
     /// Set starting point of data period (e.g., "20210901" or "2021-09-01")
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g., "20210907" or "2021-09-07")
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 }
@@ -110,54 +163,48 @@ pub trait CashDividendDataApi: JQuantsPlanClient {
     fn get_cash_dividend_data(&self) -> CashDividendDataBuilder {
         CashDividendDataBuilder::new(self.get_api_client().clone())
     }
+
+    /// Get cash dividend data for multiple issue codes.
+    ///
+    /// `params` sets the params shared by all codes (e.g. `from` and `to`).
+    /// See [`CodeBatchable::fetch_codes_batch`] for the concurrency and the error handling.
+    fn get_cash_dividend_data_batch(
+        &self,
+        codes: impl IntoIterator<Item = impl IntoIssueCode>,
+        params: impl FnOnce(CashDividendDataBuilder) -> CashDividendDataBuilder,
+        concurrency: usize,
+    ) -> impl Future<Output = CodeBatchResults<CashDividendDataResponse>> {
+        params(self.get_cash_dividend_data()).fetch_codes_batch(codes, concurrency)
+    }
 }
 
 /// Cash Dividend Data API response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/dividend)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct CashDividendDataResponse {
     /// List of cash dividend data
+    #[paginated(items)]
     pub dividend: Vec<CashDividendItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for CashDividendDataResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for CashDividendDataResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.dividend.extend(p.dividend);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single cash dividend data item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct CashDividendItem {
     /// Announcement Date (YYYY-MM-DD)
     #[serde(rename = "AnnouncementDate")]
-    pub announcement_date: String,
+    pub announcement_date: ResponseDate,
 
     /// Announcement Time (HH:MM)
     #[serde(rename = "AnnouncementTime")]
-    pub announcement_time: String,
+    pub announcement_time: ResponseTime,
 
     /// Issue Code (5-character)
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: IssueCode,
 
     /// Reference Number
     #[serde(rename = "ReferenceNumber")]
@@ -169,7 +216,7 @@ pub struct CashDividendItem {
 
     /// Board Meeting Date (YYYY-MM-DD)
     #[serde(rename = "BoardMeetingDate")]
-    pub board_meeting_date: String,
+    pub board_meeting_date: ResponseDate,
 
     /// Interim/Final Code
     #[serde(rename = "InterimFinalCode")]
@@ -189,15 +236,15 @@ pub struct CashDividendItem {
 
     /// Record Date (YYYY-MM-DD)
     #[serde(rename = "RecordDate")]
-    pub record_date: String,
+    pub record_date: ResponseDate,
 
     /// Ex-Rights Date (YYYY-MM-DD)
     #[serde(rename = "ExDate")]
-    pub ex_date: String,
+    pub ex_date: ResponseDate,
 
     /// Actual Record Date (YYYY-MM-DD)
     #[serde(rename = "ActualRecordDate")]
-    pub actual_record_date: String,
+    pub actual_record_date: ResponseDate,
 
     /// Payable Date (YYYY-MM-DD)
     #[serde(rename = "PayableDate")]
@@ -238,6 +285,21 @@ pub struct CashDividendItem {
     /// Special Dividend Rate per Share
     #[serde(rename = "SpecialDividendRate")]
     pub special_dividend_rate: AmountPerShare,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(feature = "chrono-types")]
+impl CashDividendItem {
+    /// Get the announcement date and time in JST.
+    pub fn announced_at(&self) -> Result<chrono::NaiveDateTime, chrono::ParseError> {
+        Ok(self
+            .announcement_date_parsed()?
+            .and_time(self.announcement_time_parsed()?))
+    }
 }
 
 #[cfg(feature = "polars")]
@@ -246,7 +308,7 @@ impl CashDividendDataResponse {
     pub fn into_polars(
         self,
     ) -> Result<polars::prelude::DataFrame, crate::polars_utils::IntoPolarsError> {
-        use crate::polars_utils::build_categorical_column;
+        use crate::polars_utils::{build_categorical_column, build_decimal_column};
         use polars::prelude::*;
 
         let data = self.dividend;
@@ -283,6 +345,8 @@ impl CashDividendDataResponse {
         let mut commemorative_dividend_rate = Vec::with_capacity(data.len());
         let mut special_dividend_rate_variant = Vec::with_capacity(data.len());
         let mut special_dividend_rate = Vec::with_capacity(data.len());
+        #[cfg(feature = "extra-fields")]
+        let mut extras = Vec::with_capacity(data.len());
 
         for item in data {
             announcement_date.push(item.announcement_date);
@@ -317,9 +381,11 @@ impl CashDividendDataResponse {
             commemorative_dividend_rate.push(item.commemorative_dividend_rate.into_number());
             special_dividend_rate_variant.push(item.special_dividend_rate.variant());
             special_dividend_rate.push(item.special_dividend_rate.into_number());
+            #[cfg(feature = "extra-fields")]
+            extras.push(item.extra);
         }
 
-        let df = polars::frame::DataFrame::new(vec![
+        let columns = vec![
             Column::new("AnnouncementDate".into(), announcement_date).cast(&DataType::Date)?,
             Column::new("AnnouncementTime".into(), announcement_time),
             build_categorical_column("Code", code)?,
@@ -330,7 +396,7 @@ impl CashDividendDataResponse {
             build_categorical_column("ForecastResultCode", forecast_result_code)?,
             Column::new("InterimFinalTerm".into(), interim_final_term),
             build_categorical_column("GrossDividendRateVariant", gross_dividend_rate_variant)?,
-            Column::new("GrossDividendRate".into(), gross_dividend_rate),
+            build_decimal_column("GrossDividendRate", gross_dividend_rate),
             Column::new("RecordDate".into(), record_date).cast(&DataType::Date)?,
             Column::new("ExDate".into(), ex_date).cast(&DataType::Date)?,
             Column::new("ActualRecordDate".into(), actual_record_date).cast(&DataType::Date)?,
@@ -338,30 +404,34 @@ impl CashDividendDataResponse {
             Column::new("PayableDate".into(), payable_date).cast(&DataType::Date)?,
             Column::new("CAReferenceNumber".into(), ca_reference_number),
             build_categorical_column("DistributionAmountVariant", distribution_amount_variant)?,
-            Column::new("DistributionAmount".into(), distribution_amount),
+            build_decimal_column("DistributionAmount", distribution_amount),
             build_categorical_column("RetainedEarningsVariant", retained_earnings_variant)?,
-            Column::new("RetainedEarnings".into(), retained_earnings),
+            build_decimal_column("RetainedEarnings", retained_earnings),
             build_categorical_column("DeemedDividendVariant", deemed_dividend_variant)?,
-            Column::new("DeemedDividend".into(), deemed_dividend),
+            build_decimal_column("DeemedDividend", deemed_dividend),
             build_categorical_column("DeemedCapitalGainsVariant", deemed_capital_gains_variant)?,
-            Column::new("DeemedCapitalGains".into(), deemed_capital_gains),
+            build_decimal_column("DeemedCapitalGains", deemed_capital_gains),
             build_categorical_column(
                 "NetAssetDecreaseRatioVariant",
                 net_asset_decrease_ratio_variant,
             )?,
-            Column::new("NetAssetDecreaseRatio".into(), net_asset_decrease_ratio),
+            build_decimal_column("NetAssetDecreaseRatio", net_asset_decrease_ratio),
             build_categorical_column("CommemorativeSpecialCode", commemorative_special_code)?,
             build_categorical_column(
                 "CommemorativeDividendRateVariant",
                 commemorative_dividend_rate_variant,
             )?,
-            Column::new(
-                "CommemorativeDividendRate".into(),
-                commemorative_dividend_rate,
-            ),
+            build_decimal_column("CommemorativeDividendRate", commemorative_dividend_rate),
             build_categorical_column("SpecialDividendRateVariant", special_dividend_rate_variant)?,
-            Column::new("SpecialDividendRate".into(), special_dividend_rate),
-        ])?;
+            build_decimal_column("SpecialDividendRate", special_dividend_rate),
+        ];
+        #[cfg(feature = "extra-fields")]
+        let columns = columns
+            .into_iter()
+            .chain(crate::polars_utils::extra_fields_to_columns(extras))
+            .collect::<Vec<_>>();
+
+        let df = polars::frame::DataFrame::new(columns)?;
 
         let df = df
             .lazy()
@@ -388,6 +458,7 @@ impl CashDividendDataResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_cash_dividend_data_response() {
@@ -426,20 +497,22 @@ mod tests {
 
         let response: CashDividendDataResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_dividend = vec![CashDividendItem {
-            announcement_date: "2014-02-24".to_string(),
-            announcement_time: "09:21".to_string(),
-            code: "15550".to_string(),
+            announcement_date: "2014-02-24".parse().unwrap(),
+            announcement_time: "09:21".parse().unwrap(),
+            code: "15550".parse().unwrap(),
             reference_number: "201402241B00002".to_string(),
             status_code: DevidendStatucCode::New,
-            board_meeting_date: "2014-02-24".to_string(),
+            board_meeting_date: "2014-02-24".parse().unwrap(),
             interim_final_code: DividendInterimFinalCode::Final,
             forecast_result_code: DividendForecastResultCode::Forecast,
             interim_final_term: "2014-03".to_string(),
             gross_dividend_rate: AmountPerShare::Undetermined,
-            record_date: "2014-03-10".to_string(),
-            ex_date: "2014-03-06".to_string(),
-            actual_record_date: "2014-03-10".to_string(),
+            record_date: "2014-03-10".parse().unwrap(),
+            ex_date: "2014-03-06".parse().unwrap(),
+            actual_record_date: "2014-03-10".parse().unwrap(),
             payable_date: PayableDate::Undetermined,
             ca_reference_number: "201402241B00002".to_string(),
             distribution_amount: AmountPerShare::NotApplicable,
@@ -450,6 +523,8 @@ mod tests {
             commemorative_special_code: DividendCommemorativeSpecialCode::Normal,
             commemorative_dividend_rate: AmountPerShare::NotApplicable,
             special_dividend_rate: AmountPerShare::NotApplicable,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = CashDividendDataResponse {
@@ -496,20 +571,22 @@ mod tests {
 
         let response: CashDividendDataResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_dividend = vec![CashDividendItem {
-            announcement_date: "2014-02-24".to_string(),
-            announcement_time: "09:21".to_string(),
-            code: "15550".to_string(),
+            announcement_date: "2014-02-24".parse().unwrap(),
+            announcement_time: "09:21".parse().unwrap(),
+            code: "15550".parse().unwrap(),
             reference_number: "201402241B00002".to_string(),
             status_code: DevidendStatucCode::New,
-            board_meeting_date: "2014-02-24".to_string(),
+            board_meeting_date: "2014-02-24".parse().unwrap(),
             interim_final_code: DividendInterimFinalCode::Final,
             forecast_result_code: DividendForecastResultCode::Forecast,
             interim_final_term: "2014-03".to_string(),
             gross_dividend_rate: AmountPerShare::Undetermined,
-            record_date: "2014-03-10".to_string(),
-            ex_date: "2014-03-06".to_string(),
-            actual_record_date: "2014-03-10".to_string(),
+            record_date: "2014-03-10".parse().unwrap(),
+            ex_date: "2014-03-06".parse().unwrap(),
+            actual_record_date: "2014-03-10".parse().unwrap(),
             payable_date: PayableDate::Undetermined,
             ca_reference_number: "201402241B00002".to_string(),
             distribution_amount: AmountPerShare::NotApplicable,
@@ -520,6 +597,8 @@ mod tests {
             commemorative_special_code: DividendCommemorativeSpecialCode::Normal,
             commemorative_dividend_rate: AmountPerShare::NotApplicable,
             special_dividend_rate: AmountPerShare::NotApplicable,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = CashDividendDataResponse {
@@ -592,56 +671,62 @@ mod tests {
 
         let response: CashDividendDataResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_dividend = vec![
             CashDividendItem {
-                announcement_date: "2023-03-06".to_string(),
-                announcement_time: "10:00".to_string(),
-                code: "86970".to_string(),
+                announcement_date: "2023-03-06".parse().unwrap(),
+                announcement_time: "10:00".parse().unwrap(),
+                code: "86970".parse().unwrap(),
                 reference_number: "1".to_string(),
                 status_code: DevidendStatucCode::New,
-                board_meeting_date: "2023-03-06".to_string(),
+                board_meeting_date: "2023-03-06".parse().unwrap(),
                 interim_final_code: DividendInterimFinalCode::Interim,
                 forecast_result_code: DividendForecastResultCode::Determined,
                 interim_final_term: "2023-04".to_string(),
-                gross_dividend_rate: AmountPerShare::Number(100.0),
-                record_date: "2023-03-10".to_string(),
-                ex_date: "2023-03-05".to_string(),
-                actual_record_date: "2023-03-10".to_string(),
-                payable_date: PayableDate::Date("2023-03-15".to_string()),
+                gross_dividend_rate: AmountPerShare::Number("100.0".parse().unwrap()),
+                record_date: "2023-03-10".parse().unwrap(),
+                ex_date: "2023-03-05".parse().unwrap(),
+                actual_record_date: "2023-03-10".parse().unwrap(),
+                payable_date: PayableDate::Date("2023-03-15".parse().unwrap()),
                 ca_reference_number: "1".to_string(),
-                distribution_amount: AmountPerShare::Number(100.0),
-                retained_earnings: AmountPerShare::Number(50.0),
-                deemed_dividend: AmountPerShare::Number(0.0),
-                deemed_capital_gains: AmountPerShare::Number(0.0),
-                net_asset_decrease_ratio: AmountPerShare::Number(0.05),
+                distribution_amount: AmountPerShare::Number("100.0".parse().unwrap()),
+                retained_earnings: AmountPerShare::Number("50.0".parse().unwrap()),
+                deemed_dividend: AmountPerShare::Number("0.0".parse().unwrap()),
+                deemed_capital_gains: AmountPerShare::Number("0.0".parse().unwrap()),
+                net_asset_decrease_ratio: AmountPerShare::Number("0.05".parse().unwrap()),
                 commemorative_special_code: DividendCommemorativeSpecialCode::Normal,
                 commemorative_dividend_rate: AmountPerShare::Undetermined,
                 special_dividend_rate: AmountPerShare::Undetermined,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
             CashDividendItem {
-                announcement_date: "2023-03-07".to_string(),
-                announcement_time: "11:00".to_string(),
-                code: "86970".to_string(),
+                announcement_date: "2023-03-07".parse().unwrap(),
+                announcement_time: "11:00".parse().unwrap(),
+                code: "86970".parse().unwrap(),
                 reference_number: "2".to_string(),
                 status_code: DevidendStatucCode::Revised,
-                board_meeting_date: "2023-03-07".to_string(),
+                board_meeting_date: "2023-03-07".parse().unwrap(),
                 interim_final_code: DividendInterimFinalCode::Final,
                 forecast_result_code: DividendForecastResultCode::Determined,
                 interim_final_term: "2023-04".to_string(),
-                gross_dividend_rate: AmountPerShare::Number(110.0),
-                record_date: "2023-03-12".to_string(),
-                ex_date: "2023-03-07".to_string(),
-                actual_record_date: "2023-03-12".to_string(),
-                payable_date: PayableDate::Date("2023-03-17".to_string()),
+                gross_dividend_rate: AmountPerShare::Number("110.0".parse().unwrap()),
+                record_date: "2023-03-12".parse().unwrap(),
+                ex_date: "2023-03-07".parse().unwrap(),
+                actual_record_date: "2023-03-12".parse().unwrap(),
+                payable_date: PayableDate::Date("2023-03-17".parse().unwrap()),
                 ca_reference_number: "1".to_string(),
-                distribution_amount: AmountPerShare::Number(110.0),
-                retained_earnings: AmountPerShare::Number(55.0),
-                deemed_dividend: AmountPerShare::Number(0.0),
-                deemed_capital_gains: AmountPerShare::Number(0.0),
-                net_asset_decrease_ratio: AmountPerShare::Number(0.055),
+                distribution_amount: AmountPerShare::Number("110.0".parse().unwrap()),
+                retained_earnings: AmountPerShare::Number("55.0".parse().unwrap()),
+                deemed_dividend: AmountPerShare::Number("0.0".parse().unwrap()),
+                deemed_capital_gains: AmountPerShare::Number("0.0".parse().unwrap()),
+                net_asset_decrease_ratio: AmountPerShare::Number("0.055".parse().unwrap()),
                 commemorative_special_code: DividendCommemorativeSpecialCode::Commemorative,
-                commemorative_dividend_rate: AmountPerShare::Number(10.0),
+                commemorative_dividend_rate: AmountPerShare::Number("10.0".parse().unwrap()),
                 special_dividend_rate: AmountPerShare::Undetermined,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
         ];
 
@@ -662,6 +747,8 @@ mod tests {
         "#;
 
         let response: CashDividendDataResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = CashDividendDataResponse {
             dividend: vec![],
             pagination_key: None,
@@ -670,7 +757,7 @@ mod tests {
         pretty_assertions::assert_eq!(response, expected_response);
     }
 
-    #[cfg(feature = "polars")]
+    #[cfg(all(feature = "polars", not(feature = "decimal")))]
     #[test]
     fn test_into_polars() {
         std::env::set_var("POLARS_FMT_MAX_COLS", "-1");
@@ -679,44 +766,46 @@ mod tests {
         let response = CashDividendDataResponse {
             dividend: vec![
                 CashDividendItem {
-                    announcement_date: "2023-03-06".to_string(),
-                    announcement_time: "10:00".to_string(),
-                    code: "86970".to_string(),
+                    announcement_date: "2023-03-06".parse().unwrap(),
+                    announcement_time: "10:00".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
                     reference_number: "1".to_string(),
                     status_code: DevidendStatucCode::New,
-                    board_meeting_date: "2023-03-07".to_string(),
+                    board_meeting_date: "2023-03-07".parse().unwrap(),
                     interim_final_code: DividendInterimFinalCode::Interim,
                     forecast_result_code: DividendForecastResultCode::Determined,
                     interim_final_term: "2023-04".to_string(),
-                    gross_dividend_rate: AmountPerShare::Number(100.0),
-                    record_date: "2023-03-08".to_string(),
-                    ex_date: "2023-03-09".to_string(),
-                    actual_record_date: "2023-03-10".to_string(),
-                    payable_date: PayableDate::Date("2023-03-11".to_string()),
+                    gross_dividend_rate: AmountPerShare::Number("100.0".parse().unwrap()),
+                    record_date: "2023-03-08".parse().unwrap(),
+                    ex_date: "2023-03-09".parse().unwrap(),
+                    actual_record_date: "2023-03-10".parse().unwrap(),
+                    payable_date: PayableDate::Date("2023-03-11".parse().unwrap()),
                     ca_reference_number: "1".to_string(),
-                    distribution_amount: AmountPerShare::Number(100.0),
-                    retained_earnings: AmountPerShare::Number(200.0),
-                    deemed_dividend: AmountPerShare::Number(300.0),
-                    deemed_capital_gains: AmountPerShare::Number(400.0),
-                    net_asset_decrease_ratio: AmountPerShare::Number(500.0),
+                    distribution_amount: AmountPerShare::Number("100.0".parse().unwrap()),
+                    retained_earnings: AmountPerShare::Number("200.0".parse().unwrap()),
+                    deemed_dividend: AmountPerShare::Number("300.0".parse().unwrap()),
+                    deemed_capital_gains: AmountPerShare::Number("400.0".parse().unwrap()),
+                    net_asset_decrease_ratio: AmountPerShare::Number("500.0".parse().unwrap()),
                     commemorative_special_code: DividendCommemorativeSpecialCode::Normal,
-                    commemorative_dividend_rate: AmountPerShare::Number(600.0),
-                    special_dividend_rate: AmountPerShare::Number(700.0),
+                    commemorative_dividend_rate: AmountPerShare::Number("600.0".parse().unwrap()),
+                    special_dividend_rate: AmountPerShare::Number("700.0".parse().unwrap()),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 CashDividendItem {
-                    announcement_date: "2023-03-07".to_string(),
-                    announcement_time: "11:00".to_string(),
-                    code: "86970".to_string(),
+                    announcement_date: "2023-03-07".parse().unwrap(),
+                    announcement_time: "11:00".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
                     reference_number: "2".to_string(),
                     status_code: DevidendStatucCode::Revised,
-                    board_meeting_date: "2023-03-07".to_string(),
+                    board_meeting_date: "2023-03-07".parse().unwrap(),
                     interim_final_code: DividendInterimFinalCode::Final,
                     forecast_result_code: DividendForecastResultCode::Determined,
                     interim_final_term: "2023-04".to_string(),
                     gross_dividend_rate: AmountPerShare::Undetermined,
-                    record_date: "2023-03-12".to_string(),
-                    ex_date: "2023-03-13".to_string(),
-                    actual_record_date: "2023-03-14".to_string(),
+                    record_date: "2023-03-12".parse().unwrap(),
+                    ex_date: "2023-03-13".parse().unwrap(),
+                    actual_record_date: "2023-03-14".parse().unwrap(),
                     payable_date: PayableDate::Undetermined,
                     ca_reference_number: "2".to_string(),
                     distribution_amount: AmountPerShare::Undetermined,
@@ -727,21 +816,23 @@ mod tests {
                     commemorative_special_code: DividendCommemorativeSpecialCode::Commemorative,
                     commemorative_dividend_rate: AmountPerShare::Undetermined,
                     special_dividend_rate: AmountPerShare::Undetermined,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 CashDividendItem {
-                    announcement_date: "2023-03-08".to_string(),
-                    announcement_time: "12:00".to_string(),
-                    code: "86970".to_string(),
+                    announcement_date: "2023-03-08".parse().unwrap(),
+                    announcement_time: "12:00".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
                     reference_number: "3".to_string(),
                     status_code: DevidendStatucCode::Delete,
-                    board_meeting_date: "2023-03-08".to_string(),
+                    board_meeting_date: "2023-03-08".parse().unwrap(),
                     interim_final_code: DividendInterimFinalCode::Unknown("aaa".to_string()),
                     forecast_result_code: DividendForecastResultCode::Unknown("bbb".to_string()),
                     interim_final_term: "2023-05".to_string(),
                     gross_dividend_rate: AmountPerShare::NotApplicable,
-                    record_date: "2023-03-16".to_string(),
-                    ex_date: "2023-03-17".to_string(),
-                    actual_record_date: "2023-03-18".to_string(),
+                    record_date: "2023-03-16".parse().unwrap(),
+                    ex_date: "2023-03-17".parse().unwrap(),
+                    actual_record_date: "2023-03-18".parse().unwrap(),
                     payable_date: PayableDate::NotApplicable,
                     ca_reference_number: "3".to_string(),
                     distribution_amount: AmountPerShare::NotApplicable,
@@ -754,6 +845,8 @@ mod tests {
                     ),
                     commemorative_dividend_rate: AmountPerShare::NotApplicable,
                     special_dividend_rate: AmountPerShare::NotApplicable,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value3.value4.".to_string()),
@@ -1,17 +1,34 @@
 //! Prices daily quotes API.
-use std::{fmt, marker::PhantomData};
+use std::{fmt, future::Future, marker::PhantomData};
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::PriceLimit;
 
 use super::{
-    shared::traits::{
-        builder::JQuantsBuilder,
-        pagination::{HasPaginationKey, MergePage, Paginatable},
+    shared::{
+        traits::{
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            code_batch::{CodeBatchResults, CodeBatchable},
+            date_range::DateRangeShardable,
+            pagination::{HasPaginationKey, MergePage, Paginatable},
+            request_preview::RequestPreview,
+        },
+        types::{
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
+            issue_code::{
+                serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+            },
+            query_date::{IntoQueryDate, QueryDate},
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Daily Stock Prices (OHLC) API.
 #[derive(Clone, Serialize)]
@@ -19,38 +36,63 @@ pub struct DailyStockPricesBuilder<R: DeserializeOwned + fmt::Debug + Clone> {
     #[serde(skip)]
     client: JQuantsApiClient,
     #[serde(skip)]
-    phantom: PhantomData<R>,
+    phantom: PhantomData<fn() -> R>,
 
     /// Issue code (e.g. 27800 or 2780)
     ///
     /// If a 4-character issue code is specified,  
     /// only the data of common stock will be obtained for the issue on which both common and preferred stocks are listed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
     /// Starting point of data period (e.g. 20210901 or 2021-09-01)
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g. 20210907 or 2021-09-07)
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
     /// Date of data (e.g. 20210907 or 2021-09-07)
     ///
     /// Used when `from` and `to` are not specified.
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl<R: DeserializeOwned + fmt::Debug + Clone> RequestPreview for DailyStockPricesBuilder<R> {
+    const PATH: &'static str = "prices/daily_quotes";
+}
+
 impl<R: DeserializeOwned + fmt::Debug + Clone> JQuantsBuilder<R> for DailyStockPricesBuilder<R> {
     async fn send(self) -> Result<R, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[("code", self.code.is_some()), ("date", self.date.is_some())])?;
+        validation::date_excludes_range(self.date.as_ref(), self.from.as_ref(), self.to.as_ref())?;
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<R, crate::JQuantsError> {
-        self.client.inner.get("prices/daily_quotes", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl<R: DeserializeOwned + fmt::Debug + Clone + Send> JQuantsSendBuilder<R>
+    for DailyStockPricesBuilder<R>
+{
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<R, crate::JQuantsError>> + Send {
+        self.send_ref()
     }
 }
 
@@ -63,6 +105,25 @@ impl<R: DeserializeOwned + fmt::Debug + Clone + HasPaginationKey + MergePage> Pa
     }
 }
 
+impl<R: DeserializeOwned + fmt::Debug + Clone + HasPaginationKey + MergePage> DateRangeShardable<R>
+    for DailyStockPricesBuilder<R>
+{
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
+impl<R: DeserializeOwned + fmt::Debug + Clone + HasPaginationKey + MergePage> CodeBatchable<R>
+    for DailyStockPricesBuilder<R>
+{
+    fn with_code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+}
+
 impl<R: DeserializeOwned + fmt::Debug + Clone> DailyStockPricesBuilder<R> {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -78,26 +139,26 @@ impl<R: DeserializeOwned + fmt::Debug + Clone> DailyStockPricesBuilder<R> {
     }
 
     /// Set issue code (e.g. 27800 or 2780)
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 
     /// Set starting point of data period (e.g. 20210901 or 2021-09-01)
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g. 20210907 or 2021-09-07)
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 
     /// Set date of data (e.g. 20210907 or 2021-09-07)
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
@@ -113,6 +174,24 @@ pub trait DailyStockPricesApi: JQuantsPlanClient {
     fn get_daily_stock_prices(&self) -> DailyStockPricesBuilder<Self::Response> {
         DailyStockPricesBuilder::new(self.get_api_client().clone())
     }
+
+    /// Get daily stock prices for multiple issue codes.
+    ///
+    /// `params` sets the params shared by all codes (e.g. `from` and `to`).
+    /// See [`CodeBatchable::fetch_codes_batch`] for the concurrency and the error handling.
+    fn get_daily_stock_prices_batch(
+        &self,
+        codes: impl IntoIterator<Item = impl IntoIssueCode>,
+        params: impl FnOnce(
+            DailyStockPricesBuilder<Self::Response>,
+        ) -> DailyStockPricesBuilder<Self::Response>,
+        concurrency: usize,
+    ) -> impl Future<Output = CodeBatchResults<Self::Response>>
+    where
+        Self::Response: HasPaginationKey + MergePage,
+    {
+        params(self.get_daily_stock_prices()).fetch_codes_batch(codes, concurrency)
+    }
 }
 
 /// Daily Stock prices (OHLC) response for free plan.
@@ -128,75 +207,46 @@ pub type DailyStockPricesLightPlanResponse = DailyStockPricesStandardPlanRespons
 /// Daily Stock prices (OHLC) response for standard plan.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/daily_quotes)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct DailyStockPricesStandardPlanResponse {
     /// List of daily quotes
+    #[paginated(items)]
     pub daily_quotes: Vec<DailyQuoteStandardPlanItem>,
 
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
-impl HasPaginationKey for DailyStockPricesStandardPlanResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-impl MergePage for DailyStockPricesStandardPlanResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.daily_quotes.extend(p.daily_quotes);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
 
 /// Daily Stock prices (OHLC) response for premium plan.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/daily_quotes)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct DailyStockPricesPremiumPlanResponse {
     /// List of daily quotes
+    #[paginated(items)]
     pub daily_quotes: Vec<DailyQuotePremiumPlanItem>,
 
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
-impl HasPaginationKey for DailyStockPricesPremiumPlanResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-impl MergePage for DailyStockPricesPremiumPlanResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.daily_quotes.extend(p.daily_quotes);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
 
 /// Daily Quote for standard plan.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct DailyQuoteStandardPlanItem {
     /// The common structure for daily quote
     #[serde(flatten)]
     pub common: DailyQuoteCommonItem,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Daily Quote for premium plan.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct DailyQuotePremiumPlanItem {
     /// The common structure for daily quote
     #[serde(flatten)]
@@ -204,19 +254,19 @@ pub struct DailyQuotePremiumPlanItem {
 
     /// Open price of the morning session (before Adjustment)
     #[serde(rename = "MorningOpen")]
-    pub morning_open: Option<f64>,
+    pub morning_open: Option<ResponseDecimal>,
 
     /// High price of the morning session (before Adjustment)
     #[serde(rename = "MorningHigh")]
-    pub morning_high: Option<f64>,
+    pub morning_high: Option<ResponseDecimal>,
 
     /// Low price of the morning session (before Adjustment)
     #[serde(rename = "MorningLow")]
-    pub morning_low: Option<f64>,
+    pub morning_low: Option<ResponseDecimal>,
 
     /// Close price of the morning session (before Adjustment)
     #[serde(rename = "MorningClose")]
-    pub morning_close: Option<f64>,
+    pub morning_close: Option<ResponseDecimal>,
 
     /// Flag of hitting the upper price limit of the day in morning session
     #[serde(rename = "MorningUpperLimit")]
@@ -232,23 +282,23 @@ pub struct DailyQuotePremiumPlanItem {
 
     /// Trading value of the morning session
     #[serde(rename = "MorningTurnoverValue")]
-    pub morning_turnover_value: Option<f64>,
+    pub morning_turnover_value: Option<ResponseDecimal>,
 
     /// Adjusted open price of the morning session
     #[serde(rename = "MorningAdjustmentOpen")]
-    pub morning_adjustment_open: Option<f64>,
+    pub morning_adjustment_open: Option<ResponseDecimal>,
 
     /// Adjusted high price of the morning session
     #[serde(rename = "MorningAdjustmentHigh")]
-    pub morning_adjustment_high: Option<f64>,
+    pub morning_adjustment_high: Option<ResponseDecimal>,
 
     /// Adjusted low price of the morning session
     #[serde(rename = "MorningAdjustmentLow")]
-    pub morning_adjustment_low: Option<f64>,
+    pub morning_adjustment_low: Option<ResponseDecimal>,
 
     /// Adjusted close price of the morning session
     #[serde(rename = "MorningAdjustmentClose")]
-    pub morning_adjustment_close: Option<f64>,
+    pub morning_adjustment_close: Option<ResponseDecimal>,
 
     /// Adjusted trading volume of the morning session
     #[serde(rename = "MorningAdjustmentVolume")]
@@ -256,19 +306,19 @@ pub struct DailyQuotePremiumPlanItem {
 
     /// Open price of the afternoon session (before Adjustment)
     #[serde(rename = "AfternoonOpen")]
-    pub afternoon_open: Option<f64>,
+    pub afternoon_open: Option<ResponseDecimal>,
 
     /// High price of the afternoon session (before Adjustment)
     #[serde(rename = "AfternoonHigh")]
-    pub afternoon_high: Option<f64>,
+    pub afternoon_high: Option<ResponseDecimal>,
 
     /// Low price of the afternoon session (before Adjustment)
     #[serde(rename = "AfternoonLow")]
-    pub afternoon_low: Option<f64>,
+    pub afternoon_low: Option<ResponseDecimal>,
 
     /// Close price of the afternoon session (before Adjustment)
     #[serde(rename = "AfternoonClose")]
-    pub afternoon_close: Option<f64>,
+    pub afternoon_close: Option<ResponseDecimal>,
 
     /// Flag of hitting the upper price limit of the day in afternoon session
     #[serde(rename = "AfternoonUpperLimit")]
@@ -284,55 +334,60 @@ pub struct DailyQuotePremiumPlanItem {
 
     /// Trading value of the afternoon session
     #[serde(rename = "AfternoonTurnoverValue")]
-    pub afternoon_turnover_value: Option<f64>,
+    pub afternoon_turnover_value: Option<ResponseDecimal>,
 
     /// Adjusted open price of the afternoon session
     #[serde(rename = "AfternoonAdjustmentOpen")]
-    pub afternoon_adjustment_open: Option<f64>,
+    pub afternoon_adjustment_open: Option<ResponseDecimal>,
 
     /// Adjusted high price of the afternoon session
     #[serde(rename = "AfternoonAdjustmentHigh")]
-    pub afternoon_adjustment_high: Option<f64>,
+    pub afternoon_adjustment_high: Option<ResponseDecimal>,
 
     /// Adjusted low price of the afternoon session
     #[serde(rename = "AfternoonAdjustmentLow")]
-    pub afternoon_adjustment_low: Option<f64>,
+    pub afternoon_adjustment_low: Option<ResponseDecimal>,
 
     /// Adjusted close price of the afternoon session
     #[serde(rename = "AfternoonAdjustmentClose")]
-    pub afternoon_adjustment_close: Option<f64>,
+    pub afternoon_adjustment_close: Option<ResponseDecimal>,
 
     /// Adjusted trading volume of the afternoon session
     #[serde(rename = "AfternoonAdjustmentVolume")]
     pub afternoon_adjustment_volume: Option<f64>,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Represents a single daily quote
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct DailyQuoteCommonItem {
     /// Date (YYYY-MM-DD).
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Issue code
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: IssueCode,
 
     /// Open Price (before adjustment)
     #[serde(rename = "Open")]
-    pub open: Option<f64>,
+    pub open: Option<ResponseDecimal>,
 
     /// High price (before adjustment)
     #[serde(rename = "High")]
-    pub high: Option<f64>,
+    pub high: Option<ResponseDecimal>,
 
     /// Low price (before adjustment)
     #[serde(rename = "Low")]
-    pub low: Option<f64>,
+    pub low: Option<ResponseDecimal>,
 
     /// Close price (before adjustment)
     #[serde(rename = "Close")]
-    pub close: Option<f64>,
+    pub close: Option<ResponseDecimal>,
 
     /// Flag of hitting the upper price limit of the day
     #[serde(rename = "UpperLimit")]
@@ -348,7 +403,7 @@ pub struct DailyQuoteCommonItem {
 
     /// Trading value
     #[serde(rename = "TurnoverValue")]
-    pub turnover_value: Option<f64>,
+    pub turnover_value: Option<ResponseDecimal>,
 
     /// Adjustment factor
     #[serde(rename = "AdjustmentFactor")]
@@ -356,19 +411,19 @@ pub struct DailyQuoteCommonItem {
 
     /// Adjusted open price
     #[serde(rename = "AdjustmentOpen")]
-    pub adjustment_open: Option<f64>,
+    pub adjustment_open: Option<ResponseDecimal>,
 
     /// Adjusted high price
     #[serde(rename = "AdjustmentHigh")]
-    pub adjustment_high: Option<f64>,
+    pub adjustment_high: Option<ResponseDecimal>,
 
     /// Adjusted low price
     #[serde(rename = "AdjustmentLow")]
-    pub adjustment_low: Option<f64>,
+    pub adjustment_low: Option<ResponseDecimal>,
 
     /// Adjusted close price
     #[serde(rename = "AdjustmentClose")]
-    pub adjustment_close: Option<f64>,
+    pub adjustment_close: Option<ResponseDecimal>,
 
     /// Adjusted volume
     #[serde(rename = "AdjustmentVolume")]
@@ -379,7 +434,7 @@ pub struct DailyQuoteCommonItem {
 fn build_common_columns(
     data: Vec<DailyQuoteCommonItem>,
 ) -> Result<Vec<polars::prelude::Column>, crate::polars_utils::IntoPolarsError> {
-    use crate::polars_utils::build_categorical_column;
+    use crate::polars_utils::{build_categorical_column, build_decimal_column};
     use polars::prelude::*;
 
     let mut dates = Vec::with_capacity(data.len());
@@ -440,19 +495,19 @@ fn build_common_columns(
     let columns = vec![
         Column::new("Date".into(), dates).cast(&DataType::Date)?,
         build_categorical_column("Code", codes)?,
-        Series::new("Open".into(), opens).into(),
-        Series::new("High".into(), highs).into(),
-        Series::new("Low".into(), lows).into(),
-        Series::new("Close".into(), closes).into(),
+        build_decimal_column("Open", opens),
+        build_decimal_column("High", highs),
+        build_decimal_column("Low", lows),
+        build_decimal_column("Close", closes),
         build_categorical_column("UpperLimit", upper_limits)?,
         build_categorical_column("LowerLimit", lower_limits)?,
         Series::new("Volume".into(), volumes).into(),
-        Series::new("TurnoverValue".into(), turnover_values).into(),
+        build_decimal_column("TurnoverValue", turnover_values),
         Series::new("AdjustmentFactor".into(), adjustment_factors).into(),
-        Series::new("AdjustmentOpen".into(), adjustment_opens).into(),
-        Series::new("AdjustmentHigh".into(), adjustment_highs).into(),
-        Series::new("AdjustmentLow".into(), adjustment_lows).into(),
-        Series::new("AdjustmentClose".into(), adjustment_closes).into(),
+        build_decimal_column("AdjustmentOpen", adjustment_opens),
+        build_decimal_column("AdjustmentHigh", adjustment_highs),
+        build_decimal_column("AdjustmentLow", adjustment_lows),
+        build_decimal_column("AdjustmentClose", adjustment_closes),
         Series::new("AdjustmentVolume".into(), adjustment_volumes).into(),
     ];
 
@@ -466,7 +521,18 @@ impl DailyStockPricesStandardPlanResponse {
         self,
     ) -> Result<polars::prelude::DataFrame, crate::polars_utils::IntoPolarsError> {
         let data = self.daily_quotes;
-        let columns = build_common_columns(data.into_iter().map(|d| d.common).collect::<Vec<_>>())?;
+        #[cfg(feature = "extra-fields")]
+        let (commons, extras): (Vec<_>, Vec<_>) =
+            data.into_iter().map(|d| (d.common, d.extra)).unzip();
+        #[cfg(not(feature = "extra-fields"))]
+        let commons = data.into_iter().map(|d| d.common).collect::<Vec<_>>();
+
+        let columns = build_common_columns(commons)?;
+        #[cfg(feature = "extra-fields")]
+        let columns = columns
+            .into_iter()
+            .chain(crate::polars_utils::extra_fields_to_columns(extras))
+            .collect::<Vec<_>>();
         let df = polars::frame::DataFrame::new(columns)?;
 
         Ok(df)
@@ -479,7 +545,7 @@ impl DailyStockPricesPremiumPlanResponse {
     pub fn into_polars(
         self,
     ) -> Result<polars::prelude::DataFrame, crate::polars_utils::IntoPolarsError> {
-        use crate::polars_utils::build_categorical_column;
+        use crate::polars_utils::{build_categorical_column, build_decimal_column};
         use polars::prelude::*;
 
         let data = self.daily_quotes;
@@ -511,6 +577,8 @@ impl DailyStockPricesPremiumPlanResponse {
         let mut afternoon_adjustment_lows = Vec::with_capacity(data.len());
         let mut afternoon_adjustment_closes = Vec::with_capacity(data.len());
         let mut afternoon_adjustment_volumes = Vec::with_capacity(data.len());
+        #[cfg(feature = "extra-fields")]
+        let mut extras = Vec::with_capacity(data.len());
 
         for item in data {
             let DailyQuotePremiumPlanItem {
@@ -541,6 +609,8 @@ impl DailyStockPricesPremiumPlanResponse {
                 afternoon_adjustment_low,
                 afternoon_adjustment_close,
                 afternoon_adjustment_volume,
+                #[cfg(feature = "extra-fields")]
+                extra,
             } = item;
 
             commons.push(common);
@@ -570,45 +640,45 @@ impl DailyStockPricesPremiumPlanResponse {
             afternoon_adjustment_lows.push(afternoon_adjustment_low);
             afternoon_adjustment_closes.push(afternoon_adjustment_close);
             afternoon_adjustment_volumes.push(afternoon_adjustment_volume);
+            #[cfg(feature = "extra-fields")]
+            extras.push(extra);
         }
 
         let mut columns = build_common_columns(commons)?;
         columns.extend(vec![
-            Series::new("MorningOpen".into(), morning_opens).into(),
-            Series::new("MorningHigh".into(), morning_highs).into(),
-            Series::new("MorningLow".into(), morning_lows).into(),
-            Series::new("MorningClose".into(), morning_closes).into(),
+            build_decimal_column("MorningOpen", morning_opens),
+            build_decimal_column("MorningHigh", morning_highs),
+            build_decimal_column("MorningLow", morning_lows),
+            build_decimal_column("MorningClose", morning_closes),
             build_categorical_column("MorningUpperLimit", morning_upper_limits)?,
             build_categorical_column("MorningLowerLimit", morning_lower_limits)?,
             Series::new("MorningVolume".into(), morning_volumes).into(),
-            Series::new("MorningTurnoverValue".into(), morning_turnover_values).into(),
-            Series::new("MorningAdjustmentOpen".into(), morning_adjustment_opens).into(),
-            Series::new("MorningAdjustmentHigh".into(), morning_adjustment_highs).into(),
-            Series::new("MorningAdjustmentLow".into(), morning_adjustment_lows).into(),
-            Series::new("MorningAdjustmentClose".into(), morning_adjustment_closes).into(),
+            build_decimal_column("MorningTurnoverValue", morning_turnover_values),
+            build_decimal_column("MorningAdjustmentOpen", morning_adjustment_opens),
+            build_decimal_column("MorningAdjustmentHigh", morning_adjustment_highs),
+            build_decimal_column("MorningAdjustmentLow", morning_adjustment_lows),
+            build_decimal_column("MorningAdjustmentClose", morning_adjustment_closes),
             Series::new("MorningAdjustmentVolume".into(), morning_adjustment_volumes).into(),
-            Series::new("AfternoonOpen".into(), afternoon_opens).into(),
-            Series::new("AfternoonHigh".into(), afternoon_highs).into(),
-            Series::new("AfternoonLow".into(), afternoon_lows).into(),
-            Series::new("AfternoonClose".into(), afternoon_closes).into(),
+            build_decimal_column("AfternoonOpen", afternoon_opens),
+            build_decimal_column("AfternoonHigh", afternoon_highs),
+            build_decimal_column("AfternoonLow", afternoon_lows),
+            build_decimal_column("AfternoonClose", afternoon_closes),
             build_categorical_column("AfternoonUpperLimit", afternoon_upper_limits)?,
             build_categorical_column("AfternoonLowerLimit", afternoon_lower_limits)?,
             Series::new("AfternoonVolume".into(), afternoon_volumes).into(),
-            Series::new("AfternoonTurnoverValue".into(), afternoon_turnover_values).into(),
-            Series::new("AfternoonAdjustmentOpen".into(), afternoon_adjustment_opens).into(),
-            Series::new("AfternoonAdjustmentHigh".into(), afternoon_adjustment_highs).into(),
-            Series::new("AfternoonAdjustmentLow".into(), afternoon_adjustment_lows).into(),
-            Series::new(
-                "AfternoonAdjustmentClose".into(),
-                afternoon_adjustment_closes,
-            )
-            .into(),
+            build_decimal_column("AfternoonTurnoverValue", afternoon_turnover_values),
+            build_decimal_column("AfternoonAdjustmentOpen", afternoon_adjustment_opens),
+            build_decimal_column("AfternoonAdjustmentHigh", afternoon_adjustment_highs),
+            build_decimal_column("AfternoonAdjustmentLow", afternoon_adjustment_lows),
+            build_decimal_column("AfternoonAdjustmentClose", afternoon_adjustment_closes),
             Series::new(
                 "AfternoonAdjustmentVolume".into(),
                 afternoon_adjustment_volumes,
             )
             .into(),
         ]);
+        #[cfg(feature = "extra-fields")]
+        columns.extend(crate::polars_utils::extra_fields_to_columns(extras));
 
         let df = polars::frame::DataFrame::new(columns)?;
 
@@ -618,6 +688,7 @@ impl DailyStockPricesPremiumPlanResponse {
 
 #[cfg(test)]
 mod tests {
+    use crate::api::shared::test_utils::assert_round_trip;
     use crate::{
         api::daily_stock_prices::{
             DailyQuoteCommonItem, DailyQuotePremiumPlanItem, DailyQuoteStandardPlanItem,
@@ -655,26 +726,30 @@ mod tests {
         "#;
 
         let response: DailyStockPricesStandardPlanResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = DailyStockPricesStandardPlanResponse {
             daily_quotes: vec![DailyQuoteStandardPlanItem {
                 common: DailyQuoteCommonItem {
-                    date: "2023-03-24".to_string(),
-                    code: "86970".to_string(),
-                    open: Some(2047.0),
-                    high: Some(2069.0),
-                    low: Some(2035.0),
-                    close: Some(2045.0),
+                    date: "2023-03-24".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
+                    open: Some("2047.0".parse().unwrap()),
+                    high: Some("2069.0".parse().unwrap()),
+                    low: Some("2035.0".parse().unwrap()),
+                    close: Some("2045.0".parse().unwrap()),
                     upper_limit: PriceLimit::NotHit,
                     lower_limit: PriceLimit::NotHit,
                     volume: Some(2202500.0),
-                    turnover_value: Some(4507051850.0),
+                    turnover_value: Some("4507051850.0".parse().unwrap()),
                     adjustment_factor: 1.0,
-                    adjustment_open: Some(2047.0),
-                    adjustment_high: Some(2069.0),
-                    adjustment_low: Some(2035.0),
-                    adjustment_close: Some(2045.0),
+                    adjustment_open: Some("2047.0".parse().unwrap()),
+                    adjustment_high: Some("2069.0".parse().unwrap()),
+                    adjustment_low: Some("2035.0".parse().unwrap()),
+                    adjustment_close: Some("2045.0".parse().unwrap()),
                     adjustment_volume: Some(2202500.0),
                 },
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -710,26 +785,30 @@ mod tests {
         "#;
 
         let response: DailyStockPricesStandardPlanResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = DailyStockPricesStandardPlanResponse {
             daily_quotes: vec![DailyQuoteStandardPlanItem {
                 common: DailyQuoteCommonItem {
-                    date: "2023-03-24".to_string(),
-                    code: "86970".to_string(),
-                    open: Some(2047.0),
-                    high: Some(2069.0),
-                    low: Some(2035.0),
-                    close: Some(2045.0),
+                    date: "2023-03-24".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
+                    open: Some("2047.0".parse().unwrap()),
+                    high: Some("2069.0".parse().unwrap()),
+                    low: Some("2035.0".parse().unwrap()),
+                    close: Some("2045.0".parse().unwrap()),
                     upper_limit: PriceLimit::NotHit,
                     lower_limit: PriceLimit::NotHit,
                     volume: Some(2202500.0),
-                    turnover_value: Some(4507051850.0),
+                    turnover_value: Some("4507051850.0".parse().unwrap()),
                     adjustment_factor: 1.0,
-                    adjustment_open: Some(2047.0),
-                    adjustment_high: Some(2069.0),
-                    adjustment_low: Some(2035.0),
-                    adjustment_close: Some(2045.0),
+                    adjustment_open: Some("2047.0".parse().unwrap()),
+                    adjustment_high: Some("2069.0".parse().unwrap()),
+                    adjustment_low: Some("2035.0".parse().unwrap()),
+                    adjustment_close: Some("2045.0".parse().unwrap()),
                     adjustment_volume: Some(2202500.0),
                 },
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -792,52 +871,56 @@ mod tests {
         "#;
 
         let response: DailyStockPricesPremiumPlanResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = DailyStockPricesPremiumPlanResponse {
             daily_quotes: vec![DailyQuotePremiumPlanItem {
                 common: DailyQuoteCommonItem {
-                    date: "2023-03-24".to_string(),
-                    code: "86970".to_string(),
-                    open: Some(2047.0),
-                    high: Some(2069.0),
-                    low: Some(2035.0),
-                    close: Some(2045.0),
+                    date: "2023-03-24".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
+                    open: Some("2047.0".parse().unwrap()),
+                    high: Some("2069.0".parse().unwrap()),
+                    low: Some("2035.0".parse().unwrap()),
+                    close: Some("2045.0".parse().unwrap()),
                     upper_limit: PriceLimit::Hit,
                     lower_limit: PriceLimit::Hit,
                     volume: Some(2202500.0),
-                    turnover_value: Some(4507051850.0),
+                    turnover_value: Some("4507051850.0".parse().unwrap()),
                     adjustment_factor: 1.0,
-                    adjustment_open: Some(2047.0),
-                    adjustment_high: Some(2069.0),
-                    adjustment_low: Some(2035.0),
-                    adjustment_close: Some(2045.0),
+                    adjustment_open: Some("2047.0".parse().unwrap()),
+                    adjustment_high: Some("2069.0".parse().unwrap()),
+                    adjustment_low: Some("2035.0".parse().unwrap()),
+                    adjustment_close: Some("2045.0".parse().unwrap()),
                     adjustment_volume: Some(2202500.0),
                 },
-                morning_open: Some(2047.0),
-                morning_high: Some(2069.0),
-                morning_low: Some(2040.0),
-                morning_close: Some(2045.5),
+                morning_open: Some("2047.0".parse().unwrap()),
+                morning_high: Some("2069.0".parse().unwrap()),
+                morning_low: Some("2040.0".parse().unwrap()),
+                morning_close: Some("2045.5".parse().unwrap()),
                 morning_upper_limit: PriceLimit::Hit,
                 morning_lower_limit: PriceLimit::Hit,
                 morning_volume: Some(1121200.0),
-                morning_turnover_value: Some(2297525850.0),
-                morning_adjustment_open: Some(2047.0),
-                morning_adjustment_high: Some(2069.0),
-                morning_adjustment_low: Some(2040.0),
-                morning_adjustment_close: Some(2045.5),
+                morning_turnover_value: Some("2297525850.0".parse().unwrap()),
+                morning_adjustment_open: Some("2047.0".parse().unwrap()),
+                morning_adjustment_high: Some("2069.0".parse().unwrap()),
+                morning_adjustment_low: Some("2040.0".parse().unwrap()),
+                morning_adjustment_close: Some("2045.5".parse().unwrap()),
                 morning_adjustment_volume: Some(1121200.0),
-                afternoon_open: Some(2047.0),
-                afternoon_high: Some(2047.0),
-                afternoon_low: Some(2035.0),
-                afternoon_close: Some(2045.0),
+                afternoon_open: Some("2047.0".parse().unwrap()),
+                afternoon_high: Some("2047.0".parse().unwrap()),
+                afternoon_low: Some("2035.0".parse().unwrap()),
+                afternoon_close: Some("2045.0".parse().unwrap()),
                 afternoon_upper_limit: PriceLimit::Hit,
                 afternoon_lower_limit: PriceLimit::Hit,
                 afternoon_volume: Some(1081300.0),
-                afternoon_turnover_value: Some(2209526000.0),
-                afternoon_adjustment_open: Some(2047.0),
-                afternoon_adjustment_high: Some(2047.0),
-                afternoon_adjustment_low: Some(2035.0),
-                afternoon_adjustment_close: Some(2045.0),
+                afternoon_turnover_value: Some("2209526000.0".parse().unwrap()),
+                afternoon_adjustment_open: Some("2047.0".parse().unwrap()),
+                afternoon_adjustment_high: Some("2047.0".parse().unwrap()),
+                afternoon_adjustment_low: Some("2035.0".parse().unwrap()),
+                afternoon_adjustment_close: Some("2045.0".parse().unwrap()),
                 afternoon_adjustment_volume: Some(1081300.0),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -900,11 +983,13 @@ mod tests {
         "#;
 
         let response: DailyStockPricesPremiumPlanResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = DailyStockPricesPremiumPlanResponse {
             daily_quotes: vec![DailyQuotePremiumPlanItem {
                 common: DailyQuoteCommonItem {
-                    date: "2023-03-24".to_string(),
-                    code: "86970".to_string(),
+                    date: "2023-03-24".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
                     open: None,
                     high: None,
                     low: None,
@@ -946,6 +1031,8 @@ mod tests {
                 afternoon_adjustment_low: None,
                 afternoon_adjustment_close: None,
                 afternoon_adjustment_volume: None,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -1007,52 +1094,56 @@ mod tests {
         "#;
 
         let response: DailyStockPricesPremiumPlanResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = DailyStockPricesPremiumPlanResponse {
             daily_quotes: vec![DailyQuotePremiumPlanItem {
                 common: DailyQuoteCommonItem {
-                    date: "2023-03-24".to_string(),
-                    code: "86970".to_string(),
-                    open: Some(2047.0),
-                    high: Some(2069.0),
-                    low: Some(2035.0),
-                    close: Some(2045.0),
+                    date: "2023-03-24".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
+                    open: Some("2047.0".parse().unwrap()),
+                    high: Some("2069.0".parse().unwrap()),
+                    low: Some("2035.0".parse().unwrap()),
+                    close: Some("2045.0".parse().unwrap()),
                     upper_limit: PriceLimit::NotHit,
                     lower_limit: PriceLimit::NotHit,
                     volume: Some(2202500.0),
-                    turnover_value: Some(4507051850.0),
+                    turnover_value: Some("4507051850.0".parse().unwrap()),
                     adjustment_factor: 1.0,
-                    adjustment_open: Some(2047.0),
-                    adjustment_high: Some(2069.0),
-                    adjustment_low: Some(2035.0),
-                    adjustment_close: Some(2045.0),
+                    adjustment_open: Some("2047.0".parse().unwrap()),
+                    adjustment_high: Some("2069.0".parse().unwrap()),
+                    adjustment_low: Some("2035.0".parse().unwrap()),
+                    adjustment_close: Some("2045.0".parse().unwrap()),
                     adjustment_volume: Some(2202500.0),
                 },
-                morning_open: Some(2047.0),
-                morning_high: Some(2069.0),
-                morning_low: Some(2040.0),
-                morning_close: Some(2045.5),
+                morning_open: Some("2047.0".parse().unwrap()),
+                morning_high: Some("2069.0".parse().unwrap()),
+                morning_low: Some("2040.0".parse().unwrap()),
+                morning_close: Some("2045.5".parse().unwrap()),
                 morning_upper_limit: PriceLimit::NotHit,
                 morning_lower_limit: PriceLimit::NotHit,
                 morning_volume: Some(1121200.0),
-                morning_turnover_value: Some(2297525850.0),
-                morning_adjustment_open: Some(2047.0),
-                morning_adjustment_high: Some(2069.0),
-                morning_adjustment_low: Some(2040.0),
-                morning_adjustment_close: Some(2045.5),
+                morning_turnover_value: Some("2297525850.0".parse().unwrap()),
+                morning_adjustment_open: Some("2047.0".parse().unwrap()),
+                morning_adjustment_high: Some("2069.0".parse().unwrap()),
+                morning_adjustment_low: Some("2040.0".parse().unwrap()),
+                morning_adjustment_close: Some("2045.5".parse().unwrap()),
                 morning_adjustment_volume: Some(1121200.0),
-                afternoon_open: Some(2047.0),
-                afternoon_high: Some(2047.0),
-                afternoon_low: Some(2035.0),
-                afternoon_close: Some(2045.0),
+                afternoon_open: Some("2047.0".parse().unwrap()),
+                afternoon_high: Some("2047.0".parse().unwrap()),
+                afternoon_low: Some("2035.0".parse().unwrap()),
+                afternoon_close: Some("2045.0".parse().unwrap()),
                 afternoon_upper_limit: PriceLimit::NotHit,
                 afternoon_lower_limit: PriceLimit::NotHit,
                 afternoon_volume: Some(1081300.0),
-                afternoon_turnover_value: Some(2209526000.0),
-                afternoon_adjustment_open: Some(2047.0),
-                afternoon_adjustment_high: Some(2047.0),
-                afternoon_adjustment_low: Some(2035.0),
-                afternoon_adjustment_close: Some(2045.0),
+                afternoon_turnover_value: Some("2209526000.0".parse().unwrap()),
+                afternoon_adjustment_open: Some("2047.0".parse().unwrap()),
+                afternoon_adjustment_high: Some("2047.0".parse().unwrap()),
+                afternoon_adjustment_low: Some("2035.0".parse().unwrap()),
+                afternoon_adjustment_close: Some("2045.0".parse().unwrap()),
                 afternoon_adjustment_volume: Some(1081300.0),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -1060,7 +1151,54 @@ mod tests {
         pretty_assertions::assert_eq!(response, expected_response);
     }
 
-    #[cfg(feature = "polars")]
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn test_deserialize_daily_stock_prices_with_extra_fields() {
+        let common = r#"
+            "Date": "2023-03-24",
+            "Code": "86970",
+            "Open": 2047.0,
+            "High": 2069.0,
+            "Low": 2035.0,
+            "Close": 2045.0,
+            "UpperLimit": "0",
+            "LowerLimit": "0",
+            "Volume": 2202500.0,
+            "TurnoverValue": 4507051850.0,
+            "AdjustmentFactor": 1.0,
+            "AdjustmentOpen": 2047.0,
+            "AdjustmentHigh": 2069.0,
+            "AdjustmentLow": 2035.0,
+            "AdjustmentClose": 2045.0,
+            "AdjustmentVolume": 2202500.0,
+            "NewColumn": "1.5",
+            "NewFlag": true
+        "#;
+        let expected_extra = maplit::btreemap! {
+            "NewColumn".to_string() => serde_json::json!("1.5"),
+            "NewFlag".to_string() => serde_json::json!(true),
+        };
+
+        let json = format!(r#"{{"daily_quotes": [{{{common}}}]}}"#);
+        let response: DailyStockPricesStandardPlanResponse = serde_json::from_str(&json).unwrap();
+        assert_round_trip(&response);
+        let item = &response.daily_quotes[0];
+        assert_eq!(item.common.code.as_str(), "86970");
+        assert_eq!(item.extra, expected_extra);
+
+        // The fields of the premium plan are not captured as extra fields.
+        let json = format!(
+            r#"{{"daily_quotes": [{{{common}, "MorningOpen": 2047.0, "MorningUpperLimit": "0",
+                "MorningLowerLimit": "0", "AfternoonUpperLimit": "0", "AfternoonLowerLimit": "0"}}]}}"#
+        );
+        let response: DailyStockPricesPremiumPlanResponse = serde_json::from_str(&json).unwrap();
+        assert_round_trip(&response);
+        let item = &response.daily_quotes[0];
+        assert_eq!(item.morning_open, Some("2047.0".parse().unwrap()));
+        assert_eq!(item.extra, expected_extra);
+    }
+
+    #[cfg(all(feature = "polars", not(feature = "decimal")))]
     #[test]
     fn test_some_light_into_polars() {
         std::env::set_var("POLARS_FMT_MAX_COLS", "-1");
@@ -1069,43 +1207,47 @@ mod tests {
             daily_quotes: vec![
                 DailyQuoteStandardPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-11".to_string(),
-                        code: "86970".to_string(),
-                        open: Some(100.0),
-                        high: Some(200.0),
-                        low: Some(300.0),
-                        close: Some(400.0),
+                        date: "2022-11-11".parse().unwrap(),
+                        code: "86970".parse().unwrap(),
+                        open: Some("100.0".parse().unwrap()),
+                        high: Some("200.0".parse().unwrap()),
+                        low: Some("300.0".parse().unwrap()),
+                        close: Some("400.0".parse().unwrap()),
                         upper_limit: PriceLimit::NotHit,
                         lower_limit: PriceLimit::Hit,
                         volume: Some(500.0),
-                        turnover_value: Some(600.0),
+                        turnover_value: Some("600.0".parse().unwrap()),
                         adjustment_factor: 1.0,
-                        adjustment_open: Some(700.0),
-                        adjustment_high: Some(800.0),
-                        adjustment_low: Some(900.0),
-                        adjustment_close: Some(1000.0),
+                        adjustment_open: Some("700.0".parse().unwrap()),
+                        adjustment_high: Some("800.0".parse().unwrap()),
+                        adjustment_low: Some("900.0".parse().unwrap()),
+                        adjustment_close: Some("1000.0".parse().unwrap()),
                         adjustment_volume: Some(1100.0),
                     },
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 DailyQuoteStandardPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-12".to_string(),
-                        code: "86971".to_string(),
-                        open: Some(10000.0),
-                        high: Some(11000.0),
-                        low: Some(12000.0),
-                        close: Some(13000.0),
+                        date: "2022-11-12".parse().unwrap(),
+                        code: "86971".parse().unwrap(),
+                        open: Some("10000.0".parse().unwrap()),
+                        high: Some("11000.0".parse().unwrap()),
+                        low: Some("12000.0".parse().unwrap()),
+                        close: Some("13000.0".parse().unwrap()),
                         upper_limit: PriceLimit::NotHit,
                         lower_limit: PriceLimit::NotHit,
                         volume: Some(14000.0),
-                        turnover_value: Some(15000.0),
+                        turnover_value: Some("15000.0".parse().unwrap()),
                         adjustment_factor: 1.0,
-                        adjustment_open: Some(16000.0),
-                        adjustment_high: Some(17000.0),
-                        adjustment_low: Some(18000.0),
-                        adjustment_close: Some(19000.0),
+                        adjustment_open: Some("16000.0".parse().unwrap()),
+                        adjustment_high: Some("17000.0".parse().unwrap()),
+                        adjustment_low: Some("18000.0".parse().unwrap()),
+                        adjustment_close: Some("19000.0".parse().unwrap()),
                         adjustment_volume: Some(20000.0),
                     },
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value1.value2.".to_string()),
@@ -1126,7 +1268,7 @@ mod tests {
         .assert_eq(&df.to_string());
     }
 
-    #[cfg(feature = "polars")]
+    #[cfg(all(feature = "polars", not(feature = "decimal")))]
     #[test]
     fn test_none_light_into_polars() {
         std::env::set_var("POLARS_FMT_MAX_COLS", "-1");
@@ -1135,8 +1277,8 @@ mod tests {
             daily_quotes: vec![
                 DailyQuoteStandardPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-11".to_string(),
-                        code: "86970".to_string(),
+                        date: "2022-11-11".parse().unwrap(),
+                        code: "86970".parse().unwrap(),
                         open: None,
                         high: None,
                         low: None,
@@ -1152,11 +1294,13 @@ mod tests {
                         adjustment_close: None,
                         adjustment_volume: None,
                     },
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 DailyQuoteStandardPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-12".to_string(),
-                        code: "86971".to_string(),
+                        date: "2022-11-12".parse().unwrap(),
+                        code: "86971".parse().unwrap(),
                         open: None,
                         high: None,
                         low: None,
@@ -1172,6 +1316,8 @@ mod tests {
                         adjustment_close: None,
                         adjustment_volume: None,
                     },
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: None,
@@ -1191,7 +1337,7 @@ mod tests {
             └────────────┴───────┴──────┴──────┴──────┴───────┴────────────┴────────────┴────────┴───────────────┴──────────────────┴────────────────┴────────────────┴───────────────┴─────────────────┴──────────────────┘"#]].assert_eq(&df.to_string());
     }
 
-    #[cfg(feature = "polars")]
+    #[cfg(all(feature = "polars", not(feature = "decimal")))]
     #[test]
     fn test_some_premium_into_polars() {
         std::env::set_var("POLARS_FMT_MAX_COLS", "-1");
@@ -1200,95 +1346,99 @@ mod tests {
             daily_quotes: vec![
                 DailyQuotePremiumPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-11".to_string(),
-                        code: "86970".to_string(),
-                        open: Some(100.0),
-                        high: Some(200.0),
-                        low: Some(300.0),
-                        close: Some(400.0),
+                        date: "2022-11-11".parse().unwrap(),
+                        code: "86970".parse().unwrap(),
+                        open: Some("100.0".parse().unwrap()),
+                        high: Some("200.0".parse().unwrap()),
+                        low: Some("300.0".parse().unwrap()),
+                        close: Some("400.0".parse().unwrap()),
                         upper_limit: PriceLimit::NotHit,
                         lower_limit: PriceLimit::Hit,
                         volume: Some(500.0),
-                        turnover_value: Some(600.0),
+                        turnover_value: Some("600.0".parse().unwrap()),
                         adjustment_factor: 1.0,
-                        adjustment_open: Some(700.0),
-                        adjustment_high: Some(800.0),
-                        adjustment_low: Some(900.0),
-                        adjustment_close: Some(1000.0),
+                        adjustment_open: Some("700.0".parse().unwrap()),
+                        adjustment_high: Some("800.0".parse().unwrap()),
+                        adjustment_low: Some("900.0".parse().unwrap()),
+                        adjustment_close: Some("1000.0".parse().unwrap()),
                         adjustment_volume: Some(1100.0),
                     },
-                    morning_open: Some(1200.0),
-                    morning_high: Some(1300.0),
-                    morning_low: Some(1400.0),
-                    morning_close: Some(1500.0),
+                    morning_open: Some("1200.0".parse().unwrap()),
+                    morning_high: Some("1300.0".parse().unwrap()),
+                    morning_low: Some("1400.0".parse().unwrap()),
+                    morning_close: Some("1500.0".parse().unwrap()),
                     morning_upper_limit: PriceLimit::Hit,
                     morning_lower_limit: PriceLimit::Hit,
                     morning_volume: Some(1600.0),
-                    morning_turnover_value: Some(1700.0),
-                    morning_adjustment_open: Some(1800.0),
-                    morning_adjustment_high: Some(1900.0),
-                    morning_adjustment_low: Some(2000.0),
-                    morning_adjustment_close: Some(2100.0),
+                    morning_turnover_value: Some("1700.0".parse().unwrap()),
+                    morning_adjustment_open: Some("1800.0".parse().unwrap()),
+                    morning_adjustment_high: Some("1900.0".parse().unwrap()),
+                    morning_adjustment_low: Some("2000.0".parse().unwrap()),
+                    morning_adjustment_close: Some("2100.0".parse().unwrap()),
                     morning_adjustment_volume: Some(2200.0),
-                    afternoon_open: Some(2300.0),
-                    afternoon_high: Some(2400.0),
-                    afternoon_low: Some(2500.0),
-                    afternoon_close: Some(2600.0),
+                    afternoon_open: Some("2300.0".parse().unwrap()),
+                    afternoon_high: Some("2400.0".parse().unwrap()),
+                    afternoon_low: Some("2500.0".parse().unwrap()),
+                    afternoon_close: Some("2600.0".parse().unwrap()),
                     afternoon_upper_limit: PriceLimit::Hit,
                     afternoon_lower_limit: PriceLimit::Hit,
                     afternoon_volume: Some(2700.0),
-                    afternoon_turnover_value: Some(2800.0),
-                    afternoon_adjustment_open: Some(2900.0),
-                    afternoon_adjustment_high: Some(3000.0),
-                    afternoon_adjustment_low: Some(3100.0),
-                    afternoon_adjustment_close: Some(3200.0),
+                    afternoon_turnover_value: Some("2800.0".parse().unwrap()),
+                    afternoon_adjustment_open: Some("2900.0".parse().unwrap()),
+                    afternoon_adjustment_high: Some("3000.0".parse().unwrap()),
+                    afternoon_adjustment_low: Some("3100.0".parse().unwrap()),
+                    afternoon_adjustment_close: Some("3200.0".parse().unwrap()),
                     afternoon_adjustment_volume: Some(3300.0),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 DailyQuotePremiumPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-12".to_string(),
-                        code: "86971".to_string(),
-                        open: Some(10000.0),
-                        high: Some(11000.0),
-                        low: Some(12000.0),
-                        close: Some(13000.0),
+                        date: "2022-11-12".parse().unwrap(),
+                        code: "86971".parse().unwrap(),
+                        open: Some("10000.0".parse().unwrap()),
+                        high: Some("11000.0".parse().unwrap()),
+                        low: Some("12000.0".parse().unwrap()),
+                        close: Some("13000.0".parse().unwrap()),
                         upper_limit: PriceLimit::NotHit,
                         lower_limit: PriceLimit::NotHit,
                         volume: Some(14000.0),
-                        turnover_value: Some(15000.0),
+                        turnover_value: Some("15000.0".parse().unwrap()),
                         adjustment_factor: 1.0,
-                        adjustment_open: Some(16000.0),
-                        adjustment_high: Some(17000.0),
-                        adjustment_low: Some(18000.0),
-                        adjustment_close: Some(19000.0),
+                        adjustment_open: Some("16000.0".parse().unwrap()),
+                        adjustment_high: Some("17000.0".parse().unwrap()),
+                        adjustment_low: Some("18000.0".parse().unwrap()),
+                        adjustment_close: Some("19000.0".parse().unwrap()),
                         adjustment_volume: Some(20000.0),
                     },
-                    morning_open: Some(21000.0),
-                    morning_high: Some(22000.0),
-                    morning_low: Some(23000.0),
-                    morning_close: Some(24000.0),
+                    morning_open: Some("21000.0".parse().unwrap()),
+                    morning_high: Some("22000.0".parse().unwrap()),
+                    morning_low: Some("23000.0".parse().unwrap()),
+                    morning_close: Some("24000.0".parse().unwrap()),
                     morning_upper_limit: PriceLimit::NotHit,
                     morning_lower_limit: PriceLimit::NotHit,
                     morning_volume: Some(25000.0),
-                    morning_turnover_value: Some(26000.0),
-                    morning_adjustment_open: Some(27000.0),
-                    morning_adjustment_high: Some(28000.0),
-                    morning_adjustment_low: Some(29000.0),
-                    morning_adjustment_close: Some(30000.0),
+                    morning_turnover_value: Some("26000.0".parse().unwrap()),
+                    morning_adjustment_open: Some("27000.0".parse().unwrap()),
+                    morning_adjustment_high: Some("28000.0".parse().unwrap()),
+                    morning_adjustment_low: Some("29000.0".parse().unwrap()),
+                    morning_adjustment_close: Some("30000.0".parse().unwrap()),
                     morning_adjustment_volume: Some(31000.0),
-                    afternoon_open: Some(32000.0),
-                    afternoon_high: Some(33000.0),
-                    afternoon_low: Some(34000.0),
-                    afternoon_close: Some(35000.0),
+                    afternoon_open: Some("32000.0".parse().unwrap()),
+                    afternoon_high: Some("33000.0".parse().unwrap()),
+                    afternoon_low: Some("34000.0".parse().unwrap()),
+                    afternoon_close: Some("35000.0".parse().unwrap()),
                     afternoon_upper_limit: PriceLimit::NotHit,
                     afternoon_lower_limit: PriceLimit::NotHit,
                     afternoon_volume: Some(36000.0),
-                    afternoon_turnover_value: Some(37000.0),
-                    afternoon_adjustment_open: Some(38000.0),
-                    afternoon_adjustment_high: Some(39000.0),
-                    afternoon_adjustment_low: Some(40000.0),
-                    afternoon_adjustment_close: Some(41000.0),
+                    afternoon_turnover_value: Some("37000.0".parse().unwrap()),
+                    afternoon_adjustment_open: Some("38000.0".parse().unwrap()),
+                    afternoon_adjustment_high: Some("39000.0".parse().unwrap()),
+                    afternoon_adjustment_low: Some("40000.0".parse().unwrap()),
+                    afternoon_adjustment_close: Some("41000.0".parse().unwrap()),
                     afternoon_adjustment_volume: Some(42000.0),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some(
@@ -1312,7 +1462,7 @@ mod tests {
             └────────────┴───────┴─────────┴─────────┴─────────┴─────────┴────────────┴────────────┴─────────┴───────────────┴──────────────────┴────────────────┴────────────────┴───────────────┴─────────────────┴──────────────────┴─────────────┴─────────────┴────────────┴──────────────┴───────────────────┴───────────────────┴───────────────┴──────────────────────┴───────────────────────┴───────────────────────┴──────────────────────┴────────────────────────┴─────────────────────────┴───────────────┴───────────────┴──────────────┴────────────────┴─────────────────────┴─────────────────────┴─────────────────┴────────────────────────┴─────────────────────────┴─────────────────────────┴────────────────────────┴──────────────────────────┴───────────────────────────┘"#]].assert_eq(&df.to_string());
     }
 
-    #[cfg(feature = "polars")]
+    #[cfg(all(feature = "polars", not(feature = "decimal")))]
     #[test]
     fn test_none_premium_into_polars() {
         std::env::set_var("POLARS_FMT_MAX_COLS", "-1");
@@ -1321,8 +1471,8 @@ mod tests {
             daily_quotes: vec![
                 DailyQuotePremiumPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-11".to_string(),
-                        code: "86970".to_string(),
+                        date: "2022-11-11".parse().unwrap(),
+                        code: "86970".parse().unwrap(),
                         open: None,
                         high: None,
                         low: None,
@@ -1364,11 +1514,13 @@ mod tests {
                     afternoon_adjustment_low: None,
                     afternoon_adjustment_close: None,
                     afternoon_adjustment_volume: None,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 DailyQuotePremiumPlanItem {
                     common: DailyQuoteCommonItem {
-                        date: "2022-11-12".to_string(),
-                        code: "86971".to_string(),
+                        date: "2022-11-12".parse().unwrap(),
+                        code: "86971".parse().unwrap(),
                         open: None,
                         high: None,
                         low: None,
@@ -1410,6 +1562,8 @@ mod tests {
                     afternoon_adjustment_low: None,
                     afternoon_adjustment_close: None,
                     afternoon_adjustment_volume: None,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: None,
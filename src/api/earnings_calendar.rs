@@ -1,17 +1,25 @@
 //! Earnings Calendar (/fins/announcement) API
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
         deserialize_utils::empty_string_or_null_as_none,
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            pagination::Paginatable,
+            request_preview::RequestPreview,
+        },
+        types::{
+            date_time::ResponseDate, fiscal_quarter::FiscalQuarter, fiscal_year_end::FiscalYearEnd,
+            issue_code::IssueCode, market_code::MarketCode, sector33_code::Sector33Code,
         },
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Earnings Calendar Data API.
 #[derive(Clone, Serialize)]
@@ -24,13 +32,27 @@ pub struct EarningsCalendarBuilder {
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for EarningsCalendarBuilder {
+    const PATH: &'static str = "fins/announcement";
+}
+
 impl JQuantsBuilder<EarningsCalendarResponse> for EarningsCalendarBuilder {
     async fn send(self) -> Result<EarningsCalendarResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
     async fn send_ref(&self) -> Result<EarningsCalendarResponse, crate::JQuantsError> {
-        self.client.inner.get("fins/announcement", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<EarningsCalendarResponse> for EarningsCalendarBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<EarningsCalendarResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -70,67 +92,90 @@ pub trait EarningsCalendarApi: JQuantsPlanClient {
 /// Earnings Calendar Data API response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/announcement)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct EarningsCalendarResponse {
     /// List of earnings announcements
+    #[paginated(items)]
     pub announcement: Vec<EarningsAnnouncementItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for EarningsCalendarResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for EarningsCalendarResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.announcement.extend(p.announcement);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single earnings announcement item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct EarningsAnnouncementItem {
     /// Announcement Date (YYYY-MM-DD)
     ///
     /// If the earnings announcement date is undecided, the data will be an empty string ("").
     #[serde(rename = "Date", deserialize_with = "empty_string_or_null_as_none")]
-    pub date: Option<String>,
+    pub date: Option<ResponseDate>,
 
     /// Issue Code (e.g., "43760")
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: IssueCode,
 
     /// Company Name (Japanese)
     #[serde(rename = "CompanyName")]
     pub company_name: String,
 
     /// End of Fiscal Year (Japanese, e.g., "9月30日")
+    ///
+    /// See [`EarningsAnnouncementItem::fiscal_year_end`] for the parsed value.
     #[serde(rename = "FiscalYear")]
     pub fiscal_year: String,
 
     /// Sector Name (Japanese)
+    ///
+    /// See [`EarningsAnnouncementItem::sector33_code`] for the parsed value.
     #[serde(rename = "SectorName")]
     pub sector_name: String,
 
     /// Fiscal Quarter (Japanese, e.g., "第１四半期")
+    ///
+    /// See [`EarningsAnnouncementItem::quarter`] for the parsed value.
     #[serde(rename = "FiscalQuarter")]
     pub fiscal_quarter: String,
 
     /// Market Segment Name (Japanese, e.g., "マザーズ")
+    ///
+    /// See [`EarningsAnnouncementItem::market_code`] for the parsed value.
     #[serde(rename = "Section")]
     pub section: String,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl EarningsAnnouncementItem {
+    /// End of fiscal year parsed from `fiscal_year` (e.g. "9月30日" is September 30).
+    ///
+    /// Returns `None` if it is not a month and a day.
+    pub fn fiscal_year_end(&self) -> Option<FiscalYearEnd> {
+        self.fiscal_year.parse().ok()
+    }
+
+    /// Fiscal quarter parsed from `fiscal_quarter` (e.g. "第１四半期" is [`FiscalQuarter::Q1`]).
+    pub fn quarter(&self) -> FiscalQuarter {
+        FiscalQuarter::from(self.fiscal_quarter.as_str())
+    }
+
+    /// Market segment parsed from `section` (e.g. "マザーズ" is [`MarketCode::Mothers`]).
+    ///
+    /// Returns `None` if the name is not a known market segment.
+    pub fn market_code(&self) -> Option<MarketCode> {
+        MarketCode::from_ja_name(&self.section)
+    }
+
+    /// 33 sector code parsed from `sector_name`
+    /// (e.g. "情報・通信業" is [`Sector33Code::InformationCommunication`]).
+    ///
+    /// Returns `None` if the name is not a known sector.
+    pub fn sector33_code(&self) -> Option<Sector33Code> {
+        Sector33Code::from_ja_name(&self.sector_name)
+    }
 }
 
 #[cfg(feature = "polars")]
@@ -151,6 +196,8 @@ impl EarningsCalendarResponse {
         let mut sector_names = Vec::with_capacity(data.len());
         let mut fiscal_quarters = Vec::with_capacity(data.len());
         let mut sections = Vec::with_capacity(data.len());
+        #[cfg(feature = "extra-fields")]
+        let mut extras = Vec::with_capacity(data.len());
 
         for item in data {
             let EarningsAnnouncementItem {
@@ -161,15 +208,19 @@ impl EarningsCalendarResponse {
                 sector_name,
                 fiscal_quarter,
                 section,
+                #[cfg(feature = "extra-fields")]
+                extra,
             } = item;
 
             dates.push(date);
-            codes.push(code);
+            codes.push(code.to_string());
             company_names.push(company_name);
             fiscal_years.push(fiscal_year);
             sector_names.push(sector_name);
             fiscal_quarters.push(fiscal_quarter);
             sections.push(section);
+            #[cfg(feature = "extra-fields")]
+            extras.push(extra);
         }
 
         let columns = vec![
@@ -181,6 +232,11 @@ impl EarningsCalendarResponse {
             build_categorical_column("FiscalQuarter", fiscal_quarters)?,
             build_categorical_column("Section", sections)?,
         ];
+        #[cfg(feature = "extra-fields")]
+        let columns = columns
+            .into_iter()
+            .chain(crate::polars_utils::extra_fields_to_columns(extras))
+            .collect::<Vec<_>>();
 
         let df = polars::frame::DataFrame::new(columns)?;
 
@@ -191,6 +247,7 @@ impl EarningsCalendarResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_earnings_calendar_response() {
@@ -213,14 +270,18 @@ mod tests {
 
         let response: EarningsCalendarResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_announcement = vec![EarningsAnnouncementItem {
-            date: Some("2022-02-14".to_string()),
-            code: "43760".to_string(),
+            date: Some("2022-02-14".parse().unwrap()),
+            code: "43760".parse().unwrap(),
             company_name: "くふうカンパニー".to_string(),
             fiscal_year: "9月30日".to_string(),
             sector_name: "情報・通信業".to_string(),
             fiscal_quarter: "第１四半期".to_string(),
             section: "マザーズ".to_string(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = EarningsCalendarResponse {
@@ -251,14 +312,18 @@ mod tests {
 
         let response: EarningsCalendarResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_announcement = vec![EarningsAnnouncementItem {
-            date: Some("2022-02-14".to_string()),
-            code: "43760".to_string(),
+            date: Some("2022-02-14".parse().unwrap()),
+            code: "43760".parse().unwrap(),
             company_name: "くふうカンパニー".to_string(),
             fiscal_year: "9月30日".to_string(),
             sector_name: "情報・通信業".to_string(),
             fiscal_quarter: "第１四半期".to_string(),
             section: "マザーズ".to_string(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = EarningsCalendarResponse {
@@ -299,24 +364,30 @@ mod tests {
 
         let response: EarningsCalendarResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_announcement = vec![
             EarningsAnnouncementItem {
-                date: Some("2023-03-06".to_string()),
-                code: "86970".to_string(),
+                date: Some("2023-03-06".parse().unwrap()),
+                code: "86970".parse().unwrap(),
                 company_name: "株式会社XYZ".to_string(),
                 fiscal_year: "3月31日".to_string(),
                 sector_name: "製造業".to_string(),
                 fiscal_quarter: "第4四半期".to_string(),
                 section: "東証プライム".to_string(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
             EarningsAnnouncementItem {
-                date: Some("2023-03-07".to_string()),
-                code: "86971".to_string(),
+                date: Some("2023-03-07".parse().unwrap()),
+                code: "86971".parse().unwrap(),
                 company_name: "株式会社ABC".to_string(),
                 fiscal_year: "9月30日".to_string(),
                 sector_name: "金融業".to_string(),
                 fiscal_quarter: "第1四半期".to_string(),
                 section: "東証マザーズ".to_string(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
         ];
 
@@ -328,6 +399,46 @@ mod tests {
         pretty_assertions::assert_eq!(response, expected_response);
     }
 
+    #[test]
+    fn test_typed_fields() {
+        let item = EarningsAnnouncementItem {
+            date: Some("2022-02-14".parse().unwrap()),
+            code: "43760".parse().unwrap(),
+            company_name: "くふうカンパニー".to_string(),
+            fiscal_year: "9月30日".to_string(),
+            sector_name: "情報・通信業".to_string(),
+            fiscal_quarter: "第１四半期".to_string(),
+            section: "マザーズ".to_string(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        };
+
+        assert_eq!(
+            item.fiscal_year_end(),
+            Some(FiscalYearEnd { month: 9, day: 30 })
+        );
+        assert_eq!(item.quarter(), FiscalQuarter::Q1);
+        assert_eq!(item.market_code(), Some(MarketCode::Mothers));
+        assert_eq!(
+            item.sector33_code(),
+            Some(Sector33Code::InformationCommunication)
+        );
+        assert_eq!(item.fiscal_year, "9月30日");
+
+        let item = EarningsAnnouncementItem {
+            fiscal_year: "".to_string(),
+            sector_name: "製造業".to_string(),
+            fiscal_quarter: "中間".to_string(),
+            section: "名証".to_string(),
+            ..item
+        };
+
+        assert_eq!(item.fiscal_year_end(), None);
+        assert_eq!(item.quarter(), FiscalQuarter::Unknown("中間".to_string()));
+        assert_eq!(item.market_code(), None);
+        assert_eq!(item.sector33_code(), None);
+    }
+
     #[test]
     fn test_deserialize_earnings_calendar_response_no_data() {
         let json_data = r#"
@@ -337,6 +448,8 @@ mod tests {
         "#;
 
         let response: EarningsCalendarResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = EarningsCalendarResponse {
             announcement: vec![],
             pagination_key: None,
@@ -353,22 +466,26 @@ mod tests {
         let response = EarningsCalendarResponse {
             announcement: vec![
                 EarningsAnnouncementItem {
-                    date: Some("2022-02-14".to_string()),
-                    code: "43760".to_string(),
+                    date: Some("2022-02-14".parse().unwrap()),
+                    code: "43760".parse().unwrap(),
                     company_name: "ABC".to_string(),
                     fiscal_year: "9/30".to_string(),
                     sector_name: "IT".to_string(),
                     fiscal_quarter: "1Q".to_string(),
                     section: "Mothers".to_string(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 EarningsAnnouncementItem {
                     date: None,
-                    code: "86970".to_string(),
+                    code: "86970".parse().unwrap(),
                     company_name: "XYZ".to_string(),
                     fiscal_year: "3/31".to_string(),
                     sector_name: "Prod".to_string(),
                     fiscal_quarter: "4Q".to_string(),
                     section: "Prime".to_string(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value1.value2.".to_string()),
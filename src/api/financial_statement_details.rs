@@ -1,18 +1,33 @@
 //! Financial Statement Data(BS/PL) (/fins/fs_details) API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::{
     shared::{
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            code_batch::CodeBatchable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
-        types::type_of_document::TypeOfDocument,
+        types::{
+            date_time::{ResponseDate, ResponseTime},
+            financial_figure::FinancialFigure,
+            issue_code::{
+                serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+            },
+            query_date::{IntoQueryDate, QueryDate},
+            type_of_document::TypeOfDocument,
+            xbrl_statements::{BalanceSheet, CashFlowStatement, IncomeStatement},
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Financial Statement Details Data API.
 #[derive(Clone, Serialize)]
@@ -21,24 +36,47 @@ pub struct FinancialStatementDetailsBuilder {
     client: JQuantsApiClient,
 
     /// Issue code (e.g. "27890" or "2789")
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
     /// Disclosure date (e.g. "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for FinancialStatementDetailsBuilder {
+    const PATH: &'static str = "fins/fs_details";
+}
+
 impl JQuantsBuilder<FinancialStatementDetailsResponse> for FinancialStatementDetailsBuilder {
     async fn send(self) -> Result<FinancialStatementDetailsResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[("code", self.code.is_some()), ("date", self.date.is_some())])?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<FinancialStatementDetailsResponse, crate::JQuantsError> {
-        self.client.inner.get("fins/fs_details", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<FinancialStatementDetailsResponse> for FinancialStatementDetailsBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<
+        Output = Result<FinancialStatementDetailsResponse, crate::JQuantsError>,
+    > + Send {
+        self.send_ref()
     }
 }
 
@@ -49,6 +87,13 @@ impl Paginatable<FinancialStatementDetailsResponse> for FinancialStatementDetail
     }
 }
 
+impl CodeBatchable<FinancialStatementDetailsResponse> for FinancialStatementDetailsBuilder {
+    fn with_code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+}
+
 impl FinancialStatementDetailsBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -61,14 +106,14 @@ impl FinancialStatementDetailsBuilder {
     }
 
     /// Set issue code (e.g. "27890" or "2789")
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 
     /// Set disclosure date (e.g. "20210901" or "2021-09-01")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
@@ -86,49 +131,30 @@ pub trait FinancialStatementDetailsApi: JQuantsPlanClient {
 /// Financial Statement Details Data response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/statements-1)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct FinancialStatementDetailsResponse {
     /// List of financial statement details
+    #[paginated(items)]
     pub fs_details: Vec<FinancialStatementDetailItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for FinancialStatementDetailsResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for FinancialStatementDetailsResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.fs_details.extend(p.fs_details);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single financial statement detail item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct FinancialStatementDetailItem {
     /// Disclosed Date (YYYY-MM-DD)
     #[serde(rename = "DisclosedDate")]
-    pub disclosed_date: String,
+    pub disclosed_date: ResponseDate,
 
     /// Disclosed Time (HH:MM:SS)
     #[serde(rename = "DisclosedTime")]
-    pub disclosed_time: String,
+    pub disclosed_time: ResponseTime,
 
     /// Issue Code (5-character)
     #[serde(rename = "LocalCode")]
-    pub local_code: String,
+    pub local_code: IssueCode,
 
     /// Disclosure Number
     ///
@@ -145,6 +171,47 @@ pub struct FinancialStatementDetailItem {
     /// Redundant labels (English) associated with XBRL tags and their values
     #[serde(rename = "FinancialStatement")]
     pub financial_statement: HashMap<String, String>,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl FinancialStatementDetailItem {
+    /// Income statement items resolved from the JGAAP, IFRS and US GAAP labels.
+    pub fn income_statement(&self) -> IncomeStatement {
+        IncomeStatement::from_map(&self.financial_statement)
+    }
+
+    /// Balance sheet items resolved from the JGAAP, IFRS and US GAAP labels.
+    pub fn balance_sheet(&self) -> BalanceSheet {
+        BalanceSheet::from_map(&self.financial_statement)
+    }
+
+    /// Cash flow statement items resolved from the JGAAP, IFRS and US GAAP labels.
+    pub fn cash_flow_statement(&self) -> CashFlowStatement {
+        CashFlowStatement::from_map(&self.financial_statement)
+    }
+
+    /// Get the value of an XBRL label (e.g. "Goodwill (IFRS)") as a figure.
+    ///
+    /// Use this for items that are not covered by the typed statements.
+    pub fn figure(&self, label: &str) -> Option<FinancialFigure> {
+        self.financial_statement
+            .get(label)
+            .map(|value| value.parse().unwrap_or_else(|e| match e {}))
+    }
+}
+
+#[cfg(feature = "chrono-types")]
+impl FinancialStatementDetailItem {
+    /// Get the disclosed date and time in JST.
+    pub fn disclosed_at(&self) -> Result<chrono::NaiveDateTime, chrono::ParseError> {
+        Ok(self
+            .disclosed_date_parsed()?
+            .and_time(self.disclosed_time_parsed()?))
+    }
 }
 
 #[cfg(feature = "polars")]
@@ -164,6 +231,8 @@ impl FinancialStatementDetailsResponse {
         let mut disclosure_numbers = Vec::with_capacity(data.len());
         let mut type_of_documents = Vec::with_capacity(data.len());
         let mut financial_statements = Vec::with_capacity(data.len());
+        #[cfg(feature = "extra-fields")]
+        let mut extras = Vec::with_capacity(data.len());
 
         for item in data {
             let FinancialStatementDetailItem {
@@ -173,6 +242,8 @@ impl FinancialStatementDetailsResponse {
                 disclosure_number,
                 type_of_document,
                 financial_statement,
+                #[cfg(feature = "extra-fields")]
+                extra,
             } = item;
 
             disclosed_dates.push(disclosed_date);
@@ -181,26 +252,41 @@ impl FinancialStatementDetailsResponse {
             disclosure_numbers.push(disclosure_number);
             type_of_documents.push(type_of_document);
             financial_statements.push(financial_statement);
+            #[cfg(feature = "extra-fields")]
+            extras.push(extra);
         }
 
         let extra_columns = hashmap_list_to_columns(financial_statements);
         let mut columns = vec![
             Column::new("DisclosedDate".into(), disclosed_dates).cast(&DataType::Date)?,
-            Column::new("DisclosedTime".into(), disclosed_times).cast(&DataType::Time)?,
+            Column::new("DisclosedTime".into(), disclosed_times),
             build_categorical_column("LocalCode", local_codes)?,
             build_categorical_column("DisclosureNumber", disclosure_numbers)?,
             build_categorical_column("TypeOfDocument", type_of_documents)?,
         ];
         columns.extend(extra_columns);
+        #[cfg(feature = "extra-fields")]
+        columns.extend(crate::polars_utils::extra_fields_to_columns(extras));
 
         let df = polars::frame::DataFrame::new(columns)?;
 
+        let df = df
+            .lazy()
+            .with_columns(vec![col("DisclosedTime").str().to_time(StrptimeOptions {
+                format: Some("%H:%M:%S".into()),
+                strict: true,
+                exact: true,
+                ..Default::default()
+            })])
+            .collect()?;
+
         Ok(df)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::api::shared::test_utils::assert_round_trip;
     use maplit::hashmap;
 
     use super::*;
@@ -294,6 +380,32 @@ mod tests {
         "#;
 
         let response: FinancialStatementDetailsResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
+
+        let item = &response.fs_details[0];
+        assert_eq!(
+            item.income_statement().operating_profit,
+            "51765000000".parse().ok()
+        );
+        assert_eq!(
+            item.income_statement().basic_earnings_per_share,
+            "66.76".parse().ok()
+        );
+        assert_eq!(
+            item.balance_sheet().total_assets,
+            "79205861000000".parse().ok()
+        );
+        assert_eq!(
+            item.cash_flow_statement().cash_and_cash_equivalents,
+            "91135000000".parse().ok()
+        );
+        assert_eq!(
+            item.figure("Goodwill (IFRS)")
+                .and_then(|figure| figure.number()),
+            "67374000000".parse().ok()
+        );
+        assert_eq!(item.figure("Goodwill"), None);
         let financial_statement_map: HashMap<&str, &str> = hashmap! {
             "Goodwill (IFRS)" => "67374000000",
             "Retained earnings (IFRS)" => "263894000000",
@@ -368,15 +480,17 @@ mod tests {
 
         let expected_response = FinancialStatementDetailsResponse {
             fs_details: vec![FinancialStatementDetailItem {
-                disclosed_date: "2023-01-30".to_string(),
-                disclosed_time: "12:00:00".to_string(),
-                local_code: "86970".to_string(),
+                disclosed_date: "2023-01-30".parse().unwrap(),
+                disclosed_time: "12:00:00".parse().unwrap(),
+                local_code: "86970".parse().unwrap(),
                 disclosure_number: "20230127594871".to_string(),
                 type_of_document: TypeOfDocument::Q3FinancialStatementsConsolidatedIFRS,
                 financial_statement: financial_statement_map
                     .into_iter()
                     .map(|(k, v)| (k.to_string(), v.to_string()))
                     .collect(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -405,6 +519,8 @@ mod tests {
         "#;
 
         let response: FinancialStatementDetailsResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let financial_statement_map: HashMap<&str, &str> = hashmap! {
             "Goodwill (IFRS)" =>  "67374000000",
             "Retained earnings (IFRS)" => "263894000000"
@@ -412,15 +528,17 @@ mod tests {
 
         let expected_response = FinancialStatementDetailsResponse {
             fs_details: vec![FinancialStatementDetailItem {
-                disclosed_date: "2023-01-30".to_string(),
-                disclosed_time: "12:00:00".to_string(),
-                local_code: "86970".to_string(),
+                disclosed_date: "2023-01-30".parse().unwrap(),
+                disclosed_time: "12:00:00".parse().unwrap(),
+                local_code: "86970".parse().unwrap(),
                 disclosure_number: "20230127594871".to_string(),
                 type_of_document: TypeOfDocument::Q3FinancialStatementsConsolidatedIFRS,
                 financial_statement: financial_statement_map
                     .into_iter()
                     .map(|(k, v)| (k.to_string(), v.to_string()))
                     .collect(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -462,6 +580,8 @@ mod tests {
 
         let response: FinancialStatementDetailsResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let fs_map1: HashMap<&str, &str> = hashmap! {
             "Goodwill (IFRS)" => "67374000000",
             "Retained earnings (IFRS)" => "263894000000",
@@ -477,20 +597,22 @@ mod tests {
         let expected_response = FinancialStatementDetailsResponse {
             fs_details: vec![
                 FinancialStatementDetailItem {
-                    disclosed_date: "2023-01-30".to_string(),
-                    disclosed_time: "12:00:00".to_string(),
-                    local_code: "86970".to_string(),
+                    disclosed_date: "2023-01-30".parse().unwrap(),
+                    disclosed_time: "12:00:00".parse().unwrap(),
+                    local_code: "86970".parse().unwrap(),
                     disclosure_number: "20230127594871".to_string(),
                     type_of_document: TypeOfDocument::Q3FinancialStatementsConsolidatedIFRS,
                     financial_statement: fs_map1
                         .into_iter()
                         .map(|(k, v)| (k.to_string(), v.to_string()))
                         .collect(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 FinancialStatementDetailItem {
-                    disclosed_date: "2023-02-15".to_string(),
-                    disclosed_time: "14:30:00".to_string(),
-                    local_code: "86971".to_string(),
+                    disclosed_date: "2023-02-15".parse().unwrap(),
+                    disclosed_time: "14:30:00".parse().unwrap(),
+                    local_code: "86971".parse().unwrap(),
                     disclosure_number: "20230227594872".to_string(),
                     type_of_document:
                         TypeOfDocument::OtherPeriodFinancialStatementsConsolidatedIFRS,
@@ -498,6 +620,8 @@ mod tests {
                         .into_iter()
                         .map(|(k, v)| (k.to_string(), v.to_string()))
                         .collect(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value3.value4.".to_string()),
@@ -515,6 +639,8 @@ mod tests {
         "#;
 
         let response: FinancialStatementDetailsResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = FinancialStatementDetailsResponse {
             fs_details: vec![],
             pagination_key: None,
@@ -602,21 +728,39 @@ mod tests {
 
         let response = FinancialStatementDetailsResponse {
             fs_details: vec![FinancialStatementDetailItem {
-                disclosed_date: "2023-01-30".to_string(),
-                disclosed_time: "12:00:00".to_string(),
-                local_code: "86970".to_string(),
+                disclosed_date: "2023-01-30".parse().unwrap(),
+                disclosed_time: "12:00:00".parse().unwrap(),
+                local_code: "86970".parse().unwrap(),
                 disclosure_number: "20230127594871".to_string(),
                 type_of_document: TypeOfDocument::Q3FinancialStatementsConsolidatedIFRS,
                 financial_statement: financial_statement_map
                     .into_iter()
                     .map(|(k, v)| (k.to_string(), v.to_string()))
                     .collect(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
 
         let df = response.into_polars().unwrap();
 
+        #[cfg(feature = "decimal")]
+        {
+            use polars::prelude::{AnyValue, DataType};
+
+            let disclosed_time = df.column("DisclosedTime").unwrap();
+            assert_eq!(disclosed_time.dtype(), &DataType::Time);
+            assert_eq!(disclosed_time.null_count(), 0);
+            let operating_profit = df.column("Operating profit (loss) (IFRS)").unwrap();
+            assert_eq!(operating_profit.dtype(), &DataType::Decimal(None, Some(1)));
+            assert_eq!(
+                operating_profit.get(0).unwrap(),
+                AnyValue::Decimal(517650000000, 1)
+            );
+        }
+
+        #[cfg(not(feature = "decimal"))]
         expect_test::expect![[r#"
             shape: (1, 74)
             ┌─────────────┬─────────────┬───────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────┬─────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬─────────────┬────────┬─────────────┬─────────────┬─────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬────────────┬─────────┬────────────┬────────────┐
@@ -627,7 +771,7 @@ mod tests {
             │             ┆             ┆           ┆             ┆             ┆ str         ┆             ┆             ┆ ---         ┆ f64         ┆ f64         ┆ f64         ┆ ---         ┆ str         ┆ f64         ┆ str         ┆ str         ┆ f64         ┆ str         ┆ f64         ┆ ---         ┆             ┆             ┆             ┆ f64         ┆ str         ┆ ---         ┆ f64     ┆ f64     ┆             ┆ f64         ┆ ---         ┆ ---         ┆ ---         ┆ f64         ┆ f64         ┆             ┆ f64         ┆ ---         ┆ f64         ┆ f64         ┆ f64         ┆ f64         ┆ ---         ┆ ---         ┆ ---         ┆ ---         ┆ f64         ┆ ---         ┆ ---         ┆ f64    ┆ ---         ┆ ---         ┆ str         ┆ ---        ┆ f64        ┆ ---        ┆ ---        ┆ ---        ┆ ---        ┆ bool       ┆ f64        ┆ ---        ┆ ---        ┆            ┆            ┆ f64        ┆ ---        ┆ ---        ┆ ---        ┆ f64        ┆ ---     ┆ ---        ┆ bool       │
             │             ┆             ┆           ┆             ┆             ┆             ┆             ┆             ┆ f64         ┆             ┆             ┆             ┆ f64         ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆ f64         ┆             ┆             ┆             ┆             ┆             ┆ str         ┆         ┆         ┆             ┆             ┆ f64         ┆ f64         ┆ str         ┆             ┆             ┆             ┆             ┆ f64         ┆             ┆             ┆             ┆             ┆ f64         ┆ f64         ┆ f64         ┆ f64         ┆             ┆ f64         ┆ f64         ┆        ┆ f64         ┆ f64         ┆             ┆ str        ┆            ┆ f64        ┆ f64        ┆ f64        ┆ f64        ┆            ┆            ┆ f64        ┆ f64        ┆            ┆            ┆            ┆ f64        ┆ f64        ┆ f64        ┆            ┆ str     ┆ bool       ┆            │
             ╞═════════════╪═════════════╪═══════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════╪═════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪═════════════╪════════╪═════════════╪═════════════╪═════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪════════════╪═════════╪════════════╪════════════╡
-            │ 2023-01-30  ┆ null        ┆ 86970     ┆ 20230127594 ┆ 3QFinancial ┆ IFRS        ┆ false       ┆ 7.9206e13   ┆ 66.76       ┆ 3.3000e10   ┆ 1.9972e10   ┆ 3.8844e10   ┆ 9.1135e10   ┆ 2021-12-31  ┆ 7.9024e13   ┆ 2023-03-31  ┆ 2022-04-01  ┆ 7.8852e13   ┆ 2022-12-31  ┆ 2.8620e9    ┆ 4.19e8      ┆ 四半期第３  ┆ E03814      ┆ 3.2002e11   ┆ 3.1110e11   ┆ Japan       ┆ 株式会社日  ┆ 7.1e7   ┆ 4.3e7   ┆ 6.7374e10   ┆ 1.5841e10   ┆ 5.2450e9    ┆ 5.5290e9    ┆ CTE         ┆ 3.6324e10   ┆ 1.8362e10   ┆ 7.8886e13   ┆ 7.9206e13   ┆ 8.9180e9    ┆ 1.8232e11   ┆ 3.3476e10   ┆ 1.0         ┆ 5.0206e10   ┆ 5.1765e10   ┆ 4.22e8      ┆ 4.2170e9    ┆ 8.9040e9    ┆ 5.8e7       ┆ 1.1240e11   ┆ 2.8980e9    ┆ 4.58e8 ┆ 6.2400e9    ┆ 3.8700e9    ┆ 2022-03-31  ┆ 2021-04-01 ┆ 3.5894e10  ┆ 7.19e8     ┆ 3.5175e10  ┆ 5.1736e10  ┆ 1.1277e10  ┆ false      ┆ 2.6389e11  ┆ 9.0280e9   ┆ 9.2140e9   ┆ 1.0099e11  ┆ 86970.0    ┆ 1.1500e10  ┆ 1.0420e9   ┆ 5.0370e9   ┆ 1.8837e10  ┆ -3.5560e9  ┆ Q3      ┆ true       ┆ false      │
+            │ 2023-01-30  ┆ 12:00:00    ┆ 86970     ┆ 20230127594 ┆ 3QFinancial ┆ IFRS        ┆ false       ┆ 7.9206e13   ┆ 66.76       ┆ 3.3000e10   ┆ 1.9972e10   ┆ 3.8844e10   ┆ 9.1135e10   ┆ 2021-12-31  ┆ 7.9024e13   ┆ 2023-03-31  ┆ 2022-04-01  ┆ 7.8852e13   ┆ 2022-12-31  ┆ 2.8620e9    ┆ 4.19e8      ┆ 四半期第３  ┆ E03814      ┆ 3.2002e11   ┆ 3.1110e11   ┆ Japan       ┆ 株式会社日  ┆ 7.1e7   ┆ 4.3e7   ┆ 6.7374e10   ┆ 1.5841e10   ┆ 5.2450e9    ┆ 5.5290e9    ┆ CTE         ┆ 3.6324e10   ┆ 1.8362e10   ┆ 7.8886e13   ┆ 7.9206e13   ┆ 8.9180e9    ┆ 1.8232e11   ┆ 3.3476e10   ┆ 1.0         ┆ 5.0206e10   ┆ 5.1765e10   ┆ 4.22e8      ┆ 4.2170e9    ┆ 8.9040e9    ┆ 5.8e7       ┆ 1.1240e11   ┆ 2.8980e9    ┆ 4.58e8 ┆ 6.2400e9    ┆ 3.8700e9    ┆ 2022-03-31  ┆ 2021-04-01 ┆ 3.5894e10  ┆ 7.19e8     ┆ 3.5175e10  ┆ 5.1736e10  ┆ 1.1277e10  ┆ false      ┆ 2.6389e11  ┆ 9.0280e9   ┆ 9.2140e9   ┆ 1.0099e11  ┆ 86970.0    ┆ 1.1500e10  ┆ 1.0420e9   ┆ 5.0370e9   ┆ 1.8837e10  ┆ -3.5560e9  ┆ Q3      ┆ true       ┆ false      │
             │             ┆             ┆           ┆ 871         ┆ Statements_ ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆ 号参考様式  ┆             ┆             ┆             ┆ Exchange    ┆ 本取引所グ  ┆         ┆         ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆        ┆             ┆             ┆             ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆         ┆            ┆            │
             │             ┆             ┆           ┆             ┆ Consolid…   ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆ 　[IFRS]（  ┆             ┆             ┆             ┆ Group, Inc. ┆ ループ      ┆         ┆         ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆        ┆             ┆             ┆             ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆         ┆            ┆            │
             │             ┆             ┆           ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆ 連結）      ┆             ┆             ┆             ┆             ┆             ┆         ┆         ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆             ┆        ┆             ┆             ┆             ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆            ┆         ┆            ┆            │
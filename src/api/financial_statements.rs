@@ -1,5 +1,8 @@
 //! Financial Statements Data API.
 
+use std::future::Future;
+
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use crate::{AccountingPeriod, TypeOfDocument};
@@ -8,12 +11,25 @@ use super::{
     shared::{
         deserialize_utils::empty_string_or_null_as_none,
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            code_batch::{CodeBatchResults, CodeBatchable},
+            pagination::Paginatable,
+            request_preview::RequestPreview,
+        },
+        types::{
+            date_time::{ResponseDate, ResponseTime},
+            financial_figure::FinancialFigure,
+            issue_code::{
+                serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+            },
+            query_date::{IntoQueryDate, QueryDate},
         },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Financial Statements Data API.
 #[derive(Clone, Serialize)]
@@ -22,24 +38,46 @@ pub struct FinancialStatementsBuilder {
     client: JQuantsApiClient,
 
     /// Issue code (e.g. "27890" or "2789")
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
     /// Disclosure date (e.g. "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for FinancialStatementsBuilder {
+    const PATH: &'static str = "fins/statements";
+}
+
 impl JQuantsBuilder<FinancialStatementsResponse> for FinancialStatementsBuilder {
     async fn send(self) -> Result<FinancialStatementsResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[("code", self.code.is_some()), ("date", self.date.is_some())])?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<FinancialStatementsResponse, crate::JQuantsError> {
-        self.client.inner.get("fins/statements", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<FinancialStatementsResponse> for FinancialStatementsBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<FinancialStatementsResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -50,6 +88,13 @@ impl Paginatable<FinancialStatementsResponse> for FinancialStatementsBuilder {
     }
 }
 
+impl CodeBatchable<FinancialStatementsResponse> for FinancialStatementsBuilder {
+    fn with_code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+}
+
 impl FinancialStatementsBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -62,14 +107,14 @@ impl FinancialStatementsBuilder {
     }
 
     /// Set issue code (e.g. "27890" or "2789")
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 
     /// Set disclosure date (e.g. "20210901" or "2021-09-01")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
@@ -82,54 +127,48 @@ pub trait FinancialStatementsApi: JQuantsPlanClient {
     fn get_financial_statements(&self) -> FinancialStatementsBuilder {
         FinancialStatementsBuilder::new(self.get_api_client().clone())
     }
+
+    /// Get financial statements for multiple issue codes.
+    ///
+    /// `params` sets the params shared by all codes.
+    /// See [`CodeBatchable::fetch_codes_batch`] for the concurrency and the error handling.
+    fn get_financial_statements_batch(
+        &self,
+        codes: impl IntoIterator<Item = impl IntoIssueCode>,
+        params: impl FnOnce(FinancialStatementsBuilder) -> FinancialStatementsBuilder,
+        concurrency: usize,
+    ) -> impl Future<Output = CodeBatchResults<FinancialStatementsResponse>> {
+        params(self.get_financial_statements()).fetch_codes_batch(codes, concurrency)
+    }
 }
 
 /// Financial Statements Data response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/statements)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct FinancialStatementsResponse {
     /// List of financial statements
+    #[paginated(items)]
     pub statements: Vec<FinancialStatementItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for FinancialStatementsResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for FinancialStatementsResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.statements.extend(p.statements);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single financial statement item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct FinancialStatementItem {
     /// Disclosed Date (YYYY-MM-DD)
     #[serde(rename = "DisclosedDate")]
-    pub disclosed_date: String,
+    pub disclosed_date: ResponseDate,
 
     /// Disclosed Time (HH:MM:SS)
     #[serde(rename = "DisclosedTime")]
-    pub disclosed_time: String,
+    pub disclosed_time: ResponseTime,
 
     /// Issue Code (5-character)
     #[serde(rename = "LocalCode")]
-    pub local_code: String,
+    pub local_code: IssueCode,
 
     /// Disclosure Number
     #[serde(rename = "DisclosureNumber")]
@@ -145,19 +184,19 @@ pub struct FinancialStatementItem {
 
     /// Start date of current accounting period
     #[serde(rename = "CurrentPeriodStartDate")]
-    pub current_period_start_date: String,
+    pub current_period_start_date: ResponseDate,
 
     /// End date of current accounting period
     #[serde(rename = "CurrentPeriodEndDate")]
-    pub current_period_end_date: String,
+    pub current_period_end_date: ResponseDate,
 
     /// Start date of current fiscal year
     #[serde(rename = "CurrentFiscalYearStartDate")]
-    pub current_fiscal_year_start_date: String,
+    pub current_fiscal_year_start_date: ResponseDate,
 
     /// End date of current fiscal year
     #[serde(rename = "CurrentFiscalYearEndDate")]
-    pub current_fiscal_year_end_date: String,
+    pub current_fiscal_year_end_date: ResponseDate,
 
     /// Start date of next fiscal year
     ///
@@ -166,7 +205,7 @@ pub struct FinancialStatementItem {
         rename = "NextFiscalYearStartDate",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub next_fiscal_year_start_date: Option<String>,
+    pub next_fiscal_year_start_date: Option<ResponseDate>,
 
     /// End date of next fiscal year
     ///
@@ -175,235 +214,235 @@ pub struct FinancialStatementItem {
         rename = "NextFiscalYearEndDate",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub next_fiscal_year_end_date: Option<String>,
+    pub next_fiscal_year_end_date: Option<ResponseDate>,
 
     /// Net Sales
     #[serde(rename = "NetSales")]
-    pub net_sales: String,
+    pub net_sales: FinancialFigure,
 
     /// Operating Profit
     #[serde(rename = "OperatingProfit")]
-    pub operating_profit: String,
+    pub operating_profit: FinancialFigure,
 
     /// Ordinary Profit
     #[serde(rename = "OrdinaryProfit")]
-    pub ordinary_profit: String,
+    pub ordinary_profit: FinancialFigure,
 
     /// Profit
     #[serde(rename = "Profit")]
-    pub profit: String,
+    pub profit: FinancialFigure,
 
     /// Earnings per share
     #[serde(rename = "EarningsPerShare")]
-    pub earnings_per_share: String,
+    pub earnings_per_share: FinancialFigure,
 
     /// Diluted Earnings per share
     #[serde(rename = "DilutedEarningsPerShare")]
-    pub diluted_earnings_per_share: String,
+    pub diluted_earnings_per_share: FinancialFigure,
 
     /// Total Assets
     #[serde(rename = "TotalAssets")]
-    pub total_assets: String,
+    pub total_assets: FinancialFigure,
 
     /// Equity
     #[serde(rename = "Equity")]
-    pub equity: String,
+    pub equity: FinancialFigure,
 
     /// Equity to Asset Ratio
     #[serde(rename = "EquityToAssetRatio")]
-    pub equity_to_asset_ratio: String,
+    pub equity_to_asset_ratio: FinancialFigure,
 
     /// Book Value per Share
     #[serde(rename = "BookValuePerShare")]
-    pub book_value_per_share: String,
+    pub book_value_per_share: FinancialFigure,
 
     /// Cash Flows from Operating Activities
     #[serde(rename = "CashFlowsFromOperatingActivities")]
-    pub cash_flows_from_operating_activities: String,
+    pub cash_flows_from_operating_activities: FinancialFigure,
 
     /// Cash Flows from Investing Activities
     #[serde(rename = "CashFlowsFromInvestingActivities")]
-    pub cash_flows_from_investing_activities: String,
+    pub cash_flows_from_investing_activities: FinancialFigure,
 
     /// Cash Flows from Financing Activities
     #[serde(rename = "CashFlowsFromFinancingActivities")]
-    pub cash_flows_from_financing_activities: String,
+    pub cash_flows_from_financing_activities: FinancialFigure,
 
     /// Cash and Equivalents
     #[serde(rename = "CashAndEquivalents")]
-    pub cash_and_equivalents: String,
+    pub cash_and_equivalents: FinancialFigure,
 
     /// Result Dividend Per Share 1st Quarter
     #[serde(rename = "ResultDividendPerShare1stQuarter")]
-    pub result_dividend_per_share_1st_quarter: String,
+    pub result_dividend_per_share_1st_quarter: FinancialFigure,
 
     /// Result Dividend Per Share 2nd Quarter
     #[serde(rename = "ResultDividendPerShare2ndQuarter")]
-    pub result_dividend_per_share_2nd_quarter: String,
+    pub result_dividend_per_share_2nd_quarter: FinancialFigure,
 
     /// Result Dividend Per Share 3rd Quarter
     #[serde(rename = "ResultDividendPerShare3rdQuarter")]
-    pub result_dividend_per_share_3rd_quarter: String,
+    pub result_dividend_per_share_3rd_quarter: FinancialFigure,
 
     /// Result Dividend Per Share Fiscal Year End
     #[serde(rename = "ResultDividendPerShareFiscalYearEnd")]
-    pub result_dividend_per_share_fiscal_year_end: String,
+    pub result_dividend_per_share_fiscal_year_end: FinancialFigure,
 
     /// Result Dividend Per Share Annual
     #[serde(rename = "ResultDividendPerShareAnnual")]
-    pub result_dividend_per_share_annual: String,
+    pub result_dividend_per_share_annual: FinancialFigure,
 
     /// Distributions Per Unit (REIT)
     #[serde(rename = "DistributionsPerUnit(REIT)")]
-    pub distributions_per_unit_reit: String,
+    pub distributions_per_unit_reit: FinancialFigure,
 
     /// Result Total Dividend Paid Annual
     #[serde(rename = "ResultTotalDividendPaidAnnual")]
-    pub result_total_dividend_paid_annual: String,
+    pub result_total_dividend_paid_annual: FinancialFigure,
 
     /// Result Payout Ratio Annual
     #[serde(rename = "ResultPayoutRatioAnnual")]
-    pub result_payout_ratio_annual: String,
+    pub result_payout_ratio_annual: FinancialFigure,
 
     /// Forecast Dividend Per Share 1st Quarter
     #[serde(rename = "ForecastDividendPerShare1stQuarter")]
-    pub forecast_dividend_per_share_1st_quarter: String,
+    pub forecast_dividend_per_share_1st_quarter: FinancialFigure,
 
     /// Forecast Dividend Per Share 2nd Quarter
     #[serde(rename = "ForecastDividendPerShare2ndQuarter")]
-    pub forecast_dividend_per_share_2nd_quarter: String,
+    pub forecast_dividend_per_share_2nd_quarter: FinancialFigure,
 
     /// Forecast Dividend Per Share 3rd Quarter
     #[serde(rename = "ForecastDividendPerShare3rdQuarter")]
-    pub forecast_dividend_per_share_3rd_quarter: String,
+    pub forecast_dividend_per_share_3rd_quarter: FinancialFigure,
 
     /// Forecast Dividend Per Share Fiscal Year End
     #[serde(rename = "ForecastDividendPerShareFiscalYearEnd")]
-    pub forecast_dividend_per_share_fiscal_year_end: String,
+    pub forecast_dividend_per_share_fiscal_year_end: FinancialFigure,
 
     /// Forecast Dividend Per Share Annual
     #[serde(rename = "ForecastDividendPerShareAnnual")]
-    pub forecast_dividend_per_share_annual: String,
+    pub forecast_dividend_per_share_annual: FinancialFigure,
 
     /// Forecast Distributions Per Unit (REIT)
     #[serde(rename = "ForecastDistributionsPerUnit(REIT)")]
-    pub forecast_distributions_per_unit_reit: String,
+    pub forecast_distributions_per_unit_reit: FinancialFigure,
 
     /// Forecast Total Dividend Paid Annual
     #[serde(rename = "ForecastTotalDividendPaidAnnual")]
-    pub forecast_total_dividend_paid_annual: String,
+    pub forecast_total_dividend_paid_annual: FinancialFigure,
 
     /// Forecast Payout Ratio Annual
     #[serde(rename = "ForecastPayoutRatioAnnual")]
-    pub forecast_payout_ratio_annual: String,
+    pub forecast_payout_ratio_annual: FinancialFigure,
 
     /// Next Year Forecast Dividend Per Share 1st Quarter
     #[serde(rename = "NextYearForecastDividendPerShare1stQuarter")]
-    pub next_year_forecast_dividend_per_share_1st_quarter: String,
+    pub next_year_forecast_dividend_per_share_1st_quarter: FinancialFigure,
 
     /// Next Year Forecast Dividend Per Share 2nd Quarter
     #[serde(rename = "NextYearForecastDividendPerShare2ndQuarter")]
-    pub next_year_forecast_dividend_per_share_2nd_quarter: String,
+    pub next_year_forecast_dividend_per_share_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Dividend Per Share 3rd Quarter
     #[serde(rename = "NextYearForecastDividendPerShare3rdQuarter")]
-    pub next_year_forecast_dividend_per_share_3rd_quarter: String,
+    pub next_year_forecast_dividend_per_share_3rd_quarter: FinancialFigure,
 
     /// Next Year Forecast Dividend Per Share Fiscal Year End
     #[serde(rename = "NextYearForecastDividendPerShareFiscalYearEnd")]
-    pub next_year_forecast_dividend_per_share_fiscal_year_end: String,
+    pub next_year_forecast_dividend_per_share_fiscal_year_end: FinancialFigure,
 
     /// Next Year Forecast Dividend Per Share Annual
     #[serde(rename = "NextYearForecastDividendPerShareAnnual")]
-    pub next_year_forecast_dividend_per_share_annual: String,
+    pub next_year_forecast_dividend_per_share_annual: FinancialFigure,
 
     /// Next Year Forecast Distributions Per Unit (REIT)
     #[serde(rename = "NextYearForecastDistributionsPerUnit(REIT)")]
-    pub next_year_forecast_distributions_per_unit_reit: String,
+    pub next_year_forecast_distributions_per_unit_reit: FinancialFigure,
 
     /// Next Year Forecast Payout Ratio Annual
     #[serde(rename = "NextYearForecastPayoutRatioAnnual")]
-    pub next_year_forecast_payout_ratio_annual: String,
+    pub next_year_forecast_payout_ratio_annual: FinancialFigure,
 
     /// Forecast Net Sales 2nd Quarter
     #[serde(rename = "ForecastNetSales2ndQuarter")]
-    pub forecast_net_sales_2nd_quarter: String,
+    pub forecast_net_sales_2nd_quarter: FinancialFigure,
 
     /// Forecast Operating Profit 2nd Quarter
     #[serde(rename = "ForecastOperatingProfit2ndQuarter")]
-    pub forecast_operating_profit_2nd_quarter: String,
+    pub forecast_operating_profit_2nd_quarter: FinancialFigure,
 
     /// Forecast Ordinary Profit 2nd Quarter
     #[serde(rename = "ForecastOrdinaryProfit2ndQuarter")]
-    pub forecast_ordinary_profit_2nd_quarter: String,
+    pub forecast_ordinary_profit_2nd_quarter: FinancialFigure,
 
     /// Forecast Profit 2nd Quarter
     #[serde(rename = "ForecastProfit2ndQuarter")]
-    pub forecast_profit_2nd_quarter: String,
+    pub forecast_profit_2nd_quarter: FinancialFigure,
 
     /// Forecast Earnings Per Share 2nd Quarter
     #[serde(rename = "ForecastEarningsPerShare2ndQuarter")]
-    pub forecast_earnings_per_share_2nd_quarter: String,
+    pub forecast_earnings_per_share_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Net Sales 2nd Quarter
     #[serde(rename = "NextYearForecastNetSales2ndQuarter")]
-    pub next_year_forecast_net_sales_2nd_quarter: String,
+    pub next_year_forecast_net_sales_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Operating Profit 2nd Quarter
     #[serde(rename = "NextYearForecastOperatingProfit2ndQuarter")]
-    pub next_year_forecast_operating_profit_2nd_quarter: String,
+    pub next_year_forecast_operating_profit_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Ordinary Profit 2nd Quarter
     #[serde(rename = "NextYearForecastOrdinaryProfit2ndQuarter")]
-    pub next_year_forecast_ordinary_profit_2nd_quarter: String,
+    pub next_year_forecast_ordinary_profit_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Profit 2nd Quarter
     #[serde(rename = "NextYearForecastProfit2ndQuarter")]
-    pub next_year_forecast_profit_2nd_quarter: String,
+    pub next_year_forecast_profit_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Earnings Per Share 2nd Quarter
     #[serde(rename = "NextYearForecastEarningsPerShare2ndQuarter")]
-    pub next_year_forecast_earnings_per_share_2nd_quarter: String,
+    pub next_year_forecast_earnings_per_share_2nd_quarter: FinancialFigure,
 
     /// Forecast Net Sales at Fiscal Year End
     #[serde(rename = "ForecastNetSales")]
-    pub forecast_net_sales: String,
+    pub forecast_net_sales: FinancialFigure,
 
     /// Forecast Operating Profit at Fiscal Year End
     #[serde(rename = "ForecastOperatingProfit")]
-    pub forecast_operating_profit: String,
+    pub forecast_operating_profit: FinancialFigure,
 
     /// Forecast Ordinary Profit at Fiscal Year End
     #[serde(rename = "ForecastOrdinaryProfit")]
-    pub forecast_ordinary_profit: String,
+    pub forecast_ordinary_profit: FinancialFigure,
 
     /// Forecast Profit at Fiscal Year End
     #[serde(rename = "ForecastProfit")]
-    pub forecast_profit: String,
+    pub forecast_profit: FinancialFigure,
 
     /// Forecast Earnings Per Share at Fiscal Year End
     #[serde(rename = "ForecastEarningsPerShare")]
-    pub forecast_earnings_per_share: String,
+    pub forecast_earnings_per_share: FinancialFigure,
 
     /// Next Year Forecast Net Sales at Fiscal Year End
     #[serde(rename = "NextYearForecastNetSales")]
-    pub next_year_forecast_net_sales: String,
+    pub next_year_forecast_net_sales: FinancialFigure,
 
     /// Next Year Forecast Operating Profit at Fiscal Year End
     #[serde(rename = "NextYearForecastOperatingProfit")]
-    pub next_year_forecast_operating_profit: String,
+    pub next_year_forecast_operating_profit: FinancialFigure,
 
     /// Next Year Forecast Ordinary Profit at Fiscal Year End
     #[serde(rename = "NextYearForecastOrdinaryProfit")]
-    pub next_year_forecast_ordinary_profit: String,
+    pub next_year_forecast_ordinary_profit: FinancialFigure,
 
     /// Next Year Forecast Profit at Fiscal Year End
     #[serde(rename = "NextYearForecastProfit")]
-    pub next_year_forecast_profit: String,
+    pub next_year_forecast_profit: FinancialFigure,
 
     /// Next Year Forecast Earnings Per Share at Fiscal Year End
     #[serde(rename = "NextYearForecastEarningsPerShare")]
-    pub next_year_forecast_earnings_per_share: String,
+    pub next_year_forecast_earnings_per_share: FinancialFigure,
 
     /// Material Changes in Subsidiaries at Fiscal Year End
     #[serde(rename = "MaterialChangesInSubsidiaries")]
@@ -437,136 +476,152 @@ pub struct FinancialStatementItem {
         rename = "NumberOfIssuedAndOutstandingSharesAtTheEndOfFiscalYearIncludingTreasuryStock"
     )]
     pub number_of_issued_and_outstanding_shares_at_the_end_of_fiscal_year_including_treasury_stock:
-        String,
+        FinancialFigure,
 
     /// Number of Treasury Stock at the End of Fiscal Year
     #[serde(rename = "NumberOfTreasuryStockAtTheEndOfFiscalYear")]
-    pub number_of_treasury_stock_at_the_end_of_fiscal_year: String,
+    pub number_of_treasury_stock_at_the_end_of_fiscal_year: FinancialFigure,
 
     /// Average Number of Shares
     #[serde(rename = "AverageNumberOfShares")]
-    pub average_number_of_shares: String,
+    pub average_number_of_shares: FinancialFigure,
 
     /// Non-Consolidated Net Sales at Fiscal Year End
     #[serde(rename = "NonConsolidatedNetSales")]
-    pub non_consolidated_net_sales: String,
+    pub non_consolidated_net_sales: FinancialFigure,
 
     /// Non-Consolidated Operating Profit at Fiscal Year End
     #[serde(rename = "NonConsolidatedOperatingProfit")]
-    pub non_consolidated_operating_profit: String,
+    pub non_consolidated_operating_profit: FinancialFigure,
 
     /// Non-Consolidated Ordinary Profit at Fiscal Year End
     #[serde(rename = "NonConsolidatedOrdinaryProfit")]
-    pub non_consolidated_ordinary_profit: String,
+    pub non_consolidated_ordinary_profit: FinancialFigure,
 
     /// Non-Consolidated Profit at Fiscal Year End
     #[serde(rename = "NonConsolidatedProfit")]
-    pub non_consolidated_profit: String,
+    pub non_consolidated_profit: FinancialFigure,
 
     /// Non-Consolidated Earnings Per Share at Fiscal Year End
     #[serde(rename = "NonConsolidatedEarningsPerShare")]
-    pub non_consolidated_earnings_per_share: String,
+    pub non_consolidated_earnings_per_share: FinancialFigure,
 
     /// Non-Consolidated Total Assets at Fiscal Year End
     #[serde(rename = "NonConsolidatedTotalAssets")]
-    pub non_consolidated_total_assets: String,
+    pub non_consolidated_total_assets: FinancialFigure,
 
     /// Non-Consolidated Equity at Fiscal Year End
     #[serde(rename = "NonConsolidatedEquity")]
-    pub non_consolidated_equity: String,
+    pub non_consolidated_equity: FinancialFigure,
 
     /// Non-Consolidated Equity to Asset Ratio at Fiscal Year End
     #[serde(rename = "NonConsolidatedEquityToAssetRatio")]
-    pub non_consolidated_equity_to_asset_ratio: String,
+    pub non_consolidated_equity_to_asset_ratio: FinancialFigure,
 
     /// Non-Consolidated Book Value Per Share at Fiscal Year End
     #[serde(rename = "NonConsolidatedBookValuePerShare")]
-    pub non_consolidated_book_value_per_share: String,
+    pub non_consolidated_book_value_per_share: FinancialFigure,
 
     /// Forecast Non-Consolidated Net Sales 2nd Quarter
     #[serde(rename = "ForecastNonConsolidatedNetSales2ndQuarter")]
-    pub forecast_non_consolidated_net_sales_2nd_quarter: String,
+    pub forecast_non_consolidated_net_sales_2nd_quarter: FinancialFigure,
 
     /// Forecast Non-Consolidated Operating Profit 2nd Quarter
     #[serde(rename = "ForecastNonConsolidatedOperatingProfit2ndQuarter")]
-    pub forecast_non_consolidated_operating_profit_2nd_quarter: String,
+    pub forecast_non_consolidated_operating_profit_2nd_quarter: FinancialFigure,
 
     /// Forecast Non-Consolidated Ordinary Profit 2nd Quarter
     #[serde(rename = "ForecastNonConsolidatedOrdinaryProfit2ndQuarter")]
-    pub forecast_non_consolidated_ordinary_profit_2nd_quarter: String,
+    pub forecast_non_consolidated_ordinary_profit_2nd_quarter: FinancialFigure,
 
     /// Forecast Non-Consolidated Profit 2nd Quarter
     #[serde(rename = "ForecastNonConsolidatedProfit2ndQuarter")]
-    pub forecast_non_consolidated_profit_2nd_quarter: String,
+    pub forecast_non_consolidated_profit_2nd_quarter: FinancialFigure,
 
     /// Forecast Non-Consolidated Earnings Per Share 2nd Quarter
     #[serde(rename = "ForecastNonConsolidatedEarningsPerShare2ndQuarter")]
-    pub forecast_non_consolidated_earnings_per_share_2nd_quarter: String,
+    pub forecast_non_consolidated_earnings_per_share_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Net Sales 2nd Quarter
     #[serde(rename = "NextYearForecastNonConsolidatedNetSales2ndQuarter")]
-    pub next_year_forecast_non_consolidated_net_sales_2nd_quarter: String,
+    pub next_year_forecast_non_consolidated_net_sales_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Operating Profit 2nd Quarter
     #[serde(rename = "NextYearForecastNonConsolidatedOperatingProfit2ndQuarter")]
-    pub next_year_forecast_non_consolidated_operating_profit_2nd_quarter: String,
+    pub next_year_forecast_non_consolidated_operating_profit_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Ordinary Profit 2nd Quarter
     #[serde(rename = "NextYearForecastNonConsolidatedOrdinaryProfit2ndQuarter")]
-    pub next_year_forecast_non_consolidated_ordinary_profit_2nd_quarter: String,
+    pub next_year_forecast_non_consolidated_ordinary_profit_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Profit 2nd Quarter
     #[serde(rename = "NextYearForecastNonConsolidatedProfit2ndQuarter")]
-    pub next_year_forecast_non_consolidated_profit_2nd_quarter: String,
+    pub next_year_forecast_non_consolidated_profit_2nd_quarter: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Earnings Per Share 2nd Quarter
     #[serde(rename = "NextYearForecastNonConsolidatedEarningsPerShare2ndQuarter")]
-    pub next_year_forecast_non_consolidated_earnings_per_share_2nd_quarter: String,
+    pub next_year_forecast_non_consolidated_earnings_per_share_2nd_quarter: FinancialFigure,
 
     /// Forecast Non-Consolidated Net Sales at Fiscal Year End
     #[serde(rename = "ForecastNonConsolidatedNetSales")]
-    pub forecast_non_consolidated_net_sales: String,
+    pub forecast_non_consolidated_net_sales: FinancialFigure,
 
     /// Forecast Non-Consolidated Operating Profit at Fiscal Year End
     #[serde(rename = "ForecastNonConsolidatedOperatingProfit")]
-    pub forecast_non_consolidated_operating_profit: String,
+    pub forecast_non_consolidated_operating_profit: FinancialFigure,
 
     /// Forecast Non-Consolidated Ordinary Profit at Fiscal Year End
     #[serde(rename = "ForecastNonConsolidatedOrdinaryProfit")]
-    pub forecast_non_consolidated_ordinary_profit: String,
+    pub forecast_non_consolidated_ordinary_profit: FinancialFigure,
 
     /// Forecast Non-Consolidated Profit at Fiscal Year End
     #[serde(rename = "ForecastNonConsolidatedProfit")]
-    pub forecast_non_consolidated_profit: String,
+    pub forecast_non_consolidated_profit: FinancialFigure,
 
     /// Forecast Non-Consolidated Earnings Per Share at Fiscal Year End
     #[serde(rename = "ForecastNonConsolidatedEarningsPerShare")]
-    pub forecast_non_consolidated_earnings_per_share: String,
+    pub forecast_non_consolidated_earnings_per_share: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Net Sales at Fiscal Year End
     #[serde(rename = "NextYearForecastNonConsolidatedNetSales")]
-    pub next_year_forecast_non_consolidated_net_sales: String,
+    pub next_year_forecast_non_consolidated_net_sales: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Operating Profit at Fiscal Year End
     #[serde(rename = "NextYearForecastNonConsolidatedOperatingProfit")]
-    pub next_year_forecast_non_consolidated_operating_profit: String,
+    pub next_year_forecast_non_consolidated_operating_profit: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Ordinary Profit at Fiscal Year End
     #[serde(rename = "NextYearForecastNonConsolidatedOrdinaryProfit")]
-    pub next_year_forecast_non_consolidated_ordinary_profit: String,
+    pub next_year_forecast_non_consolidated_ordinary_profit: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Profit at Fiscal Year End
     #[serde(rename = "NextYearForecastNonConsolidatedProfit")]
-    pub next_year_forecast_non_consolidated_profit: String,
+    pub next_year_forecast_non_consolidated_profit: FinancialFigure,
 
     /// Next Year Forecast Non-Consolidated Earnings Per Share at Fiscal Year End
     #[serde(rename = "NextYearForecastNonConsolidatedEarningsPerShare")]
-    pub next_year_forecast_non_consolidated_earnings_per_share: String,
+    pub next_year_forecast_non_consolidated_earnings_per_share: FinancialFigure,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(feature = "chrono-types")]
+impl FinancialStatementItem {
+    /// Get the disclosed date and time in JST.
+    pub fn disclosed_at(&self) -> Result<chrono::NaiveDateTime, chrono::ParseError> {
+        Ok(self
+            .disclosed_date_parsed()?
+            .and_time(self.disclosed_time_parsed()?))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_financial_statements_response() {
@@ -688,120 +743,133 @@ mod tests {
         "#;
 
         let response: FinancialStatementsResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = FinancialStatementsResponse {
             statements: vec![FinancialStatementItem {
-                disclosed_date: "2023-01-30".to_string(),
-                disclosed_time: "12:00:00".to_string(),
-                local_code: "86970".to_string(),
+                disclosed_date: "2023-01-30".parse().unwrap(),
+                disclosed_time: "12:00:00".parse().unwrap(),
+                local_code: "86970".parse().unwrap(),
                 disclosure_number: "20230127594871".to_string(),
                 type_of_document: TypeOfDocument::Q3FinancialStatementsConsolidatedIFRS,
                 type_of_current_period: AccountingPeriod::Q3,
-                current_period_start_date: "2022-04-01".to_string(),
-                current_period_end_date: "2022-12-31".to_string(),
-                current_fiscal_year_start_date: "2022-04-01".to_string(),
-                current_fiscal_year_end_date: "2023-03-31".to_string(),
+                current_period_start_date: "2022-04-01".parse().unwrap(),
+                current_period_end_date: "2022-12-31".parse().unwrap(),
+                current_fiscal_year_start_date: "2022-04-01".parse().unwrap(),
+                current_fiscal_year_end_date: "2023-03-31".parse().unwrap(),
                 next_fiscal_year_start_date: None,
                 next_fiscal_year_end_date: None,
-                net_sales: "100529000000".to_string(),
-                operating_profit: "51765000000".to_string(),
-                ordinary_profit: "".to_string(),
-                profit: "35175000000".to_string(),
-                earnings_per_share: "66.76".to_string(),
-                diluted_earnings_per_share: "".to_string(),
-                total_assets: "79205861000000".to_string(),
-                equity: "320021000000".to_string(),
-                equity_to_asset_ratio: "0.004".to_string(),
-                book_value_per_share: "".to_string(),
-                cash_flows_from_operating_activities: "".to_string(),
-                cash_flows_from_investing_activities: "".to_string(),
-                cash_flows_from_financing_activities: "".to_string(),
-                cash_and_equivalents: "91135000000".to_string(),
-                result_dividend_per_share_1st_quarter: "".to_string(),
-                result_dividend_per_share_2nd_quarter: "26.0".to_string(),
-                result_dividend_per_share_3rd_quarter: "".to_string(),
-                result_dividend_per_share_fiscal_year_end: "".to_string(),
-                result_dividend_per_share_annual: "".to_string(),
-                distributions_per_unit_reit: "".to_string(),
-                result_total_dividend_paid_annual: "".to_string(),
-                result_payout_ratio_annual: "".to_string(),
-                forecast_dividend_per_share_1st_quarter: "".to_string(),
-                forecast_dividend_per_share_2nd_quarter: "".to_string(),
-                forecast_dividend_per_share_3rd_quarter: "".to_string(),
-                forecast_dividend_per_share_fiscal_year_end: "36.0".to_string(),
-                forecast_dividend_per_share_annual: "62.0".to_string(),
-                forecast_distributions_per_unit_reit: "".to_string(),
-                forecast_total_dividend_paid_annual: "".to_string(),
-                forecast_payout_ratio_annual: "".to_string(),
-                next_year_forecast_dividend_per_share_1st_quarter: "".to_string(),
-                next_year_forecast_dividend_per_share_2nd_quarter: "".to_string(),
-                next_year_forecast_dividend_per_share_3rd_quarter: "".to_string(),
-                next_year_forecast_dividend_per_share_fiscal_year_end: "".to_string(),
-                next_year_forecast_dividend_per_share_annual: "".to_string(),
-                next_year_forecast_distributions_per_unit_reit: "".to_string(),
-                next_year_forecast_payout_ratio_annual: "".to_string(),
-                forecast_net_sales_2nd_quarter: "".to_string(),
-                forecast_operating_profit_2nd_quarter: "".to_string(),
-                forecast_ordinary_profit_2nd_quarter: "".to_string(),
-                forecast_profit_2nd_quarter: "".to_string(),
-                forecast_earnings_per_share_2nd_quarter: "".to_string(),
-                next_year_forecast_net_sales_2nd_quarter: "".to_string(),
-                next_year_forecast_operating_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_ordinary_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_earnings_per_share_2nd_quarter: "".to_string(),
-                forecast_net_sales: "132500000000".to_string(),
-                forecast_operating_profit: "65500000000".to_string(),
-                forecast_ordinary_profit: "".to_string(),
-                forecast_profit: "45000000000".to_string(),
-                forecast_earnings_per_share: "85.42".to_string(),
-                next_year_forecast_net_sales: "".to_string(),
-                next_year_forecast_operating_profit: "".to_string(),
-                next_year_forecast_ordinary_profit: "".to_string(),
-                next_year_forecast_profit: "".to_string(),
-                next_year_forecast_earnings_per_share: "".to_string(),
+                net_sales: "100529000000".parse().unwrap(),
+                operating_profit: "51765000000".parse().unwrap(),
+                ordinary_profit: "".parse().unwrap(),
+                profit: "35175000000".parse().unwrap(),
+                earnings_per_share: "66.76".parse().unwrap(),
+                diluted_earnings_per_share: "".parse().unwrap(),
+                total_assets: "79205861000000".parse().unwrap(),
+                equity: "320021000000".parse().unwrap(),
+                equity_to_asset_ratio: "0.004".parse().unwrap(),
+                book_value_per_share: "".parse().unwrap(),
+                cash_flows_from_operating_activities: "".parse().unwrap(),
+                cash_flows_from_investing_activities: "".parse().unwrap(),
+                cash_flows_from_financing_activities: "".parse().unwrap(),
+                cash_and_equivalents: "91135000000".parse().unwrap(),
+                result_dividend_per_share_1st_quarter: "".parse().unwrap(),
+                result_dividend_per_share_2nd_quarter: "26.0".parse().unwrap(),
+                result_dividend_per_share_3rd_quarter: "".parse().unwrap(),
+                result_dividend_per_share_fiscal_year_end: "".parse().unwrap(),
+                result_dividend_per_share_annual: "".parse().unwrap(),
+                distributions_per_unit_reit: "".parse().unwrap(),
+                result_total_dividend_paid_annual: "".parse().unwrap(),
+                result_payout_ratio_annual: "".parse().unwrap(),
+                forecast_dividend_per_share_1st_quarter: "".parse().unwrap(),
+                forecast_dividend_per_share_2nd_quarter: "".parse().unwrap(),
+                forecast_dividend_per_share_3rd_quarter: "".parse().unwrap(),
+                forecast_dividend_per_share_fiscal_year_end: "36.0".parse().unwrap(),
+                forecast_dividend_per_share_annual: "62.0".parse().unwrap(),
+                forecast_distributions_per_unit_reit: "".parse().unwrap(),
+                forecast_total_dividend_paid_annual: "".parse().unwrap(),
+                forecast_payout_ratio_annual: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_1st_quarter: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_3rd_quarter: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_fiscal_year_end: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_annual: "".parse().unwrap(),
+                next_year_forecast_distributions_per_unit_reit: "".parse().unwrap(),
+                next_year_forecast_payout_ratio_annual: "".parse().unwrap(),
+                forecast_net_sales_2nd_quarter: "".parse().unwrap(),
+                forecast_operating_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_net_sales_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_operating_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                forecast_net_sales: "132500000000".parse().unwrap(),
+                forecast_operating_profit: "65500000000".parse().unwrap(),
+                forecast_ordinary_profit: "".parse().unwrap(),
+                forecast_profit: "45000000000".parse().unwrap(),
+                forecast_earnings_per_share: "85.42".parse().unwrap(),
+                next_year_forecast_net_sales: "".parse().unwrap(),
+                next_year_forecast_operating_profit: "".parse().unwrap(),
+                next_year_forecast_ordinary_profit: "".parse().unwrap(),
+                next_year_forecast_profit: "".parse().unwrap(),
+                next_year_forecast_earnings_per_share: "".parse().unwrap(),
                 material_changes_in_subsidiaries: "false".to_string(),
                 significant_changes_in_the_scope_of_consolidation: None,
                 changes_based_on_revisions_of_accounting_standard: "false".to_string(),
                 changes_other_than_based_on_revisions_of_accounting_standard: "false".to_string(),
                 changes_in_accounting_estimates: "true".to_string(),
                 retrospective_restatement: "".to_string(),
-                number_of_issued_and_outstanding_shares_at_the_end_of_fiscal_year_including_treasury_stock: "528578441".to_string(),
-                number_of_treasury_stock_at_the_end_of_fiscal_year: "1861043".to_string(),
-                average_number_of_shares: "526874759".to_string(),
-                non_consolidated_net_sales: "".to_string(),
-                non_consolidated_operating_profit: "".to_string(),
-                non_consolidated_ordinary_profit: "".to_string(),
-                non_consolidated_profit: "".to_string(),
-                non_consolidated_earnings_per_share: "".to_string(),
-                non_consolidated_total_assets: "".to_string(),
-                non_consolidated_equity: "".to_string(),
-                non_consolidated_equity_to_asset_ratio: "".to_string(),
-                non_consolidated_book_value_per_share: "".to_string(),
-                forecast_non_consolidated_net_sales_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_operating_profit_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_ordinary_profit_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_profit_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_earnings_per_share_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_net_sales_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_operating_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_ordinary_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_earnings_per_share_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_net_sales: "".to_string(),
-                forecast_non_consolidated_operating_profit: "".to_string(),
-                forecast_non_consolidated_ordinary_profit: "".to_string(),
-                forecast_non_consolidated_profit: "".to_string(),
-                forecast_non_consolidated_earnings_per_share: "".to_string(),
-                next_year_forecast_non_consolidated_net_sales: "".to_string(),
-                next_year_forecast_non_consolidated_operating_profit: "".to_string(),
-                next_year_forecast_non_consolidated_ordinary_profit: "".to_string(),
-                next_year_forecast_non_consolidated_profit: "".to_string(),
-                next_year_forecast_non_consolidated_earnings_per_share: "".to_string(),
+                number_of_issued_and_outstanding_shares_at_the_end_of_fiscal_year_including_treasury_stock: "528578441".parse().unwrap(),
+                number_of_treasury_stock_at_the_end_of_fiscal_year: "1861043".parse().unwrap(),
+                average_number_of_shares: "526874759".parse().unwrap(),
+                non_consolidated_net_sales: "".parse().unwrap(),
+                non_consolidated_operating_profit: "".parse().unwrap(),
+                non_consolidated_ordinary_profit: "".parse().unwrap(),
+                non_consolidated_profit: "".parse().unwrap(),
+                non_consolidated_earnings_per_share: "".parse().unwrap(),
+                non_consolidated_total_assets: "".parse().unwrap(),
+                non_consolidated_equity: "".parse().unwrap(),
+                non_consolidated_equity_to_asset_ratio: "".parse().unwrap(),
+                non_consolidated_book_value_per_share: "".parse().unwrap(),
+                forecast_non_consolidated_net_sales_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_operating_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_net_sales_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_operating_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_net_sales: "".parse().unwrap(),
+                forecast_non_consolidated_operating_profit: "".parse().unwrap(),
+                forecast_non_consolidated_ordinary_profit: "".parse().unwrap(),
+                forecast_non_consolidated_profit: "".parse().unwrap(),
+                forecast_non_consolidated_earnings_per_share: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_net_sales: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_operating_profit: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_ordinary_profit: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_profit: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_earnings_per_share: "".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
 
         pretty_assertions::assert_eq!(response, expected_response);
+
+        #[cfg(feature = "chrono-types")]
+        assert_eq!(
+            response.statements[0].disclosed_at(),
+            Ok(chrono::NaiveDate::from_ymd_opt(2023, 1, 30)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap())
+        );
     }
 
     #[test]
@@ -923,115 +991,119 @@ mod tests {
         "#;
 
         let response: FinancialStatementsResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = FinancialStatementsResponse {
             statements: vec![FinancialStatementItem {
-                disclosed_date: "2023-01-30".to_string(),
-                disclosed_time: "12:00:00".to_string(),
-                local_code: "86970".to_string(),
+                disclosed_date: "2023-01-30".parse().unwrap(),
+                disclosed_time: "12:00:00".parse().unwrap(),
+                local_code: "86970".parse().unwrap(),
                 disclosure_number: "20230127594871".to_string(),
                 type_of_document: TypeOfDocument::Q3FinancialStatementsConsolidatedIFRS,
                 type_of_current_period: AccountingPeriod::Q3,
-                current_period_start_date: "2022-04-01".to_string(),
-                current_period_end_date: "2022-12-31".to_string(),
-                current_fiscal_year_start_date: "2022-04-01".to_string(),
-                current_fiscal_year_end_date: "2023-03-31".to_string(),
-                next_fiscal_year_start_date: Some("2024-11-01".to_string()),
-                next_fiscal_year_end_date: Some("2024-11-01".to_string()),
-                net_sales: "100529000000".to_string(),
-                operating_profit: "51765000000".to_string(),
-                ordinary_profit: "".to_string(),
-                profit: "35175000000".to_string(),
-                earnings_per_share: "66.76".to_string(),
-                diluted_earnings_per_share: "".to_string(),
-                total_assets: "79205861000000".to_string(),
-                equity: "320021000000".to_string(),
-                equity_to_asset_ratio: "0.004".to_string(),
-                book_value_per_share: "".to_string(),
-                cash_flows_from_operating_activities: "".to_string(),
-                cash_flows_from_investing_activities: "".to_string(),
-                cash_flows_from_financing_activities: "".to_string(),
-                cash_and_equivalents: "91135000000".to_string(),
-                result_dividend_per_share_1st_quarter: "".to_string(),
-                result_dividend_per_share_2nd_quarter: "26.0".to_string(),
-                result_dividend_per_share_3rd_quarter: "".to_string(),
-                result_dividend_per_share_fiscal_year_end: "".to_string(),
-                result_dividend_per_share_annual: "".to_string(),
-                distributions_per_unit_reit: "".to_string(),
-                result_total_dividend_paid_annual: "".to_string(),
-                result_payout_ratio_annual: "".to_string(),
-                forecast_dividend_per_share_1st_quarter: "".to_string(),
-                forecast_dividend_per_share_2nd_quarter: "".to_string(),
-                forecast_dividend_per_share_3rd_quarter: "".to_string(),
-                forecast_dividend_per_share_fiscal_year_end: "36.0".to_string(),
-                forecast_dividend_per_share_annual: "62.0".to_string(),
-                forecast_distributions_per_unit_reit: "".to_string(),
-                forecast_total_dividend_paid_annual: "".to_string(),
-                forecast_payout_ratio_annual: "".to_string(),
-                next_year_forecast_dividend_per_share_1st_quarter: "".to_string(),
-                next_year_forecast_dividend_per_share_2nd_quarter: "".to_string(),
-                next_year_forecast_dividend_per_share_3rd_quarter: "".to_string(),
-                next_year_forecast_dividend_per_share_fiscal_year_end: "".to_string(),
-                next_year_forecast_dividend_per_share_annual: "".to_string(),
-                next_year_forecast_distributions_per_unit_reit: "".to_string(),
-                next_year_forecast_payout_ratio_annual: "".to_string(),
-                forecast_net_sales_2nd_quarter: "".to_string(),
-                forecast_operating_profit_2nd_quarter: "".to_string(),
-                forecast_ordinary_profit_2nd_quarter: "".to_string(),
-                forecast_profit_2nd_quarter: "".to_string(),
-                forecast_earnings_per_share_2nd_quarter: "".to_string(),
-                next_year_forecast_net_sales_2nd_quarter: "".to_string(),
-                next_year_forecast_operating_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_ordinary_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_earnings_per_share_2nd_quarter: "".to_string(),
-                forecast_net_sales: "132500000000".to_string(),
-                forecast_operating_profit: "65500000000".to_string(),
-                forecast_ordinary_profit: "".to_string(),
-                forecast_profit: "45000000000".to_string(),
-                forecast_earnings_per_share: "85.42".to_string(),
-                next_year_forecast_net_sales: "".to_string(),
-                next_year_forecast_operating_profit: "".to_string(),
-                next_year_forecast_ordinary_profit: "".to_string(),
-                next_year_forecast_profit: "".to_string(),
-                next_year_forecast_earnings_per_share: "".to_string(),
+                current_period_start_date: "2022-04-01".parse().unwrap(),
+                current_period_end_date: "2022-12-31".parse().unwrap(),
+                current_fiscal_year_start_date: "2022-04-01".parse().unwrap(),
+                current_fiscal_year_end_date: "2023-03-31".parse().unwrap(),
+                next_fiscal_year_start_date: Some("2024-11-01".parse().unwrap()),
+                next_fiscal_year_end_date: Some("2024-11-01".parse().unwrap()),
+                net_sales: "100529000000".parse().unwrap(),
+                operating_profit: "51765000000".parse().unwrap(),
+                ordinary_profit: "".parse().unwrap(),
+                profit: "35175000000".parse().unwrap(),
+                earnings_per_share: "66.76".parse().unwrap(),
+                diluted_earnings_per_share: "".parse().unwrap(),
+                total_assets: "79205861000000".parse().unwrap(),
+                equity: "320021000000".parse().unwrap(),
+                equity_to_asset_ratio: "0.004".parse().unwrap(),
+                book_value_per_share: "".parse().unwrap(),
+                cash_flows_from_operating_activities: "".parse().unwrap(),
+                cash_flows_from_investing_activities: "".parse().unwrap(),
+                cash_flows_from_financing_activities: "".parse().unwrap(),
+                cash_and_equivalents: "91135000000".parse().unwrap(),
+                result_dividend_per_share_1st_quarter: "".parse().unwrap(),
+                result_dividend_per_share_2nd_quarter: "26.0".parse().unwrap(),
+                result_dividend_per_share_3rd_quarter: "".parse().unwrap(),
+                result_dividend_per_share_fiscal_year_end: "".parse().unwrap(),
+                result_dividend_per_share_annual: "".parse().unwrap(),
+                distributions_per_unit_reit: "".parse().unwrap(),
+                result_total_dividend_paid_annual: "".parse().unwrap(),
+                result_payout_ratio_annual: "".parse().unwrap(),
+                forecast_dividend_per_share_1st_quarter: "".parse().unwrap(),
+                forecast_dividend_per_share_2nd_quarter: "".parse().unwrap(),
+                forecast_dividend_per_share_3rd_quarter: "".parse().unwrap(),
+                forecast_dividend_per_share_fiscal_year_end: "36.0".parse().unwrap(),
+                forecast_dividend_per_share_annual: "62.0".parse().unwrap(),
+                forecast_distributions_per_unit_reit: "".parse().unwrap(),
+                forecast_total_dividend_paid_annual: "".parse().unwrap(),
+                forecast_payout_ratio_annual: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_1st_quarter: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_3rd_quarter: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_fiscal_year_end: "".parse().unwrap(),
+                next_year_forecast_dividend_per_share_annual: "".parse().unwrap(),
+                next_year_forecast_distributions_per_unit_reit: "".parse().unwrap(),
+                next_year_forecast_payout_ratio_annual: "".parse().unwrap(),
+                forecast_net_sales_2nd_quarter: "".parse().unwrap(),
+                forecast_operating_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_net_sales_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_operating_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                forecast_net_sales: "132500000000".parse().unwrap(),
+                forecast_operating_profit: "65500000000".parse().unwrap(),
+                forecast_ordinary_profit: "".parse().unwrap(),
+                forecast_profit: "45000000000".parse().unwrap(),
+                forecast_earnings_per_share: "85.42".parse().unwrap(),
+                next_year_forecast_net_sales: "".parse().unwrap(),
+                next_year_forecast_operating_profit: "".parse().unwrap(),
+                next_year_forecast_ordinary_profit: "".parse().unwrap(),
+                next_year_forecast_profit: "".parse().unwrap(),
+                next_year_forecast_earnings_per_share: "".parse().unwrap(),
                 material_changes_in_subsidiaries: "false".to_string(),
                 significant_changes_in_the_scope_of_consolidation: Some("2024-11-01".to_string()),
                 changes_based_on_revisions_of_accounting_standard: "false".to_string(),
                 changes_other_than_based_on_revisions_of_accounting_standard: "false".to_string(),
                 changes_in_accounting_estimates: "true".to_string(),
                 retrospective_restatement: "".to_string(),
-                number_of_issued_and_outstanding_shares_at_the_end_of_fiscal_year_including_treasury_stock: "528578441".to_string(),
-                number_of_treasury_stock_at_the_end_of_fiscal_year: "1861043".to_string(),
-                average_number_of_shares: "526874759".to_string(),
-                non_consolidated_net_sales: "".to_string(),
-                non_consolidated_operating_profit: "".to_string(),
-                non_consolidated_ordinary_profit: "".to_string(),
-                non_consolidated_profit: "".to_string(),
-                non_consolidated_earnings_per_share: "".to_string(),
-                non_consolidated_total_assets: "".to_string(),
-                non_consolidated_equity: "".to_string(),
-                non_consolidated_equity_to_asset_ratio: "".to_string(),
-                non_consolidated_book_value_per_share: "".to_string(),
-                forecast_non_consolidated_net_sales_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_operating_profit_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_ordinary_profit_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_profit_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_earnings_per_share_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_net_sales_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_operating_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_ordinary_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_profit_2nd_quarter: "".to_string(),
-                next_year_forecast_non_consolidated_earnings_per_share_2nd_quarter: "".to_string(),
-                forecast_non_consolidated_net_sales: "".to_string(),
-                forecast_non_consolidated_operating_profit: "".to_string(),
-                forecast_non_consolidated_ordinary_profit: "".to_string(),
-                forecast_non_consolidated_profit: "".to_string(),
-                forecast_non_consolidated_earnings_per_share: "".to_string(),
-                next_year_forecast_non_consolidated_net_sales: "".to_string(),
-                next_year_forecast_non_consolidated_operating_profit: "".to_string(),
-                next_year_forecast_non_consolidated_ordinary_profit: "".to_string(),
-                next_year_forecast_non_consolidated_profit: "".to_string(),
-                next_year_forecast_non_consolidated_earnings_per_share: "".to_string(),
+                number_of_issued_and_outstanding_shares_at_the_end_of_fiscal_year_including_treasury_stock: "528578441".parse().unwrap(),
+                number_of_treasury_stock_at_the_end_of_fiscal_year: "1861043".parse().unwrap(),
+                average_number_of_shares: "526874759".parse().unwrap(),
+                non_consolidated_net_sales: "".parse().unwrap(),
+                non_consolidated_operating_profit: "".parse().unwrap(),
+                non_consolidated_ordinary_profit: "".parse().unwrap(),
+                non_consolidated_profit: "".parse().unwrap(),
+                non_consolidated_earnings_per_share: "".parse().unwrap(),
+                non_consolidated_total_assets: "".parse().unwrap(),
+                non_consolidated_equity: "".parse().unwrap(),
+                non_consolidated_equity_to_asset_ratio: "".parse().unwrap(),
+                non_consolidated_book_value_per_share: "".parse().unwrap(),
+                forecast_non_consolidated_net_sales_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_operating_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_profit_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_net_sales_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_operating_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_ordinary_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_profit_2nd_quarter: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_earnings_per_share_2nd_quarter: "".parse().unwrap(),
+                forecast_non_consolidated_net_sales: "".parse().unwrap(),
+                forecast_non_consolidated_operating_profit: "".parse().unwrap(),
+                forecast_non_consolidated_ordinary_profit: "".parse().unwrap(),
+                forecast_non_consolidated_profit: "".parse().unwrap(),
+                forecast_non_consolidated_earnings_per_share: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_net_sales: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_operating_profit: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_ordinary_profit: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_profit: "".parse().unwrap(),
+                next_year_forecast_non_consolidated_earnings_per_share: "".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -1048,6 +1120,8 @@ mod tests {
         "#;
 
         let response: FinancialStatementsResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = FinancialStatementsResponse {
             statements: vec![],
             pagination_key: None,
@@ -1,22 +1,32 @@
 //! Futures OHLC (/derivatives/futures) API
 
+use futures::Stream;
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
-        deserialize_utils::{deserialize_f64_or_none, empty_string_or_null_as_none},
+        deserialize_utils::{deserialize_number_or_none, empty_string_or_null_as_none},
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            date_iter::DateIterable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
         types::{
             central_contract_month_flag::CentralContractMonthFlag,
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
             emergency_margin_trigger_division::EmergencyMarginTriggerDivision,
             futures_code::FuturesCode,
+            query_date::{IntoQueryDate, QueryDate},
         },
     },
+    trading_calendar::TradingCalendarApi,
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Futures (OHLC) Data API.
 #[derive(Clone, Serialize)]
@@ -29,24 +39,38 @@ pub struct FuturesPricesBuilder {
     category: Option<FuturesCode>,
 
     /// Date of data (e.g., "20210901" or "2021-09-01")
-    date: String,
+    date: QueryDate,
 
     /// Central contract month flag
     #[serde(skip_serializing_if = "Option::is_none")]
-    central_contract_month_flag: Option<String>,
+    central_contract_month_flag: Option<CentralContractMonthFlag>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for FuturesPricesBuilder {
+    const PATH: &'static str = "derivatives/futures";
+}
+
 impl JQuantsBuilder<FuturesPricesResponse> for FuturesPricesBuilder {
     async fn send(self) -> Result<FuturesPricesResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
     async fn send_ref(&self) -> Result<FuturesPricesResponse, crate::JQuantsError> {
-        self.client.inner.get("derivatives/futures", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<FuturesPricesResponse> for FuturesPricesBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<FuturesPricesResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -57,9 +81,16 @@ impl Paginatable<FuturesPricesResponse> for FuturesPricesBuilder {
     }
 }
 
+impl DateIterable<FuturesPricesResponse> for FuturesPricesBuilder {
+    fn with_date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = date.into_query_date();
+        self
+    }
+}
+
 impl FuturesPricesBuilder {
     /// Create a new builder.
-    pub(crate) fn new(client: JQuantsApiClient, date: String) -> Self {
+    pub(crate) fn new(client: JQuantsApiClient, date: QueryDate) -> Self {
         Self {
             client,
             category: None,
@@ -76,14 +107,14 @@ impl FuturesPricesBuilder {
     }
 
     /// Set the date of data (e.g., "20210901" or "2021-09-01")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = date.into();
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = date.into_query_date();
         self
     }
 
     /// Set the central contract month flag.
-    pub fn central_contract_month_flag(mut self, flag: impl Into<String>) -> Self {
-        self.central_contract_month_flag = Some(flag.into());
+    pub fn central_contract_month_flag(mut self, flag: CentralContractMonthFlag) -> Self {
+        self.central_contract_month_flag = Some(flag);
         self
     }
 
@@ -99,45 +130,46 @@ pub trait FuturesPricesApi: JQuantsPlanClient {
     /// Get API builder for Futures (OHLC) Data.
     ///
     /// Use [Futures (OHLC) (/derivatives/futures) API](https://jpx.gitbook.io/j-quants-en/api-reference/futures)
-    fn get_futures_prices(&self, date: impl Into<String>) -> FuturesPricesBuilder {
-        FuturesPricesBuilder::new(self.get_api_client().clone(), date.into())
+    fn get_futures_prices(&self, date: impl IntoQueryDate) -> FuturesPricesBuilder {
+        FuturesPricesBuilder::new(self.get_api_client().clone(), date.into_query_date())
+    }
+
+    /// Get futures prices for each trading day from `from` to `to` (e.g., "20210901" or "2021-09-01").
+    ///
+    /// The trading days are looked up with [`TradingCalendarApi`] and non-business days are skipped.
+    /// `params` sets the params shared by all days (e.g. `category`).
+    /// The date of the builder is `from` until it is set to each trading day.
+    /// See [`DateIterable::fetch_dates_stream`] for the responses.
+    fn get_futures_prices_range(
+        &self,
+        from: impl IntoQueryDate,
+        to: impl IntoQueryDate,
+        params: impl FnOnce(FuturesPricesBuilder) -> FuturesPricesBuilder,
+    ) -> impl Stream<Item = Result<FuturesPricesResponse, crate::JQuantsError>>
+    where
+        Self: TradingCalendarApi,
+    {
+        let from = from.into_query_date();
+        let calendar = self.get_trading_calendar().from(from.clone()).to(to);
+        params(self.get_futures_prices(from)).fetch_trading_days_stream(calendar)
     }
 }
 
 /// Futures (OHLC) Data API response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/futures)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct FuturesPricesResponse {
     /// List of Futures prices
+    #[paginated(items)]
     pub futures: Vec<FuturesPricesItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for FuturesPricesResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for FuturesPricesResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.futures.extend(p.futures);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single Futures price record.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct FuturesPricesItem {
     /// Issue code
     #[serde(rename = "Code")]
@@ -149,95 +181,95 @@ pub struct FuturesPricesItem {
 
     /// Trading day (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Whole day open price
     #[serde(rename = "WholeDayOpen")]
-    pub whole_day_open: f64,
+    pub whole_day_open: ResponseDecimal,
 
     /// Whole day high price
     #[serde(rename = "WholeDayHigh")]
-    pub whole_day_high: f64,
+    pub whole_day_high: ResponseDecimal,
 
     /// Whole day low price
     #[serde(rename = "WholeDayLow")]
-    pub whole_day_low: f64,
+    pub whole_day_low: ResponseDecimal,
 
     /// Whole day close price
     #[serde(rename = "WholeDayClose")]
-    pub whole_day_close: f64,
+    pub whole_day_close: ResponseDecimal,
 
     /// Morning session open price
     #[serde(
         rename = "MorningSessionOpen",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_open: Option<f64>,
+    pub morning_session_open: Option<ResponseDecimal>,
 
     /// Morning session high price
     #[serde(
         rename = "MorningSessionHigh",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_high: Option<f64>,
+    pub morning_session_high: Option<ResponseDecimal>,
 
     /// Morning session low price
     #[serde(
         rename = "MorningSessionLow",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_low: Option<f64>,
+    pub morning_session_low: Option<ResponseDecimal>,
 
     /// Morning session close price
     #[serde(
         rename = "MorningSessionClose",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_close: Option<f64>,
+    pub morning_session_close: Option<ResponseDecimal>,
 
     /// Night session open price
     #[serde(
         rename = "NightSessionOpen",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_open: Option<f64>,
+    pub night_session_open: Option<ResponseDecimal>,
 
     /// Night session high price
     #[serde(
         rename = "NightSessionHigh",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_high: Option<f64>,
+    pub night_session_high: Option<ResponseDecimal>,
 
     /// Night session low price
     #[serde(
         rename = "NightSessionLow",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_low: Option<f64>,
+    pub night_session_low: Option<ResponseDecimal>,
 
     /// Night session close price
     #[serde(
         rename = "NightSessionClose",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_close: Option<f64>,
+    pub night_session_close: Option<ResponseDecimal>,
 
     /// Day session open price
     #[serde(rename = "DaySessionOpen")]
-    pub day_session_open: f64,
+    pub day_session_open: ResponseDecimal,
 
     /// Day session high price
     #[serde(rename = "DaySessionHigh")]
-    pub day_session_high: f64,
+    pub day_session_high: ResponseDecimal,
 
     /// Day session low price
     #[serde(rename = "DaySessionLow")]
-    pub day_session_low: f64,
+    pub day_session_low: ResponseDecimal,
 
     /// Day session close price
     #[serde(rename = "DaySessionClose")]
-    pub day_session_close: f64,
+    pub day_session_close: ResponseDecimal,
 
     /// Volume
     #[serde(rename = "Volume")]
@@ -249,7 +281,7 @@ pub struct FuturesPricesItem {
 
     /// Turnover value
     #[serde(rename = "TurnoverValue")]
-    pub turnover_value: f64,
+    pub turnover_value: ResponseDecimal,
 
     /// Contract month (YYYY-MM)
     #[serde(rename = "ContractMonth")]
@@ -258,7 +290,7 @@ pub struct FuturesPricesItem {
     /// Volume only auction
     #[serde(
         rename = "Volume(OnlyAuction)",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
     pub volume_only_auction: Option<f64>,
 
@@ -271,21 +303,21 @@ pub struct FuturesPricesItem {
         rename = "LastTradingDay",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub last_trading_day: Option<String>,
+    pub last_trading_day: Option<ResponseDate>,
 
     /// Special quotation day (YYYY-MM-DD)
     #[serde(
         rename = "SpecialQuotationDay",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub special_quotation_day: Option<String>,
+    pub special_quotation_day: Option<ResponseDate>,
 
     /// Settlement price
     #[serde(
         rename = "SettlementPrice",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub settlement_price: Option<f64>,
+    pub settlement_price: Option<ResponseDecimal>,
 
     /// Central contract month flag
     #[serde(
@@ -293,11 +325,17 @@ pub struct FuturesPricesItem {
         deserialize_with = "empty_string_or_null_as_none"
     )]
     pub central_contract_month_flag: Option<CentralContractMonthFlag>,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_futures_prices_response() {
@@ -342,36 +380,40 @@ mod tests {
 
         let response: FuturesPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_futures = vec![FuturesPricesItem {
             code: "169090005".to_string(),
             derivatives_product_category: "TOPIXF".to_string(),
-            date: "2024-07-23".to_string(),
-            whole_day_open: 2825.5,
-            whole_day_high: 2853.0,
-            whole_day_low: 2825.5,
-            whole_day_close: 2829.0,
+            date: "2024-07-23".parse().unwrap(),
+            whole_day_open: "2825.5".parse().unwrap(),
+            whole_day_high: "2853.0".parse().unwrap(),
+            whole_day_low: "2825.5".parse().unwrap(),
+            whole_day_close: "2829.0".parse().unwrap(),
             morning_session_open: None,
             morning_session_high: None,
             morning_session_low: None,
             morning_session_close: None,
-            night_session_open: Some(2825.5),
-            night_session_high: Some(2850.0),
-            night_session_low: Some(2825.5),
-            night_session_close: Some(2845.0),
-            day_session_open: 2850.5,
-            day_session_high: 2853.0,
-            day_session_low: 2826.0,
-            day_session_close: 2829.0,
+            night_session_open: Some("2825.5".parse().unwrap()),
+            night_session_high: Some("2850.0".parse().unwrap()),
+            night_session_low: Some("2825.5".parse().unwrap()),
+            night_session_close: Some("2845.0".parse().unwrap()),
+            day_session_open: "2850.5".parse().unwrap(),
+            day_session_high: "2853.0".parse().unwrap(),
+            day_session_low: "2826.0".parse().unwrap(),
+            day_session_close: "2829.0".parse().unwrap(),
             volume: 42910.0,
             open_interest: 479812.0,
-            turnover_value: 1217918971856.0,
+            turnover_value: "1217918971856.0".parse().unwrap(),
             contract_month: "2024-09".to_string(),
             volume_only_auction: Some(40405.0),
             emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Calculated,
-            last_trading_day: Some("2024-09-12".to_string()),
-            special_quotation_day: Some("2024-09-13".to_string()),
-            settlement_price: Some(2829.0),
+            last_trading_day: Some("2024-09-12".parse().unwrap()),
+            special_quotation_day: Some("2024-09-13".parse().unwrap()),
+            settlement_price: Some("2829.0".parse().unwrap()),
             central_contract_month_flag: Some(CentralContractMonthFlag::CentralContractMonth),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = FuturesPricesResponse {
@@ -425,14 +467,16 @@ mod tests {
 
         let response: FuturesPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_futures = vec![FuturesPricesItem {
             code: "169090005".to_string(),
             derivatives_product_category: "TOPIXF".to_string(),
-            date: "2024-07-23".to_string(),
-            whole_day_open: 2825.5,
-            whole_day_high: 2853.0,
-            whole_day_low: 2825.5,
-            whole_day_close: 2829.0,
+            date: "2024-07-23".parse().unwrap(),
+            whole_day_open: "2825.5".parse().unwrap(),
+            whole_day_high: "2853.0".parse().unwrap(),
+            whole_day_low: "2825.5".parse().unwrap(),
+            whole_day_close: "2829.0".parse().unwrap(),
             morning_session_open: None,
             morning_session_high: None,
             morning_session_low: None,
@@ -441,13 +485,13 @@ mod tests {
             night_session_high: None,
             night_session_low: None,
             night_session_close: None,
-            day_session_open: 2850.5,
-            day_session_high: 2853.0,
-            day_session_low: 2826.0,
-            day_session_close: 2829.0,
+            day_session_open: "2850.5".parse().unwrap(),
+            day_session_high: "2853.0".parse().unwrap(),
+            day_session_low: "2826.0".parse().unwrap(),
+            day_session_close: "2829.0".parse().unwrap(),
             volume: 42910.0,
             open_interest: 479812.0,
-            turnover_value: 1217918971856.0,
+            turnover_value: "1217918971856.0".parse().unwrap(),
             contract_month: "2024-09".to_string(),
             volume_only_auction: None,
             emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Calculated,
@@ -455,6 +499,8 @@ mod tests {
             special_quotation_day: None,
             settlement_price: None,
             central_contract_month_flag: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = FuturesPricesResponse {
@@ -539,68 +585,74 @@ mod tests {
 
         let response: FuturesPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_futures = vec![
             FuturesPricesItem {
                 code: "169090005".to_string(),
                 derivatives_product_category: "TOPIXF".to_string(),
-                date: "2024-07-23".to_string(),
-                whole_day_open: 2825.5,
-                whole_day_high: 2853.0,
-                whole_day_low: 2825.5,
-                whole_day_close: 2829.0,
+                date: "2024-07-23".parse().unwrap(),
+                whole_day_open: "2825.5".parse().unwrap(),
+                whole_day_high: "2853.0".parse().unwrap(),
+                whole_day_low: "2825.5".parse().unwrap(),
+                whole_day_close: "2829.0".parse().unwrap(),
                 morning_session_open: None,
                 morning_session_high: None,
                 morning_session_low: None,
                 morning_session_close: None,
-                night_session_open: Some(2825.5),
-                night_session_high: Some(2850.0),
-                night_session_low: Some(2825.5),
-                night_session_close: Some(2845.0),
-                day_session_open: 2850.5,
-                day_session_high: 2853.0,
-                day_session_low: 2826.0,
-                day_session_close: 2829.0,
+                night_session_open: Some("2825.5".parse().unwrap()),
+                night_session_high: Some("2850.0".parse().unwrap()),
+                night_session_low: Some("2825.5".parse().unwrap()),
+                night_session_close: Some("2845.0".parse().unwrap()),
+                day_session_open: "2850.5".parse().unwrap(),
+                day_session_high: "2853.0".parse().unwrap(),
+                day_session_low: "2826.0".parse().unwrap(),
+                day_session_close: "2829.0".parse().unwrap(),
                 volume: 42910.0,
                 open_interest: 479812.0,
-                turnover_value: 1217918971856.0,
+                turnover_value: "1217918971856.0".parse().unwrap(),
                 contract_month: "2024-09".to_string(),
                 volume_only_auction: Some(40405.0),
                 emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Calculated,
-                last_trading_day: Some("2024-09-12".to_string()),
-                special_quotation_day: Some("2024-09-13".to_string()),
-                settlement_price: Some(2829.0),
+                last_trading_day: Some("2024-09-12".parse().unwrap()),
+                special_quotation_day: Some("2024-09-13".parse().unwrap()),
+                settlement_price: Some("2829.0".parse().unwrap()),
                 central_contract_month_flag: Some(CentralContractMonthFlag::CentralContractMonth),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
             FuturesPricesItem {
                 code: "169090006".to_string(),
                 derivatives_product_category: "NK225F".to_string(),
-                date: "2024-07-24".to_string(),
-                whole_day_open: 3000.0,
-                whole_day_high: 3050.0,
-                whole_day_low: 2950.0,
-                whole_day_close: 3025.0,
-                morning_session_open: Some(3010.0),
-                morning_session_high: Some(3040.0),
-                morning_session_low: Some(2955.0),
-                morning_session_close: Some(3030.0),
-                night_session_open: Some(3025.5),
-                night_session_high: Some(3050.0),
-                night_session_low: Some(3000.0),
-                night_session_close: Some(3045.0),
-                day_session_open: 3050.5,
-                day_session_high: 3053.0,
-                day_session_low: 3006.0,
-                day_session_close: 3029.0,
+                date: "2024-07-24".parse().unwrap(),
+                whole_day_open: "3000.0".parse().unwrap(),
+                whole_day_high: "3050.0".parse().unwrap(),
+                whole_day_low: "2950.0".parse().unwrap(),
+                whole_day_close: "3025.0".parse().unwrap(),
+                morning_session_open: Some("3010.0".parse().unwrap()),
+                morning_session_high: Some("3040.0".parse().unwrap()),
+                morning_session_low: Some("2955.0".parse().unwrap()),
+                morning_session_close: Some("3030.0".parse().unwrap()),
+                night_session_open: Some("3025.5".parse().unwrap()),
+                night_session_high: Some("3050.0".parse().unwrap()),
+                night_session_low: Some("3000.0".parse().unwrap()),
+                night_session_close: Some("3045.0".parse().unwrap()),
+                day_session_open: "3050.5".parse().unwrap(),
+                day_session_high: "3053.0".parse().unwrap(),
+                day_session_low: "3006.0".parse().unwrap(),
+                day_session_close: "3029.0".parse().unwrap(),
                 volume: 52910.0,
                 open_interest: 579812.0,
-                turnover_value: 1317918971856.0,
+                turnover_value: "1317918971856.0".parse().unwrap(),
                 contract_month: "2024-10".to_string(),
                 volume_only_auction: Some(50405.0),
                 emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Triggered,
-                last_trading_day: Some("2024-10-12".to_string()),
-                special_quotation_day: Some("2024-10-13".to_string()),
-                settlement_price: Some(3029.0),
+                last_trading_day: Some("2024-10-12".parse().unwrap()),
+                special_quotation_day: Some("2024-10-13".parse().unwrap()),
+                settlement_price: Some("3029.0".parse().unwrap()),
                 central_contract_month_flag: Some(CentralContractMonthFlag::Others),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
         ];
 
@@ -654,36 +706,40 @@ mod tests {
 
         let response: FuturesPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_futures = vec![FuturesPricesItem {
             code: "169090005".to_string(),
             derivatives_product_category: "TOPIXF".to_string(),
-            date: "2024-07-23".to_string(),
-            whole_day_open: 2825.5,
-            whole_day_high: 2853.0,
-            whole_day_low: 2825.5,
-            whole_day_close: 2829.0,
+            date: "2024-07-23".parse().unwrap(),
+            whole_day_open: "2825.5".parse().unwrap(),
+            whole_day_high: "2853.0".parse().unwrap(),
+            whole_day_low: "2825.5".parse().unwrap(),
+            whole_day_close: "2829.0".parse().unwrap(),
             morning_session_open: None,
             morning_session_high: None,
             morning_session_low: None,
             morning_session_close: None,
-            night_session_open: Some(2825.5),
-            night_session_high: Some(2850.0),
-            night_session_low: Some(2825.5),
-            night_session_close: Some(2845.0),
-            day_session_open: 2850.5,
-            day_session_high: 2853.0,
-            day_session_low: 2826.0,
-            day_session_close: 2829.0,
+            night_session_open: Some("2825.5".parse().unwrap()),
+            night_session_high: Some("2850.0".parse().unwrap()),
+            night_session_low: Some("2825.5".parse().unwrap()),
+            night_session_close: Some("2845.0".parse().unwrap()),
+            day_session_open: "2850.5".parse().unwrap(),
+            day_session_high: "2853.0".parse().unwrap(),
+            day_session_low: "2826.0".parse().unwrap(),
+            day_session_close: "2829.0".parse().unwrap(),
             volume: 42910.0,
             open_interest: 479812.0,
-            turnover_value: 1217918971856.0,
+            turnover_value: "1217918971856.0".parse().unwrap(),
             contract_month: "2024-09".to_string(),
             volume_only_auction: Some(40405.0),
             emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Calculated,
-            last_trading_day: Some("2024-09-12".to_string()),
-            special_quotation_day: Some("2024-09-13".to_string()),
-            settlement_price: Some(2829.0),
+            last_trading_day: Some("2024-09-12".parse().unwrap()),
+            special_quotation_day: Some("2024-09-13".parse().unwrap()),
+            settlement_price: Some("2829.0".parse().unwrap()),
             central_contract_month_flag: Some(CentralContractMonthFlag::CentralContractMonth),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = FuturesPricesResponse {
@@ -703,6 +759,8 @@ mod tests {
         "#;
 
         let response: FuturesPricesResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = FuturesPricesResponse {
             futures: vec![],
             pagination_key: None,
@@ -1,21 +1,31 @@
 //! Index Option Prices(OHLC)(/option/index_option) API
 
+use futures::Stream;
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
-        deserialize_utils::{deserialize_f64_or_none, empty_string_or_null_as_none},
+        deserialize_utils::{deserialize_number_or_none, empty_string_or_null_as_none},
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            date_iter::DateIterable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
         types::{
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
             emergency_margin_trigger_division::EmergencyMarginTriggerDivision,
             put_call_division::PutCallDivision,
+            query_date::{IntoQueryDate, QueryDate},
         },
     },
+    trading_calendar::TradingCalendarApi,
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Index Option Prices (OHLC) Data API.
 #[derive(Clone, Serialize)]
@@ -24,20 +34,34 @@ pub struct IndexOptionPricesBuilder {
     client: JQuantsApiClient,
 
     /// Date of data (e.g., "20210901" or "2021-09-01")
-    date: String,
+    date: QueryDate,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for IndexOptionPricesBuilder {
+    const PATH: &'static str = "option/index_option";
+}
+
 impl JQuantsBuilder<IndexOptionPricesResponse> for IndexOptionPricesBuilder {
     async fn send(self) -> Result<IndexOptionPricesResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
     async fn send_ref(&self) -> Result<IndexOptionPricesResponse, crate::JQuantsError> {
-        self.client.inner.get("option/index_option", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<IndexOptionPricesResponse> for IndexOptionPricesBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<IndexOptionPricesResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -48,9 +72,16 @@ impl Paginatable<IndexOptionPricesResponse> for IndexOptionPricesBuilder {
     }
 }
 
+impl DateIterable<IndexOptionPricesResponse> for IndexOptionPricesBuilder {
+    fn with_date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = date.into_query_date();
+        self
+    }
+}
+
 impl IndexOptionPricesBuilder {
     /// Create a new builder.
-    pub(crate) fn new(client: JQuantsApiClient, date: String) -> Self {
+    pub(crate) fn new(client: JQuantsApiClient, date: QueryDate) -> Self {
         Self {
             client,
             date,
@@ -59,8 +90,8 @@ impl IndexOptionPricesBuilder {
     }
 
     /// Set the date of data (e.g., "20210901" or "2021-09-01")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = date.into();
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = date.into_query_date();
         self
     }
 
@@ -76,49 +107,49 @@ pub trait IndexOptionPricesApi: JQuantsPlanClient {
     /// Get API builder for Index Option Prices (OHLC) Data.
     ///
     /// Use [Index Option Prices (OHLC) (/option/index_option) API](https://jpx.gitbook.io/j-quants-en/api-reference/index_option)
-    fn get_index_option_prices(&self, date: impl Into<String>) -> IndexOptionPricesBuilder {
-        IndexOptionPricesBuilder::new(self.get_api_client().clone(), date.into())
+    fn get_index_option_prices(&self, date: impl IntoQueryDate) -> IndexOptionPricesBuilder {
+        IndexOptionPricesBuilder::new(self.get_api_client().clone(), date.into_query_date())
+    }
+
+    /// Get index option prices for each trading day from `from` to `to` (e.g., "20210901" or "2021-09-01").
+    ///
+    /// The trading days are looked up with [`TradingCalendarApi`] and non-business days are skipped.
+    /// The date of the builder is `from` until it is set to each trading day.
+    /// See [`DateIterable::fetch_dates_stream`] for the responses.
+    fn get_index_option_prices_range(
+        &self,
+        from: impl IntoQueryDate,
+        to: impl IntoQueryDate,
+    ) -> impl Stream<Item = Result<IndexOptionPricesResponse, crate::JQuantsError>>
+    where
+        Self: TradingCalendarApi,
+    {
+        let from = from.into_query_date();
+        let calendar = self.get_trading_calendar().from(from.clone()).to(to);
+        self.get_index_option_prices(from)
+            .fetch_trading_days_stream(calendar)
     }
 }
 
 /// Index Option Prices (OHLC) Data API response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/index_option)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct IndexOptionPricesResponse {
     /// List of Nikkei 225 Options prices
+    #[paginated(items)]
     pub index_option: Vec<IndexOptionPriceItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for IndexOptionPricesResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for IndexOptionPricesResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.index_option.extend(p.index_option);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single Nikkei 225 Option price record.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct IndexOptionPriceItem {
     /// Trading day (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Issue code
     #[serde(rename = "Code")]
@@ -126,63 +157,63 @@ pub struct IndexOptionPriceItem {
 
     /// Whole day open price
     #[serde(rename = "WholeDayOpen")]
-    pub whole_day_open: f64,
+    pub whole_day_open: ResponseDecimal,
 
     /// Whole day high price
     #[serde(rename = "WholeDayHigh")]
-    pub whole_day_high: f64,
+    pub whole_day_high: ResponseDecimal,
 
     /// Whole day low price
     #[serde(rename = "WholeDayLow")]
-    pub whole_day_low: f64,
+    pub whole_day_low: ResponseDecimal,
 
     /// Whole day close price
     #[serde(rename = "WholeDayClose")]
-    pub whole_day_close: f64,
+    pub whole_day_close: ResponseDecimal,
 
     /// Night session open price
     #[serde(
         rename = "NightSessionOpen",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_open: Option<f64>,
+    pub night_session_open: Option<ResponseDecimal>,
 
     /// Night session high price
     #[serde(
         rename = "NightSessionHigh",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_high: Option<f64>,
+    pub night_session_high: Option<ResponseDecimal>,
 
     /// Night session low price
     #[serde(
         rename = "NightSessionLow",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_low: Option<f64>,
+    pub night_session_low: Option<ResponseDecimal>,
 
     /// Night session close price
     #[serde(
         rename = "NightSessionClose",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_close: Option<f64>,
+    pub night_session_close: Option<ResponseDecimal>,
 
     /// Day session open price
     #[serde(rename = "DaySessionOpen")]
-    pub day_session_open: f64,
+    pub day_session_open: ResponseDecimal,
 
     /// Day session high price
     #[serde(rename = "DaySessionHigh")]
-    pub day_session_high: f64,
+    pub day_session_high: ResponseDecimal,
 
     /// Day session low price
     #[serde(rename = "DaySessionLow")]
-    pub day_session_low: f64,
+    pub day_session_low: ResponseDecimal,
 
     /// Day session close price
     #[serde(rename = "DaySessionClose")]
-    pub day_session_close: f64,
+    pub day_session_close: ResponseDecimal,
 
     /// Volume
     #[serde(rename = "Volume")]
@@ -194,7 +225,7 @@ pub struct IndexOptionPriceItem {
 
     /// Turnover value
     #[serde(rename = "TurnoverValue")]
-    pub turnover_value: f64,
+    pub turnover_value: ResponseDecimal,
 
     /// Contract month (YYYY-MM)
     #[serde(rename = "ContractMonth")]
@@ -202,12 +233,12 @@ pub struct IndexOptionPriceItem {
 
     /// Strike price
     #[serde(rename = "StrikePrice")]
-    pub strike_price: f64,
+    pub strike_price: ResponseDecimal,
 
     /// Volume only auction
     #[serde(
         rename = "Volume(OnlyAuction)",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
     pub volume_only_auction: Option<f64>,
 
@@ -227,58 +258,67 @@ pub struct IndexOptionPriceItem {
         rename = "LastTradingDay",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub last_trading_day: Option<String>,
+    pub last_trading_day: Option<ResponseDate>,
 
     /// Special quotation day (YYYY-MM-DD)
     #[serde(
         rename = "SpecialQuotationDay",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub special_quotation_day: Option<String>,
+    pub special_quotation_day: Option<ResponseDate>,
 
     /// Settlement price
     #[serde(
         rename = "SettlementPrice",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub settlement_price: Option<f64>,
+    pub settlement_price: Option<ResponseDecimal>,
 
     /// Theoretical price
     #[serde(
         rename = "TheoreticalPrice",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub theoretical_price: Option<f64>,
+    pub theoretical_price: Option<ResponseDecimal>,
 
     /// Base volatility
     #[serde(
         rename = "BaseVolatility",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
     pub base_volatility: Option<f64>,
 
     /// Underlying asset price
     #[serde(
         rename = "UnderlyingPrice",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub underlying_price: Option<f64>,
+    pub underlying_price: Option<ResponseDecimal>,
 
     /// Implied volatility
     #[serde(
         rename = "ImpliedVolatility",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
     pub implied_volatility: Option<f64>,
 
     /// Interest rate for theoretical price calculation
-    #[serde(rename = "InterestRate", deserialize_with = "deserialize_f64_or_none")]
+    #[serde(
+        rename = "InterestRate",
+        deserialize_with = "deserialize_number_or_none"
+    )]
     pub interest_rate: Option<f64>,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_index_option_prices_response() {
@@ -324,37 +364,41 @@ mod tests {
 
         let response: IndexOptionPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_announcement = vec![IndexOptionPriceItem {
-            date: "2023-03-22".to_string(),
+            date: "2023-03-22".parse().unwrap(),
             code: "130060018".to_string(),
-            whole_day_open: 0.0,
-            whole_day_high: 0.0,
-            whole_day_low: 0.0,
-            whole_day_close: 0.0,
-            night_session_open: Some(0.0),
-            night_session_high: Some(0.0),
-            night_session_low: Some(0.0),
-            night_session_close: Some(0.0),
-            day_session_open: 0.0,
-            day_session_high: 0.0,
-            day_session_low: 0.0,
-            day_session_close: 0.0,
+            whole_day_open: "0.0".parse().unwrap(),
+            whole_day_high: "0.0".parse().unwrap(),
+            whole_day_low: "0.0".parse().unwrap(),
+            whole_day_close: "0.0".parse().unwrap(),
+            night_session_open: Some("0.0".parse().unwrap()),
+            night_session_high: Some("0.0".parse().unwrap()),
+            night_session_low: Some("0.0".parse().unwrap()),
+            night_session_close: Some("0.0".parse().unwrap()),
+            day_session_open: "0.0".parse().unwrap(),
+            day_session_high: "0.0".parse().unwrap(),
+            day_session_low: "0.0".parse().unwrap(),
+            day_session_close: "0.0".parse().unwrap(),
             volume: 0.0,
             open_interest: 330.0,
-            turnover_value: 0.0,
+            turnover_value: "0.0".parse().unwrap(),
             contract_month: "2025-06".to_string(),
-            strike_price: 20000.0,
+            strike_price: "20000.0".parse().unwrap(),
             volume_only_auction: Some(0.0),
             emergency_margin_trigger_division: Some(EmergencyMarginTriggerDivision::Calculated),
             put_call_division: PutCallDivision::Put,
-            last_trading_day: Some("2025-06-12".to_string()),
-            special_quotation_day: Some("2025-06-13".to_string()),
-            settlement_price: Some(980.0),
-            theoretical_price: Some(974.641),
+            last_trading_day: Some("2025-06-12".parse().unwrap()),
+            special_quotation_day: Some("2025-06-13".parse().unwrap()),
+            settlement_price: Some("980.0".parse().unwrap()),
+            theoretical_price: Some("974.641".parse().unwrap()),
             base_volatility: Some(17.93025),
-            underlying_price: Some(27466.61),
+            underlying_price: Some("27466.61".parse().unwrap()),
             implied_volatility: Some(23.1816),
             interest_rate: Some(0.2336),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = IndexOptionPricesResponse {
@@ -409,26 +453,28 @@ mod tests {
 
         let response: IndexOptionPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_announcement = vec![IndexOptionPriceItem {
-            date: "2023-03-22".to_string(),
+            date: "2023-03-22".parse().unwrap(),
             code: "130060018".to_string(),
-            whole_day_open: 0.0,
-            whole_day_high: 0.0,
-            whole_day_low: 0.0,
-            whole_day_close: 0.0,
+            whole_day_open: "0.0".parse().unwrap(),
+            whole_day_high: "0.0".parse().unwrap(),
+            whole_day_low: "0.0".parse().unwrap(),
+            whole_day_close: "0.0".parse().unwrap(),
             night_session_open: None,
             night_session_high: None,
             night_session_low: None,
             night_session_close: None,
-            day_session_open: 0.0,
-            day_session_high: 0.0,
-            day_session_low: 0.0,
-            day_session_close: 0.0,
+            day_session_open: "0.0".parse().unwrap(),
+            day_session_high: "0.0".parse().unwrap(),
+            day_session_low: "0.0".parse().unwrap(),
+            day_session_close: "0.0".parse().unwrap(),
             volume: 0.0,
             open_interest: 0.0,
-            turnover_value: 0.0,
+            turnover_value: "0.0".parse().unwrap(),
             contract_month: "2025-06".to_string(),
-            strike_price: 0.0,
+            strike_price: "0.0".parse().unwrap(),
             volume_only_auction: None,
             emergency_margin_trigger_division: None,
             put_call_division: PutCallDivision::Put,
@@ -440,6 +486,8 @@ mod tests {
             underlying_price: None,
             implied_volatility: None,
             interest_rate: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = IndexOptionPricesResponse {
@@ -526,70 +574,76 @@ mod tests {
 
         let response: IndexOptionPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_announcement = vec![
             IndexOptionPriceItem {
-                date: "2023-03-22".to_string(),
+                date: "2023-03-22".parse().unwrap(),
                 code: "130060018".to_string(),
-                whole_day_open: 1000.0,
-                whole_day_high: 1050.0,
-                whole_day_low: 990.0,
-                whole_day_close: 1025.0,
-                night_session_open: Some(1010.0),
-                night_session_high: Some(1040.0),
-                night_session_low: Some(995.0),
-                night_session_close: Some(1030.0),
-                day_session_open: 1025.0,
-                day_session_high: 1060.0,
-                day_session_low: 1000.0,
-                day_session_close: 1045.0,
+                whole_day_open: "1000.0".parse().unwrap(),
+                whole_day_high: "1050.0".parse().unwrap(),
+                whole_day_low: "990.0".parse().unwrap(),
+                whole_day_close: "1025.0".parse().unwrap(),
+                night_session_open: Some("1010.0".parse().unwrap()),
+                night_session_high: Some("1040.0".parse().unwrap()),
+                night_session_low: Some("995.0".parse().unwrap()),
+                night_session_close: Some("1030.0".parse().unwrap()),
+                day_session_open: "1025.0".parse().unwrap(),
+                day_session_high: "1060.0".parse().unwrap(),
+                day_session_low: "1000.0".parse().unwrap(),
+                day_session_close: "1045.0".parse().unwrap(),
                 volume: 1500.0,
                 open_interest: 330.0,
-                turnover_value: 1500000.0,
+                turnover_value: "1500000.0".parse().unwrap(),
                 contract_month: "2025-06".to_string(),
-                strike_price: 20000.0,
+                strike_price: "20000.0".parse().unwrap(),
                 volume_only_auction: Some(500.0),
                 emergency_margin_trigger_division: Some(EmergencyMarginTriggerDivision::Calculated),
                 put_call_division: PutCallDivision::Put,
-                last_trading_day: Some("2025-06-12".to_string()),
-                special_quotation_day: Some("2025-06-13".to_string()),
-                settlement_price: Some(980.0),
-                theoretical_price: Some(974.641),
+                last_trading_day: Some("2025-06-12".parse().unwrap()),
+                special_quotation_day: Some("2025-06-13".parse().unwrap()),
+                settlement_price: Some("980.0".parse().unwrap()),
+                theoretical_price: Some("974.641".parse().unwrap()),
                 base_volatility: Some(17.93025),
-                underlying_price: Some(27466.61),
+                underlying_price: Some("27466.61".parse().unwrap()),
                 implied_volatility: Some(23.1816),
                 interest_rate: Some(0.2336),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
             IndexOptionPriceItem {
-                date: "2023-03-22".to_string(),
+                date: "2023-03-22".parse().unwrap(),
                 code: "130060019".to_string(),
-                whole_day_open: 2000.0,
-                whole_day_high: 2050.0,
-                whole_day_low: 1990.0,
-                whole_day_close: 2025.0,
-                night_session_open: Some(2010.0),
-                night_session_high: Some(2040.0),
-                night_session_low: Some(1995.0),
-                night_session_close: Some(2030.0),
-                day_session_open: 2025.0,
-                day_session_high: 2060.0,
-                day_session_low: 2000.0,
-                day_session_close: 2045.0,
+                whole_day_open: "2000.0".parse().unwrap(),
+                whole_day_high: "2050.0".parse().unwrap(),
+                whole_day_low: "1990.0".parse().unwrap(),
+                whole_day_close: "2025.0".parse().unwrap(),
+                night_session_open: Some("2010.0".parse().unwrap()),
+                night_session_high: Some("2040.0".parse().unwrap()),
+                night_session_low: Some("1995.0".parse().unwrap()),
+                night_session_close: Some("2030.0".parse().unwrap()),
+                day_session_open: "2025.0".parse().unwrap(),
+                day_session_high: "2060.0".parse().unwrap(),
+                day_session_low: "2000.0".parse().unwrap(),
+                day_session_close: "2045.0".parse().unwrap(),
                 volume: 2500.0,
                 open_interest: 430.0,
-                turnover_value: 2500000.0,
+                turnover_value: "2500000.0".parse().unwrap(),
                 contract_month: "2025-07".to_string(),
-                strike_price: 21000.0,
+                strike_price: "21000.0".parse().unwrap(),
                 volume_only_auction: Some(600.0),
                 emergency_margin_trigger_division: Some(EmergencyMarginTriggerDivision::Triggered),
                 put_call_division: PutCallDivision::Call,
-                last_trading_day: Some("2025-07-12".to_string()),
-                special_quotation_day: Some("2025-07-13".to_string()),
-                settlement_price: Some(1980.0),
-                theoretical_price: Some(1974.641),
+                last_trading_day: Some("2025-07-12".parse().unwrap()),
+                special_quotation_day: Some("2025-07-13".parse().unwrap()),
+                settlement_price: Some("1980.0".parse().unwrap()),
+                theoretical_price: Some("1974.641".parse().unwrap()),
                 base_volatility: Some(18.93025),
-                underlying_price: Some(27566.61),
+                underlying_price: Some("27566.61".parse().unwrap()),
                 implied_volatility: Some(24.1816),
                 interest_rate: Some(0.2436),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
         ];
 
@@ -644,39 +698,43 @@ mod tests {
 
         let response: IndexOptionPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_announcement = vec![IndexOptionPriceItem {
-            date: "2023-03-22".to_string(),
+            date: "2023-03-22".parse().unwrap(),
             code: "130060018".to_string(),
-            whole_day_open: 0.0,
-            whole_day_high: 0.0,
-            whole_day_low: 0.0,
-            whole_day_close: 0.0,
-            night_session_open: Some(0.0),
-            night_session_high: Some(0.0),
-            night_session_low: Some(0.0),
-            night_session_close: Some(0.0),
-            day_session_open: 0.0,
-            day_session_high: 0.0,
-            day_session_low: 0.0,
-            day_session_close: 0.0,
+            whole_day_open: "0.0".parse().unwrap(),
+            whole_day_high: "0.0".parse().unwrap(),
+            whole_day_low: "0.0".parse().unwrap(),
+            whole_day_close: "0.0".parse().unwrap(),
+            night_session_open: Some("0.0".parse().unwrap()),
+            night_session_high: Some("0.0".parse().unwrap()),
+            night_session_low: Some("0.0".parse().unwrap()),
+            night_session_close: Some("0.0".parse().unwrap()),
+            day_session_open: "0.0".parse().unwrap(),
+            day_session_high: "0.0".parse().unwrap(),
+            day_session_low: "0.0".parse().unwrap(),
+            day_session_close: "0.0".parse().unwrap(),
             volume: 0.0,
             open_interest: 330.0,
-            turnover_value: 0.0,
+            turnover_value: "0.0".parse().unwrap(),
             contract_month: "2025-06".to_string(),
-            strike_price: 20000.0,
+            strike_price: "20000.0".parse().unwrap(),
             volume_only_auction: Some(0.0),
             emergency_margin_trigger_division: Some(EmergencyMarginTriggerDivision::Unknown(
                 "003".to_string(),
             )),
             put_call_division: PutCallDivision::Put,
-            last_trading_day: Some("2025-06-12".to_string()),
-            special_quotation_day: Some("2025-06-13".to_string()),
-            settlement_price: Some(980.0),
-            theoretical_price: Some(974.641),
+            last_trading_day: Some("2025-06-12".parse().unwrap()),
+            special_quotation_day: Some("2025-06-13".parse().unwrap()),
+            settlement_price: Some("980.0".parse().unwrap()),
+            theoretical_price: Some("974.641".parse().unwrap()),
             base_volatility: Some(17.93025),
-            underlying_price: Some(27466.61),
+            underlying_price: Some("27466.61".parse().unwrap()),
             implied_volatility: Some(23.1816),
             interest_rate: Some(0.2336),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = IndexOptionPricesResponse {
@@ -696,6 +754,8 @@ mod tests {
         "#;
 
         let response: IndexOptionPricesResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = IndexOptionPricesResponse {
             index_option: vec![],
             pagination_key: None,
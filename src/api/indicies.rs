@@ -1,17 +1,28 @@
 //! Indices (OHLC) API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            date_range::DateRangeShardable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
-        types::index_code::IndexCode,
+        types::{
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
+            index_code::IndexCode,
+            query_date::{IntoQueryDate, QueryDate},
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Indices (OHLC) API.
 #[derive(Clone, Serialize)]
@@ -24,26 +35,47 @@ pub struct IndicesBuilder {
     code: Option<IndexCode>,
     /// Starting point of data period (e.g., "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g., "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
     /// Date of data (e.g., "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for IndicesBuilder {
+    const PATH: &'static str = "indices";
+}
+
 impl JQuantsBuilder<IndicesResponse> for IndicesBuilder {
     async fn send(self) -> Result<IndicesResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[("code", self.code.is_some()), ("date", self.date.is_some())])?;
+        validation::date_excludes_range(self.date.as_ref(), self.from.as_ref(), self.to.as_ref())?;
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<IndicesResponse, crate::JQuantsError> {
-        self.client.inner.get("indices", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<IndicesResponse> for IndicesBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<IndicesResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -54,6 +86,14 @@ impl Paginatable<IndicesResponse> for IndicesBuilder {
     }
 }
 
+impl DateRangeShardable<IndicesResponse> for IndicesBuilder {
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
 impl IndicesBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -74,20 +114,20 @@ impl IndicesBuilder {
     }
 
     /// Set starting point of data period (e.g., "20210901" or "2021-09-01")
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g., "20210907" or "2021-09-07")
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 
     /// Set date of data (e.g., "20210907" or "2021-09-07")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
@@ -105,41 +145,22 @@ pub trait IndicesApi: JQuantsPlanClient {
 /// Indices (OHLC) response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/indices)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct IndicesResponse {
     /// List of indices data
+    #[paginated(items)]
     pub indices: Vec<IndexItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for IndicesResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for IndicesResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.indices.extend(p.indices);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single indices (OHLC) data item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct IndexItem {
     /// Trade date (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Index code
     #[serde(rename = "Code")]
@@ -147,24 +168,30 @@ pub struct IndexItem {
 
     /// Open Price
     #[serde(rename = "Open")]
-    pub open: f64,
+    pub open: ResponseDecimal,
 
     /// High Price
     #[serde(rename = "High")]
-    pub high: f64,
+    pub high: ResponseDecimal,
 
     /// Low Price
     #[serde(rename = "Low")]
-    pub low: f64,
+    pub low: ResponseDecimal,
 
     /// Close Price
     #[serde(rename = "Close")]
-    pub close: f64,
+    pub close: ResponseDecimal,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_indices_response() {
@@ -185,14 +212,18 @@ mod tests {
         "#;
 
         let response: IndicesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = IndicesResponse {
             indices: vec![IndexItem {
-                date: "2023-12-01".to_string(),
+                date: "2023-12-01".parse().unwrap(),
                 code: IndexCode::TOPIXCore30,
-                open: 1199.18,
-                high: 1202.58,
-                low: 1195.01,
-                close: 1200.17,
+                open: "1199.18".parse().unwrap(),
+                high: "1202.58".parse().unwrap(),
+                low: "1195.01".parse().unwrap(),
+                close: "1200.17".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -218,14 +249,18 @@ mod tests {
         "#;
 
         let response: IndicesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = IndicesResponse {
             indices: vec![IndexItem {
-                date: "2023-12-01".to_string(),
+                date: "2023-12-01".parse().unwrap(),
                 code: IndexCode::TOPIXCore30,
-                open: 1199.18,
-                high: 1202.58,
-                low: 1195.01,
-                close: 1200.17,
+                open: "1199.18".parse().unwrap(),
+                high: "1202.58".parse().unwrap(),
+                low: "1195.01".parse().unwrap(),
+                close: "1200.17".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -260,23 +295,29 @@ mod tests {
         "#;
 
         let response: IndicesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = IndicesResponse {
             indices: vec![
                 IndexItem {
-                    date: "2023-11-30".to_string(),
+                    date: "2023-11-30".parse().unwrap(),
                     code: IndexCode::TOPIX,
-                    open: 1500.50,
-                    high: 1520.75,
-                    low: 1495.00,
-                    close: 1510.25,
+                    open: "1500.50".parse().unwrap(),
+                    high: "1520.75".parse().unwrap(),
+                    low: "1495.00".parse().unwrap(),
+                    close: "1510.25".parse().unwrap(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 IndexItem {
-                    date: "2023-12-01".to_string(),
+                    date: "2023-12-01".parse().unwrap(),
                     code: IndexCode::TOPIXCore30,
-                    open: 1199.18,
-                    high: 1202.58,
-                    low: 1195.01,
-                    close: 1200.17,
+                    open: "1199.18".parse().unwrap(),
+                    high: "1202.58".parse().unwrap(),
+                    low: "1195.01".parse().unwrap(),
+                    close: "1200.17".parse().unwrap(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value1.value2.".to_string()),
@@ -294,6 +335,8 @@ mod tests {
         "#;
 
         let response: IndicesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = IndicesResponse {
             indices: vec![],
             pagination_key: None,
@@ -1,5 +1,6 @@
 //! Listed info API endpoints.
 
+use jquants_api_client_derive::ResponseAccessors;
 use std::{fmt, marker::PhantomData};
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -8,13 +9,25 @@ use crate::MarginCode;
 
 use super::{
     shared::{
-        traits::builder::JQuantsBuilder,
+        traits::{
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            request_preview::RequestPreview,
+        },
         types::{
-            market_code::MarketCode, sector17_code::Sector17Code, sector33_code::Sector33Code,
+            date_time::ResponseDate,
+            issue_code::{
+                serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+            },
+            market_code::MarketCode,
+            query_date::{IntoQueryDate, QueryDate},
+            sector17_code::Sector17Code,
+            sector33_code::Sector33Code,
         },
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Listed Issue Info API.
 #[derive(Clone, Serialize)]
@@ -22,18 +35,21 @@ pub struct ListedIssueInfoApiBuilder<R: DeserializeOwned + fmt::Debug + Clone> {
     #[serde(skip)]
     client: JQuantsApiClient,
     #[serde(skip)]
-    phantom: PhantomData<R>,
+    phantom: PhantomData<fn() -> R>,
 
     /// Issue code (e.g. 27800 or 2780)
     ///
     /// If a 4-character issue code is specified,
     /// only the data of common stock will be obtained for the issue on which both common and preferred stocks are listed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
     /// Date of application of information
     /// (e.g. 20210907 or 2021-09-07)
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 }
 impl<R: DeserializeOwned + fmt::Debug + Clone> ListedIssueInfoApiBuilder<R> {
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -46,25 +62,40 @@ impl<R: DeserializeOwned + fmt::Debug + Clone> ListedIssueInfoApiBuilder<R> {
     }
 
     /// Set issue code. (e.g. 27800 or 2780)
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 
     /// Set date. (e.g. 27800 or 2780)
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
 
+impl<R: DeserializeOwned + fmt::Debug + Clone> RequestPreview for ListedIssueInfoApiBuilder<R> {
+    const PATH: &'static str = "listed/info";
+}
+
 impl<R: DeserializeOwned + fmt::Debug + Clone> JQuantsBuilder<R> for ListedIssueInfoApiBuilder<R> {
     async fn send(self) -> Result<R, crate::JQuantsError> {
         self.send_ref().await
     }
 
     async fn send_ref(&self) -> Result<R, crate::JQuantsError> {
-        self.client.inner.get("listed/info", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl<R: DeserializeOwned + fmt::Debug + Clone + Send> JQuantsSendBuilder<R>
+    for ListedIssueInfoApiBuilder<R>
+{
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<R, crate::JQuantsError>> + Send {
+        self.send_ref()
     }
 }
 
@@ -89,7 +120,7 @@ pub type ListedIssueInfoFreePlanResponse = ListedIssueInfoLightPlanResponse;
 /// Listed issue info response for light plan.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/listed_info)
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ListedIssueInfoLightPlanResponse {
     /// The listed info for light plan.
     pub info: Vec<IssueInfoLightPlanItem>,
@@ -118,6 +149,11 @@ pub struct IssueInfoLightPlanItem {
     /// The common structure for issue info.
     #[serde(flatten)]
     pub common: IssueInfoCommonItem,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Issue info for standard plan.
@@ -137,18 +173,23 @@ pub struct IssueInfoPremiumPlanItem {
     /// Name of flags of margin and loan issues.
     #[serde(rename = "MarginCodeName")]
     pub margin_code_name: String,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Common structure for issue info.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ResponseAccessors)]
 pub struct IssueInfoCommonItem {
     /// Date of application of information (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Issue code.
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: IssueCode,
 
     /// Company Name (Japanese)
     #[serde(rename = "CompanyName")]
@@ -224,7 +265,7 @@ fn build_common_columns(
         } = common_item;
 
         dates.push(date);
-        codes.push(code);
+        codes.push(code.to_string());
         company_names.push(company_name);
         company_names_english.push(company_name_english);
         sector17_codes.push(sector17_code);
@@ -260,7 +301,18 @@ impl ListedIssueInfoLightPlanResponse {
         self,
     ) -> Result<polars::prelude::DataFrame, crate::polars_utils::IntoPolarsError> {
         let data = self.info;
-        let columns = build_common_columns(data.into_iter().map(|d| d.common).collect::<Vec<_>>())?;
+        #[cfg(feature = "extra-fields")]
+        let (commons, extras): (Vec<_>, Vec<_>) =
+            data.into_iter().map(|d| (d.common, d.extra)).unzip();
+        #[cfg(not(feature = "extra-fields"))]
+        let commons = data.into_iter().map(|d| d.common).collect::<Vec<_>>();
+
+        let columns = build_common_columns(commons)?;
+        #[cfg(feature = "extra-fields")]
+        let columns = columns
+            .into_iter()
+            .chain(crate::polars_utils::extra_fields_to_columns(extras))
+            .collect::<Vec<_>>();
         let df = polars::frame::DataFrame::new(columns)?;
 
         Ok(df)
@@ -280,11 +332,15 @@ impl ListedIssueInfoPremiumPlanResponse {
         let mut common = Vec::with_capacity(data.len());
         let mut margin_codes = Vec::with_capacity(data.len());
         let mut margin_code_names = Vec::with_capacity(data.len());
+        #[cfg(feature = "extra-fields")]
+        let mut extras = Vec::with_capacity(data.len());
 
         for item in data {
             common.push(item.common);
             margin_codes.push(item.margin_code);
             margin_code_names.push(item.margin_code_name);
+            #[cfg(feature = "extra-fields")]
+            extras.push(item.extra);
         }
 
         let mut columns = build_common_columns(common)?;
@@ -293,6 +349,8 @@ impl ListedIssueInfoPremiumPlanResponse {
             "MarginCodeName",
             margin_code_names,
         )?);
+        #[cfg(feature = "extra-fields")]
+        columns.extend(crate::polars_utils::extra_fields_to_columns(extras));
 
         let df = polars::frame::DataFrame::new(columns)?;
 
@@ -303,6 +361,7 @@ impl ListedIssueInfoPremiumPlanResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_listed_issue_info_light_plan_response() {
@@ -327,12 +386,14 @@ mod tests {
         "#;
 
         let response: ListedIssueInfoLightPlanResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response: ListedIssueInfoLightPlanResponse =
             ListedIssueInfoLightPlanResponse {
                 info: vec![IssueInfoLightPlanItem {
                     common: IssueInfoCommonItem {
-                        date: "2022-11-11".to_string(),
-                        code: "86970".to_string(),
+                        date: "2022-11-11".parse().unwrap(),
+                        code: "86970".parse().unwrap(),
                         company_name: "日本取引所グループ".to_string(),
                         company_name_english: "Japan Exchange Group,Inc.".to_string(),
                         sector17_code: Sector17Code::FinancialsExBanks,
@@ -343,6 +404,8 @@ mod tests {
                         market_code: MarketCode::Prime,
                         market_code_name: "プライム".to_string(),
                     },
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 }],
             };
 
@@ -374,12 +437,14 @@ mod tests {
         "#;
 
         let response: ListedIssueInfoPremiumPlanResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response: ListedIssueInfoPremiumPlanResponse =
             ListedIssueInfoPremiumPlanResponse {
                 info: vec![IssueInfoPremiumPlanItem {
                     common: IssueInfoCommonItem {
-                        date: "2022-11-11".to_string(),
-                        code: "86970".to_string(),
+                        date: "2022-11-11".parse().unwrap(),
+                        code: "86970".parse().unwrap(),
                         company_name: "日本取引所グループ".to_string(),
                         company_name_english: "Japan Exchange Group,Inc.".to_string(),
                         sector17_code: Sector17Code::FinancialsExBanks,
@@ -392,6 +457,8 @@ mod tests {
                     },
                     margin_code: MarginCode::MarginIssues,
                     margin_code_name: "信用".to_string(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 }],
             };
 
@@ -407,8 +474,8 @@ mod tests {
             info: vec![
                 IssueInfoLightPlanItem {
                     common: IssueInfoCommonItem {
-                        date: "2022-11-11".to_string(),
-                        code: "86970".to_string(),
+                        date: "2022-11-11".parse().unwrap(),
+                        code: "86970".parse().unwrap(),
                         company_name: "Group".to_string(),
                         company_name_english: "JEG".to_string(),
                         sector17_code: Sector17Code::FinancialsExBanks,
@@ -419,11 +486,13 @@ mod tests {
                         market_code: MarketCode::Prime,
                         market_code_name: "Prime".to_string(),
                     },
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 IssueInfoLightPlanItem {
                     common: IssueInfoCommonItem {
-                        date: "2022-11-12".to_string(),
-                        code: "86971".to_string(),
+                        date: "2022-11-12".parse().unwrap(),
+                        code: "86971".parse().unwrap(),
                         company_name: "Group".to_string(),
                         company_name_english: "JEG2".to_string(),
                         sector17_code: Sector17Code::Foods,
@@ -434,6 +503,8 @@ mod tests {
                         market_code: MarketCode::TSEFirstSection,
                         market_code_name: "Prime-B".to_string(),
                     },
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
         };
@@ -461,8 +532,8 @@ mod tests {
         let response = ListedIssueInfoPremiumPlanResponse {
             info: vec![IssueInfoPremiumPlanItem {
                 common: IssueInfoCommonItem {
-                    date: "2022-11-11".to_string(),
-                    code: "86970".to_string(),
+                    date: "2022-11-11".parse().unwrap(),
+                    code: "86970".parse().unwrap(),
                     company_name: "Group".to_string(),
                     company_name_english: "JEG".to_string(),
                     sector17_code: Sector17Code::FinancialsExBanks,
@@ -475,6 +546,8 @@ mod tests {
                 },
                 margin_code: MarginCode::MarginIssues,
                 margin_code_name: "MarginTrading".to_string(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
         };
 
@@ -1,14 +1,26 @@
 //! Morning Session Stock Prices API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    shared::traits::{
-        builder::JQuantsBuilder,
-        pagination::{HasPaginationKey, MergePage, Paginatable},
+    shared::{
+        traits::{
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            code_batch::CodeBatchable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
+        },
+        types::date_time::ResponseDate,
+        types::decimal::ResponseDecimal,
+        types::issue_code::{
+            serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+        },
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Morning Session Stock Prices API.
 ///
@@ -19,21 +31,39 @@ pub struct MorningSessionStockPricesApiBuilder {
     client: JQuantsApiClient,
 
     /// Issue code (e.g. 27800 or 2780)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for MorningSessionStockPricesApiBuilder {
+    const PATH: &'static str = "prices/prices_am";
+}
+
 impl JQuantsBuilder<MorningSessionStockPricesResponse> for MorningSessionStockPricesApiBuilder {
     async fn send(self) -> Result<MorningSessionStockPricesResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
     async fn send_ref(&self) -> Result<MorningSessionStockPricesResponse, crate::JQuantsError> {
-        self.client.inner.get("prices/prices_am", &self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, &self).await
+    }
+}
+
+impl JQuantsSendBuilder<MorningSessionStockPricesResponse> for MorningSessionStockPricesApiBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<
+        Output = Result<MorningSessionStockPricesResponse, crate::JQuantsError>,
+    > + Send {
+        self.send_ref()
     }
 }
 
@@ -44,6 +74,13 @@ impl Paginatable<MorningSessionStockPricesResponse> for MorningSessionStockPrice
     }
 }
 
+impl CodeBatchable<MorningSessionStockPricesResponse> for MorningSessionStockPricesApiBuilder {
+    fn with_code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+}
+
 impl MorningSessionStockPricesApiBuilder {
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
         Self {
@@ -54,8 +91,8 @@ impl MorningSessionStockPricesApiBuilder {
     }
 
     /// Issue code (e.g. 27800 or 2780)
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 }
@@ -71,65 +108,54 @@ pub trait MorningSessionStockPricesApi: JQuantsPlanClient {
 /// Morning Session Stock Prices response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/prices_am)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct MorningSessionStockPricesResponse {
     /// List of morning session stock prices.
+    #[paginated(items)]
     prices_am: Vec<MorningStockPriceItem>,
     /// Pagination key for fetching next set of data.
+    #[paginated(pagination_key)]
     pagination_key: Option<String>,
 }
-impl HasPaginationKey for MorningSessionStockPricesResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-impl MergePage for MorningSessionStockPricesResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.prices_am.extend(p.prices_am);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
 
 /// Morning session stock price.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct MorningStockPriceItem {
     /// Date
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
     /// Issue code
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: IssueCode,
     /// Open price of the morning session
     #[serde(rename = "MorningOpen")]
-    pub morning_open: Option<f64>,
+    pub morning_open: Option<ResponseDecimal>,
     /// High price of the morning session
     #[serde(rename = "MorningHigh")]
-    pub morning_high: Option<f64>,
+    pub morning_high: Option<ResponseDecimal>,
     /// Low price of the morning session
     #[serde(rename = "MorningLow")]
-    pub morning_low: Option<f64>,
+    pub morning_low: Option<ResponseDecimal>,
     /// Close price of the morning session
     #[serde(rename = "MorningClose")]
-    pub morning_close: Option<f64>,
+    pub morning_close: Option<ResponseDecimal>,
     /// Trading volume of the morning session
     #[serde(rename = "MorningVolume")]
     pub morning_volume: Option<f64>,
     /// Trading value of the morning session
     #[serde(rename = "MorningTurnoverValue")]
-    pub morning_turnover_value: Option<f64>,
+    pub morning_turnover_value: Option<ResponseDecimal>,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_morning_session_stock_prices_response() {
@@ -151,17 +177,20 @@ mod tests {
             }
         "#;
         let response: MorningSessionStockPricesResponse = serde_json::from_str(json).unwrap();
+        assert_round_trip(&response);
         let expected_response: MorningSessionStockPricesResponse =
             MorningSessionStockPricesResponse {
                 prices_am: vec![MorningStockPriceItem {
-                    date: "2023-03-20".to_string(),
-                    code: "39400".to_string(),
-                    morning_open: Some(232.0),
-                    morning_high: Some(244.0),
-                    morning_low: Some(232.0),
-                    morning_close: Some(240.0),
+                    date: "2023-03-20".parse().unwrap(),
+                    code: "39400".parse().unwrap(),
+                    morning_open: Some("232.0".parse().unwrap()),
+                    morning_high: Some("244.0".parse().unwrap()),
+                    morning_low: Some("232.0".parse().unwrap()),
+                    morning_close: Some("240.0".parse().unwrap()),
                     morning_volume: Some(52600.0),
-                    morning_turnover_value: Some(12518800.0),
+                    morning_turnover_value: Some("12518800.0".parse().unwrap()),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 }],
                 pagination_key: Some("value1.value2.".to_string()),
             };
@@ -189,17 +218,20 @@ mod tests {
             }
         "#;
         let response: MorningSessionStockPricesResponse = serde_json::from_str(json).unwrap();
+        assert_round_trip(&response);
         let expected_response: MorningSessionStockPricesResponse =
             MorningSessionStockPricesResponse {
                 prices_am: vec![MorningStockPriceItem {
-                    date: "2023-03-20".to_string(),
-                    code: "39400".to_string(),
+                    date: "2023-03-20".parse().unwrap(),
+                    code: "39400".parse().unwrap(),
                     morning_open: None,
                     morning_high: None,
                     morning_low: None,
                     morning_close: None,
                     morning_volume: None,
                     morning_turnover_value: None,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 }],
                 pagination_key: Some("value1.value2.".to_string()),
             };
@@ -1,23 +1,35 @@
 //! Options OHLC (/derivatives/options) API
 
+use futures::Stream;
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
-        deserialize_utils::{deserialize_f64_or_none, empty_string_or_null_as_none},
+        deserialize_utils::{deserialize_number_or_none, empty_string_or_null_as_none},
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            date_iter::DateIterable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
         types::{
             central_contract_month_flag::CentralContractMonthFlag,
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
             emergency_margin_trigger_division::EmergencyMarginTriggerDivision,
-            options_code::OptionsCode, put_call_division::PutCallDivision,
+            issue_code::IssueCode,
+            options_code::OptionsCode,
+            put_call_division::PutCallDivision,
+            query_date::{IntoQueryDate, QueryDate},
             underlying_sso::UnderlyingSSO,
         },
     },
+    trading_calendar::TradingCalendarApi,
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Options (OHLC) Data API.
 #[derive(Clone, Serialize)]
@@ -27,31 +39,45 @@ pub struct OptionsPricesBuilder {
 
     /// Category of data
     #[serde(skip_serializing_if = "Option::is_none")]
-    category: Option<String>,
+    category: Option<OptionsCode>,
 
-    /// Security options code (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<OptionsCode>,
+    /// Issue code of the underlying security (only for securities options)
+    #[serde(rename = "code", skip_serializing_if = "Option::is_none")]
+    underlying_sso: Option<IssueCode>,
 
     /// Date of data (e.g., "20210901" or "2021-09-01")
-    date: String,
+    date: QueryDate,
 
     /// Central contract month flag (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
-    contract_flag: Option<String>,
+    contract_flag: Option<CentralContractMonthFlag>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for OptionsPricesBuilder {
+    const PATH: &'static str = "derivatives/options";
+}
+
 impl JQuantsBuilder<OptionsPricesResponse> for OptionsPricesBuilder {
     async fn send(self) -> Result<OptionsPricesResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
     async fn send_ref(&self) -> Result<OptionsPricesResponse, crate::JQuantsError> {
-        self.client.inner.get("derivatives/options", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<OptionsPricesResponse> for OptionsPricesBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<OptionsPricesResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -62,13 +88,20 @@ impl Paginatable<OptionsPricesResponse> for OptionsPricesBuilder {
     }
 }
 
+impl DateIterable<OptionsPricesResponse> for OptionsPricesBuilder {
+    fn with_date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = date.into_query_date();
+        self
+    }
+}
+
 impl OptionsPricesBuilder {
     /// Create a new builder.
-    pub(crate) fn new(client: JQuantsApiClient, date: String) -> Self {
+    pub(crate) fn new(client: JQuantsApiClient, date: QueryDate) -> Self {
         Self {
             client,
             category: None,
-            code: None,
+            underlying_sso: None,
             date,
             contract_flag: None,
             pagination_key: None,
@@ -76,26 +109,34 @@ impl OptionsPricesBuilder {
     }
 
     /// Set the category of data.
-    pub fn category(mut self, category: impl Into<String>) -> Self {
-        self.category = Some(category.into());
+    pub fn category(mut self, category: OptionsCode) -> Self {
+        self.category = Some(category);
         self
     }
 
-    /// Set the security options code.
-    pub fn code(mut self, code: impl Into<OptionsCode>) -> Self {
-        self.code = Some(code.into());
-        self
+    /// Set the category of data.
+    #[deprecated(note = "use `category` instead")]
+    pub fn code(self, code: impl Into<OptionsCode>) -> Self {
+        self.category(code.into())
+    }
+
+    /// Narrow the data to securities options ([`OptionsCode::EQOP`]).
+    ///
+    /// The returned builder can filter by the underlying security.
+    pub fn securities_options(mut self) -> SecuritiesOptionsPricesBuilder {
+        self.category = Some(OptionsCode::EQOP);
+        SecuritiesOptionsPricesBuilder { inner: self }
     }
 
     /// Set the date of data (e.g., "20210901" or "2021-09-01")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = date.into();
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = date.into_query_date();
         self
     }
 
     /// Set the central contract month flag.
-    pub fn contract_flag(mut self, flag: impl Into<String>) -> Self {
-        self.contract_flag = Some(flag.into());
+    pub fn contract_flag(mut self, flag: CentralContractMonthFlag) -> Self {
+        self.contract_flag = Some(flag);
         self
     }
 
@@ -106,50 +147,126 @@ impl OptionsPricesBuilder {
     }
 }
 
+/// Builder for Options (OHLC) Data API of securities options.
+///
+/// The category is fixed to [`OptionsCode::EQOP`], which is the only category with an underlying security.
+/// Use [`OptionsPricesBuilder::securities_options`] to get it.
+#[derive(Clone, Serialize)]
+#[serde(transparent)]
+pub struct SecuritiesOptionsPricesBuilder {
+    inner: OptionsPricesBuilder,
+}
+
+impl RequestPreview for SecuritiesOptionsPricesBuilder {
+    const PATH: &'static str = OptionsPricesBuilder::PATH;
+}
+
+impl JQuantsBuilder<OptionsPricesResponse> for SecuritiesOptionsPricesBuilder {
+    async fn send(self) -> Result<OptionsPricesResponse, crate::JQuantsError> {
+        self.send_ref().await
+    }
+
+    async fn send_ref(&self) -> Result<OptionsPricesResponse, crate::JQuantsError> {
+        self.inner.send_ref().await
+    }
+}
+
+impl JQuantsSendBuilder<OptionsPricesResponse> for SecuritiesOptionsPricesBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<OptionsPricesResponse, crate::JQuantsError>> + Send
+    {
+        self.inner.send_ref_spawnable()
+    }
+}
+
+impl Paginatable<OptionsPricesResponse> for SecuritiesOptionsPricesBuilder {
+    fn pagination_key(mut self, pagination_key: impl Into<String>) -> Self {
+        self.inner.pagination_key = Some(pagination_key.into());
+        self
+    }
+}
+
+impl DateIterable<OptionsPricesResponse> for SecuritiesOptionsPricesBuilder {
+    fn with_date(mut self, date: impl IntoQueryDate) -> Self {
+        self.inner.date = date.into_query_date();
+        self
+    }
+}
+
+impl SecuritiesOptionsPricesBuilder {
+    /// Set the issue code of the underlying security.
+    pub fn underlying_sso(mut self, code: IssueCode) -> Self {
+        self.inner.underlying_sso = Some(code);
+        self
+    }
+
+    /// Set the date of data (e.g., "20210901" or "2021-09-01")
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.inner.date = date.into_query_date();
+        self
+    }
+
+    /// Set the central contract month flag.
+    pub fn contract_flag(mut self, flag: CentralContractMonthFlag) -> Self {
+        self.inner.contract_flag = Some(flag);
+        self
+    }
+
+    /// Set pagination key for fetching the next set of data.
+    pub fn pagination_key(mut self, pagination_key: impl Into<String>) -> Self {
+        self.inner.pagination_key = Some(pagination_key.into());
+        self
+    }
+}
+
 /// Trait for Options (OHLC) Data API.
 pub trait OptionsPricesApi: JQuantsPlanClient {
     /// Get API builder for Options (OHLC) Data.
     ///
     /// Use [Options (OHLC) (/derivatives/options) API](https://jpx.gitbook.io/j-quants-en/api-reference/options)
-    fn get_options_prices(&self, date: impl Into<String>) -> OptionsPricesBuilder {
-        OptionsPricesBuilder::new(self.get_api_client().clone(), date.into())
+    fn get_options_prices(&self, date: impl IntoQueryDate) -> OptionsPricesBuilder {
+        OptionsPricesBuilder::new(self.get_api_client().clone(), date.into_query_date())
+    }
+
+    /// Get options prices for each trading day from `from` to `to` (e.g., "20210901" or "2021-09-01").
+    ///
+    /// The trading days are looked up with [`TradingCalendarApi`] and non-business days are skipped.
+    /// `params` sets the params shared by all days (e.g. `category`),
+    /// and may narrow the builder with [`OptionsPricesBuilder::securities_options`].
+    /// The date of the builder is `from` until it is set to each trading day.
+    /// See [`DateIterable::fetch_dates_stream`] for the responses.
+    fn get_options_prices_range<B>(
+        &self,
+        from: impl IntoQueryDate,
+        to: impl IntoQueryDate,
+        params: impl FnOnce(OptionsPricesBuilder) -> B,
+    ) -> impl Stream<Item = Result<OptionsPricesResponse, crate::JQuantsError>>
+    where
+        Self: TradingCalendarApi,
+        B: DateIterable<OptionsPricesResponse>,
+    {
+        let from = from.into_query_date();
+        let calendar = self.get_trading_calendar().from(from.clone()).to(to);
+        params(self.get_options_prices(from)).fetch_trading_days_stream(calendar)
     }
 }
 
 /// Options (OHLC) Data API response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/options)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct OptionsPricesResponse {
     /// List of Options prices
+    #[paginated(items)]
     pub options: Vec<OptionsPricesItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for OptionsPricesResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for OptionsPricesResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.options.extend(p.options);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single Options price record.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct OptionsPricesItem {
     /// Issue code
     #[serde(rename = "Code")]
@@ -165,98 +282,98 @@ pub struct OptionsPricesItem {
 
     /// Trading day (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Whole day open price
     #[serde(rename = "WholeDayOpen")]
-    pub whole_day_open: f64,
+    pub whole_day_open: ResponseDecimal,
 
     /// Whole day high price
     #[serde(rename = "WholeDayHigh")]
-    pub whole_day_high: f64,
+    pub whole_day_high: ResponseDecimal,
 
     /// Whole day low price
     #[serde(rename = "WholeDayLow")]
-    pub whole_day_low: f64,
+    pub whole_day_low: ResponseDecimal,
 
     /// Whole day close price
     #[serde(rename = "WholeDayClose")]
-    pub whole_day_close: f64,
+    pub whole_day_close: ResponseDecimal,
 
     /// Morning session open price
     #[serde(
         rename = "MorningSessionOpen",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_open: Option<f64>,
+    pub morning_session_open: Option<ResponseDecimal>,
 
     /// Morning session high price
     #[serde(
         rename = "MorningSessionHigh",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_high: Option<f64>,
+    pub morning_session_high: Option<ResponseDecimal>,
 
     /// Morning session low price
     #[serde(
         rename = "MorningSessionLow",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_low: Option<f64>,
+    pub morning_session_low: Option<ResponseDecimal>,
 
     /// Morning session close price
     #[serde(
         rename = "MorningSessionClose",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub morning_session_close: Option<f64>,
+    pub morning_session_close: Option<ResponseDecimal>,
 
     /// Night session open price
     #[serde(
         rename = "NightSessionOpen",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_open: Option<f64>,
+    pub night_session_open: Option<ResponseDecimal>,
 
     /// Night session high price
     #[serde(
         rename = "NightSessionHigh",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_high: Option<f64>,
+    pub night_session_high: Option<ResponseDecimal>,
 
     /// Night session low price
     #[serde(
         rename = "NightSessionLow",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_low: Option<f64>,
+    pub night_session_low: Option<ResponseDecimal>,
 
     /// Night session close price
     #[serde(
         rename = "NightSessionClose",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub night_session_close: Option<f64>,
+    pub night_session_close: Option<ResponseDecimal>,
 
     /// Day session open price
     #[serde(
         rename = "DaySessionOpen",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub day_session_open: Option<f64>,
+    pub day_session_open: Option<ResponseDecimal>,
 
     /// Day session high price
     #[serde(rename = "DaySessionHigh")]
-    pub day_session_high: f64,
+    pub day_session_high: ResponseDecimal,
 
     /// Day session low price
     #[serde(rename = "DaySessionLow")]
-    pub day_session_low: f64,
+    pub day_session_low: ResponseDecimal,
 
     /// Day session close price
     #[serde(rename = "DaySessionClose")]
-    pub day_session_close: f64,
+    pub day_session_close: ResponseDecimal,
 
     /// Volume
     #[serde(rename = "Volume")]
@@ -268,7 +385,7 @@ pub struct OptionsPricesItem {
 
     /// Turnover value
     #[serde(rename = "TurnoverValue")]
-    pub turnover_value: f64,
+    pub turnover_value: ResponseDecimal,
 
     /// Contract month (YYYY-MM)
     #[serde(rename = "ContractMonth")]
@@ -276,12 +393,12 @@ pub struct OptionsPricesItem {
 
     /// Strike price
     #[serde(rename = "StrikePrice")]
-    pub strike_price: f64,
+    pub strike_price: ResponseDecimal,
 
     /// Volume only auction
     #[serde(
         rename = "Volume(OnlyAuction)",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
     pub volume_only_auction: Option<f64>,
 
@@ -298,52 +415,55 @@ pub struct OptionsPricesItem {
         rename = "LastTradingDay",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub last_trading_day: Option<String>,
+    pub last_trading_day: Option<ResponseDate>,
 
     /// Special quotation day (YYYY-MM-DD)
     #[serde(
         rename = "SpecialQuotationDay",
         deserialize_with = "empty_string_or_null_as_none"
     )]
-    pub special_quotation_day: Option<String>,
+    pub special_quotation_day: Option<ResponseDate>,
 
     /// Settlement price
     #[serde(
         rename = "SettlementPrice",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub settlement_price: Option<f64>,
+    pub settlement_price: Option<ResponseDecimal>,
 
     /// Theoretical price
     #[serde(
         rename = "TheoreticalPrice",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub theoretical_price: Option<f64>,
+    pub theoretical_price: Option<ResponseDecimal>,
 
     /// Base volatility
     #[serde(
         rename = "BaseVolatility",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
     pub base_volatility: Option<f64>,
 
     /// Underlying asset price
     #[serde(
         rename = "UnderlyingPrice",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
-    pub underlying_price: Option<f64>,
+    pub underlying_price: Option<ResponseDecimal>,
 
     /// Implied volatility
     #[serde(
         rename = "ImpliedVolatility",
-        deserialize_with = "deserialize_f64_or_none"
+        deserialize_with = "deserialize_number_or_none"
     )]
     pub implied_volatility: Option<f64>,
 
     /// Interest rate for theoretical price calculation
-    #[serde(rename = "InterestRate", deserialize_with = "deserialize_f64_or_none")]
+    #[serde(
+        rename = "InterestRate",
+        deserialize_with = "deserialize_number_or_none"
+    )]
     pub interest_rate: Option<f64>,
 
     /// Flag of the central contract month
@@ -352,11 +472,57 @@ pub struct OptionsPricesItem {
         deserialize_with = "empty_string_or_null_as_none"
     )]
     pub central_contract_month_flag: Option<CentralContractMonthFlag>,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
+
+    #[test]
+    fn test_options_prices_builder_params() {
+        use crate::{JQuantsPlanClient, JQuantsPremiumPlanClient};
+
+        let client = JQuantsPremiumPlanClient::new_from_refresh_token("refresh-token".to_string());
+        let builder = client
+            .get_options_prices("2024-08-01")
+            .category(OptionsCode::NK225E)
+            .securities_options()
+            .underlying_sso("7203".parse().unwrap())
+            .contract_flag(CentralContractMonthFlag::CentralContractMonth);
+        assert_eq!(
+            serde_urlencoded::to_string(&builder).unwrap(),
+            "category=EQOP&code=72030&date=2024-08-01&contract_flag=1"
+        );
+
+        #[allow(deprecated)]
+        let builder = client
+            .get_options_prices("2024-08-01")
+            .code(OptionsCode::NK225E);
+        assert_eq!(
+            serde_urlencoded::to_string(&builder).unwrap(),
+            "category=NK225E&date=2024-08-01"
+        );
+    }
+
+    #[test]
+    fn test_options_prices_range_builder_starts_from_first_date() {
+        use crate::JQuantsPremiumPlanClient;
+
+        let client = JQuantsPremiumPlanClient::new_from_refresh_token("refresh-token".to_string());
+        let mut query = None;
+        let stream = client.get_options_prices_range("2024-08-01", "2024-08-05", |builder| {
+            query = Some(serde_urlencoded::to_string(&builder).unwrap());
+            builder
+        });
+        drop(stream);
+        assert_eq!(query.as_deref(), Some("date=2024-08-01"));
+    }
 
     #[test]
     fn test_deserialize_options_prices_response() {
@@ -409,44 +575,48 @@ mod tests {
 
         let response: OptionsPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_option = vec![OptionsPricesItem {
             code: "140014505".to_string(),
             derivatives_product_category: "TOPIXE".to_string(),
             underlying_sso: UnderlyingSSO::Other,
-            date: "2024-07-23".to_string(),
-            whole_day_open: 0.0,
-            whole_day_high: 0.0,
-            whole_day_low: 0.0,
-            whole_day_close: 0.0,
+            date: "2024-07-23".parse().unwrap(),
+            whole_day_open: "0.0".parse().unwrap(),
+            whole_day_high: "0.0".parse().unwrap(),
+            whole_day_low: "0.0".parse().unwrap(),
+            whole_day_close: "0.0".parse().unwrap(),
             morning_session_open: None,
             morning_session_high: None,
             morning_session_low: None,
             morning_session_close: None,
-            night_session_open: Some(0.0),
-            night_session_high: Some(0.0),
-            night_session_low: Some(0.0),
-            night_session_close: Some(0.0),
-            day_session_open: Some(0.0),
-            day_session_high: 0.0,
-            day_session_low: 0.0,
-            day_session_close: 0.0,
+            night_session_open: Some("0.0".parse().unwrap()),
+            night_session_high: Some("0.0".parse().unwrap()),
+            night_session_low: Some("0.0".parse().unwrap()),
+            night_session_close: Some("0.0".parse().unwrap()),
+            day_session_open: Some("0.0".parse().unwrap()),
+            day_session_high: "0.0".parse().unwrap(),
+            day_session_low: "0.0".parse().unwrap(),
+            day_session_close: "0.0".parse().unwrap(),
             volume: 0.0,
             open_interest: 0.0,
-            turnover_value: 0.0,
+            turnover_value: "0.0".parse().unwrap(),
             contract_month: "2025-01".to_string(),
-            strike_price: 2450.0,
+            strike_price: "2450.0".parse().unwrap(),
             volume_only_auction: Some(0.0),
             emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Calculated,
             put_call_division: PutCallDivision::Call,
-            last_trading_day: Some("2025-01-09".to_string()),
-            special_quotation_day: Some("2025-01-10".to_string()),
-            settlement_price: Some(377.0),
-            theoretical_price: Some(380.3801),
+            last_trading_day: Some("2025-01-09".parse().unwrap()),
+            special_quotation_day: Some("2025-01-10".parse().unwrap()),
+            settlement_price: Some("377.0".parse().unwrap()),
+            theoretical_price: Some("380.3801".parse().unwrap()),
             base_volatility: Some(18.115),
-            underlying_price: Some(2833.39),
+            underlying_price: Some("2833.39".parse().unwrap()),
             implied_volatility: Some(17.2955),
             interest_rate: Some(0.3527),
             central_contract_month_flag: Some(CentralContractMonthFlag::Others),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = OptionsPricesResponse {
@@ -508,15 +678,17 @@ mod tests {
 
         let response: OptionsPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_option = vec![OptionsPricesItem {
             code: "140014505".to_string(),
             derivatives_product_category: "TOPIXE".to_string(),
             underlying_sso: UnderlyingSSO::Other,
-            date: "2024-07-23".to_string(),
-            whole_day_open: 0.0,
-            whole_day_high: 0.0,
-            whole_day_low: 0.0,
-            whole_day_close: 0.0,
+            date: "2024-07-23".parse().unwrap(),
+            whole_day_open: "0.0".parse().unwrap(),
+            whole_day_high: "0.0".parse().unwrap(),
+            whole_day_low: "0.0".parse().unwrap(),
+            whole_day_close: "0.0".parse().unwrap(),
             morning_session_open: None,
             morning_session_high: None,
             morning_session_low: None,
@@ -526,19 +698,19 @@ mod tests {
             night_session_low: None,
             night_session_close: None,
             day_session_open: None,
-            day_session_high: 0.0,
-            day_session_low: 0.0,
-            day_session_close: 0.0,
+            day_session_high: "0.0".parse().unwrap(),
+            day_session_low: "0.0".parse().unwrap(),
+            day_session_close: "0.0".parse().unwrap(),
             volume: 0.0,
             open_interest: 0.0,
-            turnover_value: 0.0,
+            turnover_value: "0.0".parse().unwrap(),
             contract_month: "2025-01".to_string(),
-            strike_price: 2450.0,
+            strike_price: "2450.0".parse().unwrap(),
             volume_only_auction: None,
             emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Triggered,
             put_call_division: PutCallDivision::Call,
-            last_trading_day: Some("2025-01-09".to_string()),
-            special_quotation_day: Some("2025-01-10".to_string()),
+            last_trading_day: Some("2025-01-09".parse().unwrap()),
+            special_quotation_day: Some("2025-01-10".parse().unwrap()),
             settlement_price: None,
             theoretical_price: None,
             base_volatility: None,
@@ -546,6 +718,8 @@ mod tests {
             implied_volatility: None,
             interest_rate: None,
             central_contract_month_flag: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = OptionsPricesResponse {
@@ -646,84 +820,90 @@ mod tests {
 
         let response: OptionsPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_options = vec![
             OptionsPricesItem {
                 code: "140014505".to_string(),
                 derivatives_product_category: "TOPIXE".to_string(),
                 underlying_sso: UnderlyingSSO::Other,
-                date: "2024-07-23".to_string(),
-                whole_day_open: 1000.0,
-                whole_day_high: 1050.0,
-                whole_day_low: 990.0,
-                whole_day_close: 1025.0,
-                morning_session_open: Some(1005.0),
-                morning_session_high: Some(1045.0),
-                morning_session_low: Some(995.0),
-                morning_session_close: Some(1020.0),
-                night_session_open: Some(1010.0),
-                night_session_high: Some(1040.0),
-                night_session_low: Some(995.0),
-                night_session_close: Some(1030.0),
-                day_session_open: Some(1025.0),
-                day_session_high: 1060.0,
-                day_session_low: 1000.0,
-                day_session_close: 1045.0,
+                date: "2024-07-23".parse().unwrap(),
+                whole_day_open: "1000.0".parse().unwrap(),
+                whole_day_high: "1050.0".parse().unwrap(),
+                whole_day_low: "990.0".parse().unwrap(),
+                whole_day_close: "1025.0".parse().unwrap(),
+                morning_session_open: Some("1005.0".parse().unwrap()),
+                morning_session_high: Some("1045.0".parse().unwrap()),
+                morning_session_low: Some("995.0".parse().unwrap()),
+                morning_session_close: Some("1020.0".parse().unwrap()),
+                night_session_open: Some("1010.0".parse().unwrap()),
+                night_session_high: Some("1040.0".parse().unwrap()),
+                night_session_low: Some("995.0".parse().unwrap()),
+                night_session_close: Some("1030.0".parse().unwrap()),
+                day_session_open: Some("1025.0".parse().unwrap()),
+                day_session_high: "1060.0".parse().unwrap(),
+                day_session_low: "1000.0".parse().unwrap(),
+                day_session_close: "1045.0".parse().unwrap(),
                 volume: 1500.0,
                 open_interest: 300.0,
-                turnover_value: 1500000.0,
+                turnover_value: "1500000.0".parse().unwrap(),
                 contract_month: "2025-02".to_string(),
-                strike_price: 2500.0,
+                strike_price: "2500.0".parse().unwrap(),
                 volume_only_auction: Some(500.0),
                 emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Triggered,
                 put_call_division: PutCallDivision::Put,
-                last_trading_day: Some("2025-02-09".to_string()),
-                special_quotation_day: Some("2025-02-10".to_string()),
-                settlement_price: Some(1025.0),
-                theoretical_price: Some(1030.5001),
+                last_trading_day: Some("2025-02-09".parse().unwrap()),
+                special_quotation_day: Some("2025-02-10".parse().unwrap()),
+                settlement_price: Some("1025.0".parse().unwrap()),
+                theoretical_price: Some("1030.5001".parse().unwrap()),
                 base_volatility: Some(19.200),
-                underlying_price: Some(2850.00),
+                underlying_price: Some("2850.00".parse().unwrap()),
                 implied_volatility: Some(18.5000),
                 interest_rate: Some(0.3600),
                 central_contract_month_flag: Some(CentralContractMonthFlag::CentralContractMonth),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
             OptionsPricesItem {
                 code: "140014506".to_string(),
                 derivatives_product_category: "TOPIXE".to_string(),
                 underlying_sso: UnderlyingSSO::Other,
-                date: "2024-07-23".to_string(),
-                whole_day_open: 2000.0,
-                whole_day_high: 2050.0,
-                whole_day_low: 1990.0,
-                whole_day_close: 2025.0,
-                morning_session_open: Some(2005.0),
-                morning_session_high: Some(2045.0),
-                morning_session_low: Some(1995.0),
-                morning_session_close: Some(2020.0),
-                night_session_open: Some(2010.0),
-                night_session_high: Some(2040.0),
-                night_session_low: Some(1995.0),
-                night_session_close: Some(2030.0),
-                day_session_open: Some(2025.0),
-                day_session_high: 2060.0,
-                day_session_low: 2000.0,
-                day_session_close: 2045.0,
+                date: "2024-07-23".parse().unwrap(),
+                whole_day_open: "2000.0".parse().unwrap(),
+                whole_day_high: "2050.0".parse().unwrap(),
+                whole_day_low: "1990.0".parse().unwrap(),
+                whole_day_close: "2025.0".parse().unwrap(),
+                morning_session_open: Some("2005.0".parse().unwrap()),
+                morning_session_high: Some("2045.0".parse().unwrap()),
+                morning_session_low: Some("1995.0".parse().unwrap()),
+                morning_session_close: Some("2020.0".parse().unwrap()),
+                night_session_open: Some("2010.0".parse().unwrap()),
+                night_session_high: Some("2040.0".parse().unwrap()),
+                night_session_low: Some("1995.0".parse().unwrap()),
+                night_session_close: Some("2030.0".parse().unwrap()),
+                day_session_open: Some("2025.0".parse().unwrap()),
+                day_session_high: "2060.0".parse().unwrap(),
+                day_session_low: "2000.0".parse().unwrap(),
+                day_session_close: "2045.0".parse().unwrap(),
                 volume: 2500.0,
                 open_interest: 400.0,
-                turnover_value: 2500000.0,
+                turnover_value: "2500000.0".parse().unwrap(),
                 contract_month: "2025-03".to_string(),
-                strike_price: 2550.0,
+                strike_price: "2550.0".parse().unwrap(),
                 volume_only_auction: Some(600.0),
                 emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Calculated,
                 put_call_division: PutCallDivision::Call,
-                last_trading_day: Some("2025-03-09".to_string()),
-                special_quotation_day: Some("2025-03-10".to_string()),
-                settlement_price: Some(2025.0),
-                theoretical_price: Some(2030.5001),
+                last_trading_day: Some("2025-03-09".parse().unwrap()),
+                special_quotation_day: Some("2025-03-10".parse().unwrap()),
+                settlement_price: Some("2025.0".parse().unwrap()),
+                theoretical_price: Some("2030.5001".parse().unwrap()),
                 base_volatility: Some(19.500),
-                underlying_price: Some(2855.00),
+                underlying_price: Some("2855.00".parse().unwrap()),
                 implied_volatility: Some(18.7000),
                 interest_rate: Some(0.3650),
                 central_contract_month_flag: Some(CentralContractMonthFlag::CentralContractMonth),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
         ];
 
@@ -785,44 +965,48 @@ mod tests {
 
         let response: OptionsPricesResponse = serde_json::from_str(json_data).unwrap();
 
+        assert_round_trip(&response);
+
         let expected_option = vec![OptionsPricesItem {
             code: "140014505".to_string(),
             derivatives_product_category: "TOPIXE".to_string(),
             underlying_sso: UnderlyingSSO::Other,
-            date: "2024-07-23".to_string(),
-            whole_day_open: 0.0,
-            whole_day_high: 0.0,
-            whole_day_low: 0.0,
-            whole_day_close: 0.0,
+            date: "2024-07-23".parse().unwrap(),
+            whole_day_open: "0.0".parse().unwrap(),
+            whole_day_high: "0.0".parse().unwrap(),
+            whole_day_low: "0.0".parse().unwrap(),
+            whole_day_close: "0.0".parse().unwrap(),
             morning_session_open: None,
             morning_session_high: None,
             morning_session_low: None,
             morning_session_close: None,
-            night_session_open: Some(0.0),
-            night_session_high: Some(0.0),
-            night_session_low: Some(0.0),
-            night_session_close: Some(0.0),
-            day_session_open: Some(0.0),
-            day_session_high: 0.0,
-            day_session_low: 0.0,
-            day_session_close: 0.0,
+            night_session_open: Some("0.0".parse().unwrap()),
+            night_session_high: Some("0.0".parse().unwrap()),
+            night_session_low: Some("0.0".parse().unwrap()),
+            night_session_close: Some("0.0".parse().unwrap()),
+            day_session_open: Some("0.0".parse().unwrap()),
+            day_session_high: "0.0".parse().unwrap(),
+            day_session_low: "0.0".parse().unwrap(),
+            day_session_close: "0.0".parse().unwrap(),
             volume: 0.0,
             open_interest: 0.0,
-            turnover_value: 0.0,
+            turnover_value: "0.0".parse().unwrap(),
             contract_month: "2025-01".to_string(),
-            strike_price: 2450.0,
+            strike_price: "2450.0".parse().unwrap(),
             volume_only_auction: Some(0.0),
             emergency_margin_trigger_division: EmergencyMarginTriggerDivision::Calculated,
             put_call_division: PutCallDivision::Call,
-            last_trading_day: Some("2025-01-09".to_string()),
-            special_quotation_day: Some("2025-01-10".to_string()),
-            settlement_price: Some(377.0),
-            theoretical_price: Some(380.3801),
+            last_trading_day: Some("2025-01-09".parse().unwrap()),
+            special_quotation_day: Some("2025-01-10".parse().unwrap()),
+            settlement_price: Some("377.0".parse().unwrap()),
+            theoretical_price: Some("380.3801".parse().unwrap()),
             base_volatility: Some(18.115),
-            underlying_price: Some(2833.39),
+            underlying_price: Some("2833.39".parse().unwrap()),
             implied_volatility: Some(17.2955),
             interest_rate: Some(0.3527),
             central_contract_month_flag: Some(CentralContractMonthFlag::Others),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         }];
 
         let expected_response = OptionsPricesResponse {
@@ -842,6 +1026,8 @@ mod tests {
         "#;
 
         let response: OptionsPricesResponse = serde_json::from_str(json_data).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = OptionsPricesResponse {
             options: vec![],
             pagination_key: None,
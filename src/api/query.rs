@@ -0,0 +1,865 @@
+//! Client-independent query definitions.
+//!
+//! A [`Query`] holds the endpoint and the params of a request without the client,
+//! so it can be saved to config (e.g. JSON or TOML) and executed later with [`QueryApi::execute`].
+
+use std::future::Future;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    breakdown_trading_data::{BreakdownTradingDataBuilder, BreakdownTradingDataResponse},
+    cash_dividend_data::{CashDividendDataBuilder, CashDividendDataResponse},
+    daily_stock_prices::{
+        DailyStockPricesApi, DailyStockPricesBuilder, DailyStockPricesStandardPlanResponse,
+    },
+    earnings_calendar::{EarningsCalendarBuilder, EarningsCalendarResponse},
+    financial_statement_details::{
+        FinancialStatementDetailsBuilder, FinancialStatementDetailsResponse,
+    },
+    financial_statements::{FinancialStatementsBuilder, FinancialStatementsResponse},
+    futures_prices::{FuturesPricesBuilder, FuturesPricesResponse},
+    index_option_prices::{IndexOptionPricesBuilder, IndexOptionPricesResponse},
+    indicies::{IndicesBuilder, IndicesResponse},
+    listed_issue_info::{
+        ListedIssueInfoApi, ListedIssueInfoApiBuilder, ListedIssueInfoStandardPlanResponse,
+    },
+    morning_session_stock_prices::{
+        MorningSessionStockPricesApiBuilder, MorningSessionStockPricesResponse,
+    },
+    options_prices::{OptionsPricesBuilder, OptionsPricesResponse},
+    shared::{
+        traits::{
+            builder::JQuantsBuilder,
+            pagination::{HasPaginationKey, MergePage, Paginatable},
+            request_preview::RequestPreview,
+        },
+        types::{
+            central_contract_month_flag::CentralContractMonthFlag, futures_code::FuturesCode,
+            holiday_division::HolidayDivision, index_code::IndexCode, issue_code::IssueCode,
+            options_code::OptionsCode, query_date::QueryDate, section_name::SectionName,
+            sector33_code::Sector33Code,
+        },
+    },
+    short_sale_by_sector::{ShortSaleBySectorBuilder, ShortSaleBySectorResponse},
+    topic_prices::{TopixPricesBuilder, TopixPricesResponse},
+    trading_by_type_of_investors::{TradingByInvestorTypeBuilder, TradingByInvestorTypeResponse},
+    trading_calendar::{TradingCalendarBuilder, TradingCalendarResponse},
+    weekly_margin_trading_outstandings::{
+        WeeklyMarginTradingOutstandingsBuilder, WeeklyMarginTradingOutstandingsResponse,
+    },
+};
+use crate::JQuantsError;
+
+/// Query of an endpoint.
+///
+/// The endpoint is tagged by `endpoint` and the params are flattened, e.g.
+/// `{"endpoint": "daily_stock_prices", "code": "86970", "from": "2024-08-01"}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "endpoint", rename_all = "snake_case")]
+pub enum Query {
+    /// Listed Issue Information (/listed/info)
+    ListedIssueInfo(ListedIssueInfoQuery),
+    /// Daily Stock Prices (/prices/daily_quotes)
+    DailyStockPrices(DailyStockPricesQuery),
+    /// Morning Session Stock Prices (/prices/prices_am)
+    MorningSessionStockPrices(MorningSessionStockPricesQuery),
+    /// Trading by Type of Investors (/markets/trades_spec)
+    TradingByInvestorType(TradingByInvestorTypeQuery),
+    /// Weekly Margin Trading Outstandings (/markets/weekly_margin_interest)
+    WeeklyMarginTradingOutstandings(WeeklyMarginTradingOutstandingsQuery),
+    /// Short Sale Value and Ratio by Sector (/markets/short_selling)
+    ShortSaleBySector(ShortSaleBySectorQuery),
+    /// Breakdown Trading Data (/markets/breakdown)
+    BreakdownTradingData(BreakdownTradingDataQuery),
+    /// Trading Calendar (/markets/trading_calendar)
+    TradingCalendar(TradingCalendarQuery),
+    /// Indices (/indices)
+    Indices(IndicesQuery),
+    /// TOPIX Prices (/indices/topix)
+    TopixPrices(TopixPricesQuery),
+    /// Financial Statements (/fins/statements)
+    FinancialStatements(FinancialStatementsQuery),
+    /// Financial Statement Details (/fins/fs_details)
+    FinancialStatementDetails(FinancialStatementDetailsQuery),
+    /// Cash Dividend Data (/fins/dividend)
+    CashDividendData(CashDividendDataQuery),
+    /// Earnings Calendar (/fins/announcement)
+    EarningsCalendar(EarningsCalendarQuery),
+    /// Index Option Prices (/option/index_option)
+    IndexOptionPrices(IndexOptionPricesQuery),
+    /// Futures Prices (/derivatives/futures)
+    FuturesPrices(FuturesPricesQuery),
+    /// Options Prices (/derivatives/options)
+    OptionsPrices(OptionsPricesQuery),
+}
+
+impl Query {
+    /// Get the path of the endpoint (e.g. "prices/daily_quotes").
+    pub fn path(&self) -> &'static str {
+        match self {
+            Self::ListedIssueInfo(_) => {
+                ListedIssueInfoApiBuilder::<ListedIssueInfoStandardPlanResponse>::PATH
+            }
+            Self::DailyStockPrices(_) => {
+                DailyStockPricesBuilder::<DailyStockPricesStandardPlanResponse>::PATH
+            }
+            Self::MorningSessionStockPrices(_) => MorningSessionStockPricesApiBuilder::PATH,
+            Self::TradingByInvestorType(_) => TradingByInvestorTypeBuilder::PATH,
+            Self::WeeklyMarginTradingOutstandings(_) => {
+                WeeklyMarginTradingOutstandingsBuilder::PATH
+            }
+            Self::ShortSaleBySector(_) => ShortSaleBySectorBuilder::PATH,
+            Self::BreakdownTradingData(_) => BreakdownTradingDataBuilder::PATH,
+            Self::TradingCalendar(_) => TradingCalendarBuilder::PATH,
+            Self::Indices(_) => IndicesBuilder::PATH,
+            Self::TopixPrices(_) => TopixPricesBuilder::PATH,
+            Self::FinancialStatements(_) => FinancialStatementsBuilder::PATH,
+            Self::FinancialStatementDetails(_) => FinancialStatementDetailsBuilder::PATH,
+            Self::CashDividendData(_) => CashDividendDataBuilder::PATH,
+            Self::EarningsCalendar(_) => EarningsCalendarBuilder::PATH,
+            Self::IndexOptionPrices(_) => IndexOptionPricesBuilder::PATH,
+            Self::FuturesPrices(_) => FuturesPricesBuilder::PATH,
+            Self::OptionsPrices(_) => OptionsPricesBuilder::PATH,
+        }
+    }
+}
+
+/// Pattern of the [`Query`] variants executed with the API trait of a plan client.
+///
+/// `impl_plan_apis` builds [`QueryApi::is_available`] from it,
+/// so that the available queries follow the API traits that the client implements.
+macro_rules! query_pattern {
+    (ListedIssueInfoApi) => {
+        $crate::api::query::Query::ListedIssueInfo(_)
+    };
+    (DailyStockPricesApi) => {
+        $crate::api::query::Query::DailyStockPrices(_)
+    };
+    (MorningSessionStockPricesApi) => {
+        $crate::api::query::Query::MorningSessionStockPrices(_)
+    };
+    (TradingByInvestorTypeApi) => {
+        $crate::api::query::Query::TradingByInvestorType(_)
+    };
+    (WeeklyMarginTradingOutstandingsApi) => {
+        $crate::api::query::Query::WeeklyMarginTradingOutstandings(_)
+    };
+    (ShortSaleBySectorApi) => {
+        $crate::api::query::Query::ShortSaleBySector(_)
+    };
+    (BreakdownTradingDataApi) => {
+        $crate::api::query::Query::BreakdownTradingData(_)
+    };
+    (TradingCalendarApi) => {
+        $crate::api::query::Query::TradingCalendar(_)
+    };
+    (IndicesApi) => {
+        $crate::api::query::Query::Indices(_)
+    };
+    (TopixPricesApi) => {
+        $crate::api::query::Query::TopixPrices(_)
+    };
+    (FinancialStatementsApi) => {
+        $crate::api::query::Query::FinancialStatements(_)
+    };
+    (FinancialStatementDetailsApi) => {
+        $crate::api::query::Query::FinancialStatementDetails(_)
+    };
+    (CashDividendDataApi) => {
+        $crate::api::query::Query::CashDividendData(_)
+    };
+    (EarningsCalendarApi) => {
+        $crate::api::query::Query::EarningsCalendar(_)
+    };
+    (IndexOptionPricesApi) => {
+        $crate::api::query::Query::IndexOptionPrices(_)
+    };
+    (FuturesPricesApi) => {
+        $crate::api::query::Query::FuturesPrices(_)
+    };
+    (OptionsPricesApi) => {
+        $crate::api::query::Query::OptionsPrices(_)
+    };
+}
+pub(crate) use query_pattern;
+
+/// Params of [`Query::ListedIssueInfo`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListedIssueInfoQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+    /// Date of application of information (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::DailyStockPrices`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DailyStockPricesQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+    /// Date of data (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::MorningSessionStockPrices`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MorningSessionStockPricesQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+}
+
+/// Params of [`Query::TradingByInvestorType`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TradingByInvestorTypeQuery {
+    /// Section name (e.g. "TSEPrime")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<SectionName>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+}
+
+/// Params of [`Query::WeeklyMarginTradingOutstandings`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WeeklyMarginTradingOutstandingsQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+    /// Date of data (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+}
+
+/// Params of [`Query::ShortSaleBySector`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShortSaleBySectorQuery {
+    /// 33-sector code (e.g. "0050" or "50")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sector33code: Option<Sector33Code>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+    /// Date of data (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::BreakdownTradingData`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BreakdownTradingDataQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+    /// Date of data (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::TradingCalendar`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TradingCalendarQuery {
+    /// Holiday division (e.g. "1")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holiday_division: Option<HolidayDivision>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+}
+
+/// Params of [`Query::Indices`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IndicesQuery {
+    /// Index code (e.g. "0000" or "0028")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IndexCode>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+    /// Date of data (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::TopixPrices`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TopixPricesQuery {
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+    /// Date of data (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::FinancialStatements`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FinancialStatementsQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+    /// Disclosure date (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::FinancialStatementDetails`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FinancialStatementDetailsQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+    /// Disclosure date (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+}
+
+/// Params of [`Query::CashDividendData`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CashDividendDataQuery {
+    /// Issue code (e.g. 27800 or 2780)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+    /// Announcement date (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<QueryDate>,
+    /// Starting point of data period (e.g. 20210901 or 2021-09-01)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<QueryDate>,
+    /// End point of data period (e.g. 20210907 or 2021-09-07)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<QueryDate>,
+}
+
+/// Params of [`Query::EarningsCalendar`].
+///
+/// The endpoint has no params, so any key is rejected.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EarningsCalendarQuery {}
+
+/// Params of [`Query::IndexOptionPrices`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IndexOptionPricesQuery {
+    /// Date of data (e.g. 20210901 or 2021-09-01)
+    pub date: QueryDate,
+}
+
+/// Params of [`Query::FuturesPrices`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FuturesPricesQuery {
+    /// Date of data (e.g. 20210901 or 2021-09-01)
+    pub date: QueryDate,
+    /// Category of data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<FuturesCode>,
+    /// Central contract month flag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub central_contract_month_flag: Option<CentralContractMonthFlag>,
+}
+
+/// Params of [`Query::OptionsPrices`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OptionsPricesQuery {
+    /// Date of data (e.g. 20210901 or 2021-09-01)
+    pub date: QueryDate,
+    /// Category of data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<OptionsCode>,
+    /// Issue code of the underlying security (only for securities options)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlying_sso: Option<IssueCode>,
+    /// Central contract month flag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_flag: Option<CentralContractMonthFlag>,
+}
+
+/// Response of a [`Query`].
+///
+/// `D` and `L` are the plan-specific responses of daily stock prices and listed issue information.
+/// Use [`QueryResult`] to get them from the client.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryResponse<D, L> {
+    /// Listed Issue Information (/listed/info)
+    ListedIssueInfo(L),
+    /// Daily Stock Prices (/prices/daily_quotes)
+    DailyStockPrices(D),
+    /// Morning Session Stock Prices (/prices/prices_am)
+    MorningSessionStockPrices(MorningSessionStockPricesResponse),
+    /// Trading by Type of Investors (/markets/trades_spec)
+    TradingByInvestorType(TradingByInvestorTypeResponse),
+    /// Weekly Margin Trading Outstandings (/markets/weekly_margin_interest)
+    WeeklyMarginTradingOutstandings(WeeklyMarginTradingOutstandingsResponse),
+    /// Short Sale Value and Ratio by Sector (/markets/short_selling)
+    ShortSaleBySector(ShortSaleBySectorResponse),
+    /// Breakdown Trading Data (/markets/breakdown)
+    BreakdownTradingData(BreakdownTradingDataResponse),
+    /// Trading Calendar (/markets/trading_calendar)
+    TradingCalendar(TradingCalendarResponse),
+    /// Indices (/indices)
+    Indices(IndicesResponse),
+    /// TOPIX Prices (/indices/topix)
+    TopixPrices(TopixPricesResponse),
+    /// Financial Statements (/fins/statements)
+    FinancialStatements(FinancialStatementsResponse),
+    /// Financial Statement Details (/fins/fs_details)
+    FinancialStatementDetails(FinancialStatementDetailsResponse),
+    /// Cash Dividend Data (/fins/dividend)
+    CashDividendData(CashDividendDataResponse),
+    /// Earnings Calendar (/fins/announcement)
+    EarningsCalendar(EarningsCalendarResponse),
+    /// Index Option Prices (/option/index_option)
+    IndexOptionPrices(IndexOptionPricesResponse),
+    /// Futures Prices (/derivatives/futures)
+    FuturesPrices(FuturesPricesResponse),
+    /// Options Prices (/derivatives/options)
+    OptionsPrices(OptionsPricesResponse),
+}
+
+/// Response of a [`Query`] for the plan client `C`.
+pub type QueryResult<C> =
+    QueryResponse<<C as DailyStockPricesApi>::Response, <C as ListedIssueInfoApi>::Response>;
+
+/// Trait for executing a [`Query`].
+pub trait QueryApi: DailyStockPricesApi + ListedIssueInfoApi {
+    /// Whether the endpoint of the query is available in the plan.
+    ///
+    /// The plan clients implement it with the API traits of the plan.
+    fn is_available(&self, query: &Query) -> bool;
+
+    /// Execute the query.
+    ///
+    /// All pages are fetched and merged.
+    /// An endpoint that is not available in the plan fails with [`JQuantsError::InvalidParameters`]
+    /// and no request is sent.
+    fn execute(&self, query: Query) -> impl Future<Output = Result<QueryResult<Self>, JQuantsError>>
+    where
+        <Self as DailyStockPricesApi>::Response: HasPaginationKey + MergePage,
+    {
+        async move {
+            if !self.is_available(&query) {
+                return Err(JQuantsError::InvalidParameters(format!(
+                    "`{}` is not available in this plan",
+                    query.path()
+                )));
+            }
+
+            let client = self.get_api_client().clone();
+            let response = match query {
+                Query::ListedIssueInfo(params) => {
+                    let mut builder = self.get_listed_issue_info();
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::ListedIssueInfo(builder.send().await?)
+                }
+                Query::DailyStockPrices(params) => {
+                    let mut builder = self.get_daily_stock_prices();
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::DailyStockPrices(builder.fetch_all_and_merge().await?)
+                }
+                Query::MorningSessionStockPrices(params) => {
+                    let mut builder = MorningSessionStockPricesApiBuilder::new(client);
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    QueryResponse::MorningSessionStockPrices(builder.fetch_all_and_merge().await?)
+                }
+                Query::TradingByInvestorType(params) => {
+                    let mut builder = TradingByInvestorTypeBuilder::new(client);
+                    if let Some(section) = params.section {
+                        builder = builder.section(section);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    QueryResponse::TradingByInvestorType(builder.fetch_all_and_merge().await?)
+                }
+                Query::WeeklyMarginTradingOutstandings(params) => {
+                    let mut builder = WeeklyMarginTradingOutstandingsBuilder::new(client);
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    QueryResponse::WeeklyMarginTradingOutstandings(
+                        builder.fetch_all_and_merge().await?,
+                    )
+                }
+                Query::ShortSaleBySector(params) => {
+                    let mut builder = ShortSaleBySectorBuilder::new(client);
+                    if let Some(sector33code) = params.sector33code {
+                        builder = builder.sector33code(sector33code);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::ShortSaleBySector(builder.fetch_all_and_merge().await?)
+                }
+                Query::BreakdownTradingData(params) => {
+                    let mut builder = BreakdownTradingDataBuilder::new(client);
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::BreakdownTradingData(builder.fetch_all_and_merge().await?)
+                }
+                Query::TradingCalendar(params) => {
+                    let mut builder = TradingCalendarBuilder::new(client);
+                    if let Some(holiday_division) = params.holiday_division {
+                        builder = builder.holiday_division(holiday_division);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    QueryResponse::TradingCalendar(builder.send().await?)
+                }
+                Query::Indices(params) => {
+                    let mut builder = IndicesBuilder::new(client);
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::Indices(builder.fetch_all_and_merge().await?)
+                }
+                Query::TopixPrices(params) => {
+                    let mut builder = TopixPricesBuilder::new(client);
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::TopixPrices(builder.fetch_all_and_merge().await?)
+                }
+                Query::FinancialStatements(params) => {
+                    let mut builder = FinancialStatementsBuilder::new(client);
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::FinancialStatements(builder.fetch_all_and_merge().await?)
+                }
+                Query::FinancialStatementDetails(params) => {
+                    let mut builder = FinancialStatementDetailsBuilder::new(client);
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    QueryResponse::FinancialStatementDetails(builder.fetch_all_and_merge().await?)
+                }
+                Query::CashDividendData(params) => {
+                    let mut builder = CashDividendDataBuilder::new(client);
+                    if let Some(code) = params.code {
+                        builder = builder.code(code);
+                    }
+                    if let Some(date) = params.date {
+                        builder = builder.date(date);
+                    }
+                    if let Some(from) = params.from {
+                        builder = builder.from(from);
+                    }
+                    if let Some(to) = params.to {
+                        builder = builder.to(to);
+                    }
+                    QueryResponse::CashDividendData(builder.fetch_all_and_merge().await?)
+                }
+                Query::EarningsCalendar(_) => {
+                    let builder = EarningsCalendarBuilder::new(client);
+                    QueryResponse::EarningsCalendar(builder.fetch_all_and_merge().await?)
+                }
+                Query::IndexOptionPrices(params) => {
+                    let builder = IndexOptionPricesBuilder::new(client, params.date);
+                    QueryResponse::IndexOptionPrices(builder.fetch_all_and_merge().await?)
+                }
+                Query::FuturesPrices(params) => {
+                    let mut builder = FuturesPricesBuilder::new(client, params.date);
+                    if let Some(category) = params.category {
+                        builder = builder.category(category);
+                    }
+                    if let Some(flag) = params.central_contract_month_flag {
+                        builder = builder.central_contract_month_flag(flag);
+                    }
+                    QueryResponse::FuturesPrices(builder.fetch_all_and_merge().await?)
+                }
+                Query::OptionsPrices(params) => {
+                    if params.underlying_sso.is_some()
+                        && !matches!(params.category, None | Some(OptionsCode::EQOP))
+                    {
+                        return Err(JQuantsError::InvalidParameters(
+                            "`underlying_sso` requires the `EQOP` category".to_string(),
+                        ));
+                    }
+                    let mut builder = OptionsPricesBuilder::new(client, params.date);
+                    if let Some(category) = params.category {
+                        builder = builder.category(category);
+                    }
+                    if let Some(flag) = params.contract_flag {
+                        builder = builder.contract_flag(flag);
+                    }
+                    let response = match params.underlying_sso {
+                        Some(code) => {
+                            builder
+                                .securities_options()
+                                .underlying_sso(code)
+                                .fetch_all_and_merge()
+                                .await?
+                        }
+                        None => builder.fetch_all_and_merge().await?,
+                    };
+                    QueryResponse::OptionsPrices(response)
+                }
+            };
+
+            Ok(response)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntoIssueCode, IntoQueryDate, JQuantsFreePlanClient, JQuantsPlanClient};
+
+    use super::*;
+
+    fn daily_stock_prices_query() -> Query {
+        Query::DailyStockPrices(DailyStockPricesQuery {
+            code: Some("8697".into_issue_code().unwrap()),
+            from: Some("20240801".into_query_date()),
+            to: Some("2024-08-31".into_query_date()),
+            date: None,
+        })
+    }
+
+    #[test]
+    fn test_query_json_round_trip() {
+        let query = daily_stock_prices_query();
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"endpoint":"daily_stock_prices","code":"86970","from":"2024-08-01","to":"2024-08-31"}"#
+        );
+        assert_eq!(serde_json::from_str::<Query>(&json).unwrap(), query);
+
+        let query = Query::EarningsCalendar(EarningsCalendarQuery::default());
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"endpoint":"earnings_calendar"}"#);
+        assert_eq!(serde_json::from_str::<Query>(&json).unwrap(), query);
+    }
+
+    #[test]
+    fn test_query_toml_round_trip() {
+        let query = Query::OptionsPrices(OptionsPricesQuery {
+            date: "2024-08-01".into_query_date(),
+            category: Some(OptionsCode::EQOP),
+            underlying_sso: Some("7203".into_issue_code().unwrap()),
+            contract_flag: Some(CentralContractMonthFlag::CentralContractMonth),
+        });
+        let toml = toml::to_string(&query).unwrap();
+        assert_eq!(
+            toml,
+            "endpoint = \"options_prices\"\ndate = \"2024-08-01\"\ncategory = \"EQOP\"\nunderlying_sso = \"72030\"\ncontract_flag = \"1\"\n"
+        );
+        assert_eq!(toml::from_str::<Query>(&toml).unwrap(), query);
+
+        let query: Query = toml::from_str(
+            r#"
+            endpoint = "financial_statements"
+            code = "8697"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            query,
+            Query::FinancialStatements(FinancialStatementsQuery {
+                code: Some("86970".into_issue_code().unwrap()),
+                date: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_query_invalid_params() {
+        let result = serde_json::from_str::<Query>(
+            r#"{"endpoint":"daily_stock_prices","code":"86970","date":"2024-13-01"}"#,
+        );
+        assert!(result.is_err());
+
+        let error = serde_json::from_str::<Query>(
+            r#"{"endpoint":"daily_stock_prices","code":"86970","form":"2024-08-01"}"#,
+        )
+        .unwrap_err();
+        assert!(
+            error.to_string().contains("unknown field `form`"),
+            "{error}"
+        );
+
+        let result =
+            serde_json::from_str::<Query>(r#"{"endpoint":"earnings_calendar","date":"20240801"}"#);
+        assert!(result.is_err());
+
+        let error = toml::from_str::<Query>(
+            r#"
+            endpoint = "options_prices"
+            date = "2024-08-01"
+            underlying = "7203"
+            "#,
+        )
+        .unwrap_err();
+        assert!(
+            error.to_string().contains("unknown field `underlying`"),
+            "{error}"
+        );
+
+        let result = serde_json::from_str::<Query>(r#"{"endpoint":"unknown"}"#);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_unavailable_endpoint() {
+        let client = JQuantsFreePlanClient::new_from_refresh_token("refresh-token".to_string());
+        let query = Query::Indices(IndicesQuery::default());
+        assert!(!client.is_available(&query));
+
+        match client.execute(query).await {
+            Err(JQuantsError::InvalidParameters(message)) => {
+                assert_eq!(message, "`indices` is not available in this plan")
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert!(client.is_available(&daily_stock_prices_query()));
+    }
+
+    #[test]
+    fn test_is_available_follows_plan_apis() {
+        use crate::{JQuantsPremiumPlanClient, JQuantsStandardPlanClient};
+
+        let index_option_prices = Query::IndexOptionPrices(IndexOptionPricesQuery {
+            date: "2024-08-01".into_query_date(),
+        });
+        let futures_prices = Query::FuturesPrices(FuturesPricesQuery {
+            date: "2024-08-01".into_query_date(),
+            category: None,
+            central_contract_month_flag: None,
+        });
+
+        let client = JQuantsStandardPlanClient::new_from_refresh_token("refresh-token".to_string());
+        assert!(client.is_available(&index_option_prices));
+        assert!(!client.is_available(&futures_prices));
+
+        let client = JQuantsPremiumPlanClient::new_from_refresh_token("refresh-token".to_string());
+        assert!(client.is_available(&index_option_prices));
+        assert!(client.is_available(&futures_prices));
+        assert!(client.is_available(&Query::EarningsCalendar(EarningsCalendarQuery::default())));
+    }
+}
@@ -2,6 +2,11 @@
 
 pub mod auth;
 pub(crate) mod deserialize_utils;
+pub(crate) mod ja_text;
 pub mod responses;
+#[cfg(test)]
+pub(crate) mod test_utils;
 pub mod traits;
 pub mod types;
+pub mod unknown_variants;
+pub(crate) mod validation;
@@ -3,23 +3,26 @@
 use reqwest::Client;
 
 use crate::{
-    api::build_url, IdTokenRequest, IdTokenResponse, JQuantsError, JQuantsErrorResponse,
-    RefreshTokenRequest, RefreshTokenResponse,
+    api::{build_url, shared::deserialize_utils::from_json_str},
+    IdTokenRequest, IdTokenResponse, JQuantsError, JQuantsErrorResponse, RefreshTokenRequest,
+    RefreshTokenResponse, SecretString,
 };
 
 pub mod id_token;
 pub mod refresh_token;
+pub mod secret;
+pub mod token_status;
 
 /// Get a refresh token from the Refresh Token (/token/auth_user) API.
 pub(crate) async fn get_refresh_token_from_api(
     client: &Client,
-    mail_address: &str,
-    password: &str,
-) -> Result<String, JQuantsError> {
+    mail_address: &SecretString,
+    password: &SecretString,
+) -> Result<SecretString, JQuantsError> {
     let url = build_url("token/auth_user");
     let request_body = RefreshTokenRequest {
-        mail_address: mail_address.to_string(),
-        password: password.to_string(),
+        mail_address: mail_address.clone(),
+        password: password.clone(),
     };
 
     let response = client.post(&url).json(&request_body).send().await?;
@@ -27,15 +30,16 @@ pub(crate) async fn get_refresh_token_from_api(
     let status_code = status.as_u16();
     let text = response.text().await.unwrap_or_default();
     if status == reqwest::StatusCode::OK {
-        match serde_json::from_str::<RefreshTokenResponse>(&text) {
+        match from_json_str::<RefreshTokenResponse>(&text) {
             Ok(data) => Ok(data.refresh_token),
-            Err(_) => Err(JQuantsError::InvalidResponseFormat {
+            Err(source) => Err(JQuantsError::InvalidResponseFormat {
                 status_code,
                 body: text,
+                source,
             }),
         }
     } else {
-        match serde_json::from_str::<JQuantsErrorResponse>(&text) {
+        match from_json_str::<JQuantsErrorResponse>(&text) {
             Ok(error_response) => match status {
                 reqwest::StatusCode::BAD_REQUEST | reqwest::StatusCode::FORBIDDEN => {
                     Err(JQuantsError::InvalidCredentials {
@@ -48,9 +52,10 @@ pub(crate) async fn get_refresh_token_from_api(
                     status_code,
                 }),
             },
-            Err(_) => Err(JQuantsError::InvalidResponseFormat {
+            Err(source) => Err(JQuantsError::InvalidResponseFormat {
                 status_code,
                 body: text,
+                source,
             }),
         }
     }
@@ -59,26 +64,27 @@ pub(crate) async fn get_refresh_token_from_api(
 /// リフレッシュトークンを使用してAPI経由でIDトークンを取得
 pub(crate) async fn get_id_token_from_api(
     client: &Client,
-    refresh_token: &str,
-) -> Result<String, JQuantsError> {
+    refresh_token: &SecretString,
+) -> Result<SecretString, JQuantsError> {
     let url = build_url("token/auth_refresh");
     let request_body = IdTokenRequest {
-        refresh_token: refresh_token.to_string(),
+        refresh_token: refresh_token.clone(),
     };
     let response = client.post(&url).query(&request_body).send().await?;
     let status = response.status();
     let status_code = status.as_u16();
     let text = response.text().await.unwrap_or_default();
     if status == reqwest::StatusCode::OK {
-        match serde_json::from_str::<IdTokenResponse>(&text) {
+        match from_json_str::<IdTokenResponse>(&text) {
             Ok(data) => Ok(data.id_token),
-            Err(_) => Err(JQuantsError::InvalidResponseFormat {
+            Err(source) => Err(JQuantsError::InvalidResponseFormat {
                 status_code,
                 body: text,
+                source,
             }),
         }
     } else {
-        match serde_json::from_str::<JQuantsErrorResponse>(&text) {
+        match from_json_str::<JQuantsErrorResponse>(&text) {
             Ok(error_response) => match status {
                 reqwest::StatusCode::FORBIDDEN => Err(JQuantsError::IdTokenInvalidOrExpired {
                     body: error_response,
@@ -89,9 +95,10 @@ pub(crate) async fn get_id_token_from_api(
                     status_code,
                 }),
             },
-            Err(_) => Err(JQuantsError::InvalidResponseFormat {
+            Err(source) => Err(JQuantsError::InvalidResponseFormat {
                 status_code,
                 body: text,
+                source,
             }),
         }
     }
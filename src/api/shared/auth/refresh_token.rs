@@ -1,35 +1,23 @@
 //! Refresh Token (/token/auth_user) API definition.
 
-use std::fmt;
-
 use serde::{Deserialize, Serialize};
 
+use super::secret::SecretString;
+
 /// Request for get Refresh Token (/token/auth_user)
 ///
 /// See: [JQuants API](https://jpx.gitbook.io/j-quants-ja/api-reference/refreshtoken)
 ///
 /// # Security
 ///
-/// While the Debug trait is implemented, the `mailaddress` and `password` is masked due to security risks.
-/// If you wish to display it, please do so at your own responsibility.
-#[derive(Clone, Serialize)]
+/// The `mail_address` and `password` are [`SecretString`]s, so they are masked in `Debug` and `Display`.
+#[derive(Debug, Clone, Serialize)]
 pub struct RefreshTokenRequest {
     /// The mail address.
     #[serde(rename = "mailaddress")]
-    pub mail_address: String,
+    pub mail_address: SecretString,
     /// The password.
-    pub password: String,
-}
-
-impl fmt::Debug for RefreshTokenRequest {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let masking = "********";
-
-        f.debug_struct("RefreshTokenRequest")
-            .field("mail_address", &masking)
-            .field("password", &masking)
-            .finish()
-    }
+    pub password: SecretString,
 }
 
 /// Response for get Refresh Token (/token/auth_user)
@@ -38,21 +26,10 @@ impl fmt::Debug for RefreshTokenRequest {
 ///
 /// # Security
 ///
-/// While the Debug trait is implemented, the `refresh_token` is masked due to security risks.
-/// If you wish to display it, please do so at your own responsibility.
-#[derive(Deserialize)]
+/// The `refresh_token` is a [`SecretString`], so it is masked in `Debug` and `Display`.
+#[derive(Debug, Deserialize)]
 pub struct RefreshTokenResponse {
     /// The refresh token.
     #[serde(rename = "refreshToken")]
-    pub refresh_token: String,
-}
-
-impl fmt::Debug for RefreshTokenResponse {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let masking = "********";
-
-        f.debug_struct("RefreshTokenResponse")
-            .field("refresh_token", &masking)
-            .finish()
-    }
+    pub refresh_token: SecretString,
 }
@@ -0,0 +1,140 @@
+//! Secret string type for credentials and tokens.
+
+use std::fmt;
+
+use reqwest::header::{HeaderValue, InvalidHeaderValue};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
+
+/// Masked representation used by `Debug` and `Display`.
+const MASKING: &str = "********";
+
+/// A string holding a secret value such as a refresh token, an ID token, a mail address or a password.
+///
+/// # Security
+///
+/// - `Debug` and `Display` are redacted and never print the value.
+/// - The memory is zeroized when the value is dropped.
+/// - The value is only accessible through [`SecretString::expose_secret`].
+///
+/// `Serialize` writes the raw value because it is required to build authentication requests.
+/// Do not serialize it anywhere else unless you intend to store the secret.
+#[derive(Clone, Default)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Create a new secret string.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Expose the secret value.
+    ///
+    /// If you display or log the returned value, do so at your own risk.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// Build a sensitive `Authorization` header value (`Bearer <secret>`).
+    ///
+    /// The temporary string holding the header is zeroized.
+    pub(crate) fn to_bearer_header(&self) -> Result<HeaderValue, InvalidHeaderValue> {
+        const PREFIX: &str = "Bearer ";
+
+        // Reserve the exact capacity so that no copy is left behind by a reallocation.
+        let mut value = String::with_capacity(PREFIX.len() + self.0.len());
+        value.push_str(PREFIX);
+        value.push_str(&self.0);
+        let header = HeaderValue::from_str(&value);
+        value.zeroize();
+
+        let mut header = header?;
+        header.set_sensitive(true);
+        Ok(header)
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<&SecretString> for SecretString {
+    fn from(value: &SecretString) -> Self {
+        value.clone()
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(MASKING, f)
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MASKING)
+    }
+}
+
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_string_is_redacted() {
+        let secret = SecretString::new("my-refresh-token");
+
+        assert_eq!(format!("{secret}"), "********");
+        assert_eq!(format!("{secret:?}"), "\"********\"");
+        assert_eq!(secret.expose_secret(), "my-refresh-token");
+    }
+
+    #[test]
+    fn test_to_bearer_header_is_sensitive() {
+        let secret = SecretString::new("my-id-token");
+        let header = secret.to_bearer_header().unwrap();
+
+        assert!(header.is_sensitive());
+        assert_eq!(header.to_str().unwrap(), "Bearer my-id-token");
+        assert_eq!(format!("{header:?}"), "Sensitive");
+
+        assert!(SecretString::new("invalid\ntoken")
+            .to_bearer_header()
+            .is_err());
+    }
+
+    #[test]
+    fn test_secret_string_serde() {
+        let secret: SecretString = serde_json::from_str("\"my-id-token\"").unwrap();
+        assert_eq!(secret.expose_secret(), "my-id-token");
+
+        let serialized = serde_json::to_string(&secret).unwrap();
+        assert_eq!(serialized, "\"my-id-token\"");
+    }
+}
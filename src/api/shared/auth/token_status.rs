@@ -0,0 +1,70 @@
+//! Authentication state introspection.
+
+use chrono::{DateTime, Local, TimeDelta};
+
+/// Authentication state of a client.
+///
+/// This is a snapshot taken by `JQuantsPlanClient::token_status`.
+/// It never contains secret values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenStatus {
+    /// Whether the client holds an ID token.
+    pub has_id_token: bool,
+    /// ID token expiration time.
+    ///
+    /// `None` if the client does not hold an ID token.
+    pub id_token_expires_at: Option<DateTime<Local>>,
+    /// Time remaining until the ID token expires.
+    ///
+    /// Zero if the ID token has already expired, `None` if the client does not hold an ID token.
+    pub id_token_remaining: Option<TimeDelta>,
+    /// Time when the refresh token was obtained by this client.
+    ///
+    /// `None` if the refresh token was given from outside (e.g. `new_from_refresh_token`).
+    pub refresh_token_obtained_at: Option<DateTime<Local>>,
+    /// Estimated expiration time of the refresh token.
+    ///
+    /// The refresh token is valid for one week from `refresh_token_obtained_at`.
+    pub refresh_token_expires_at: Option<DateTime<Local>>,
+    /// Result of the last refresh attempt.
+    ///
+    /// `None` if no refresh has been attempted yet.
+    pub last_refresh: Option<TokenRefreshAttempt>,
+}
+
+impl TokenStatus {
+    /// Check if the client holds an ID token that has not expired yet.
+    pub fn is_id_token_valid(&self) -> bool {
+        self.id_token_remaining
+            .is_some_and(|remaining| remaining > TimeDelta::zero())
+    }
+}
+
+/// A refresh attempt of the refresh token or the ID token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenRefreshAttempt {
+    /// Kind of the token that was refreshed.
+    pub kind: TokenKind,
+    /// Time when the refresh was attempted.
+    pub attempted_at: DateTime<Local>,
+    /// Result of the refresh.
+    pub result: TokenRefreshResult,
+}
+
+/// Kind of the token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// Refresh token
+    RefreshToken,
+    /// ID token
+    IdToken,
+}
+
+/// Result of a refresh attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenRefreshResult {
+    /// The token was refreshed successfully.
+    Succeeded,
+    /// The refresh failed with the error message.
+    Failed(String),
+}
@@ -1,6 +1,19 @@
-use serde::de::Error;
+use serde::de::{DeserializeOwned, Error};
 use serde::{Deserialize, Deserializer};
-use std::{fmt, str::FromStr};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+use crate::error::DeserializeError;
+
+/// Deserialize a JSON body, tracking the path to the value that failed.
+pub(crate) fn from_json_str<T: DeserializeOwned>(text: &str) -> Result<T, DeserializeError> {
+    let mut deserializer = serde_json::Deserializer::from_str(text);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer.end().map_err(|e| DeserializeError {
+        path: ".".to_string(),
+        message: e.to_string(),
+    })?;
+    Ok(value)
+}
 
 /// Deserialize an empty string or null as None.
 pub(crate) fn empty_string_or_null_as_none<'de, D, T>(
@@ -20,41 +33,61 @@ where
 }
 
 /// Helper function to deserialize fields that can be either a number or a string.
-/// If the field is a number, it returns the number as `Some(f64)`.
-/// If the field is a string representing a number, it parses and returns `Some(f64)`.
-/// If the field is "", or any non-numeric string, it returns `None`.
-pub(crate) fn deserialize_f64_or_none<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+/// If the field is a number, it returns the number as `Some(T)`.
+/// If the field is a string representing a number, it parses and returns `Some(T)`.
+/// If the field is "" or null, it returns `None`.
+pub(crate) fn deserialize_number_or_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
 {
-    struct F64OrNoneVisitor;
+    struct NumberOrNoneVisitor<T>(PhantomData<T>);
 
-    impl serde::de::Visitor<'_> for F64OrNoneVisitor {
-        type Value = Option<f64>;
+    impl<T> serde::de::Visitor<'_> for NumberOrNoneVisitor<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        type Value = Option<T>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a float or a string representing a float")
+            formatter.write_str("a number, a string representing a number, or null")
         }
 
         fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
         where
             E: serde::de::Error,
         {
-            Ok(Some(value))
+            self.visit_str(&value.to_string())
         }
 
         fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
         where
             E: serde::de::Error,
         {
-            Ok(Some(value as f64))
+            self.visit_str(&value.to_string())
         }
 
         fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
         where
             E: serde::de::Error,
         {
-            Ok(Some(value as f64))
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(None)
         }
 
         fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
@@ -63,7 +96,7 @@ where
         {
             match value.trim() {
                 "" => Ok(None),
-                s => s.parse::<f64>().map(Some).map_err(serde::de::Error::custom),
+                s => s.parse::<T>().map(Some).map_err(serde::de::Error::custom),
             }
         }
 
@@ -75,5 +108,5 @@ where
         }
     }
 
-    deserializer.deserialize_any(F64OrNoneVisitor)
+    deserializer.deserialize_any(NumberOrNoneVisitor(PhantomData))
 }
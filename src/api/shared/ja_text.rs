@@ -0,0 +1,31 @@
+//! Helpers for Japanese texts of responses.
+
+/// Normalizes a Japanese text for comparison.
+///
+/// Full-width ASCII characters are converted into half-width ones,
+/// half-width middle dots ("･") into full-width ones ("・"), and white spaces are removed.
+pub(crate) fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{FF65}' => '・',
+            _ => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("第１四半期"), "第1四半期");
+        assert_eq!(normalize("情報･通信業"), "情報・通信業");
+        assert_eq!(
+            normalize("ＪＡＳＤＡＱ　スタンダード"),
+            "JASDAQスタンダード"
+        );
+    }
+}
@@ -2,10 +2,10 @@
 
 use std::{error::Error, fmt};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// The common error response definition for the JQuants API.
-#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct JQuantsErrorResponse {
     /// The error message.
     pub message: String,
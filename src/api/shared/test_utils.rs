@@ -0,0 +1,200 @@
+//! Test utilities shared by the API modules.
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    api::{
+        build_request_url,
+        shared::{
+            traits::{
+                code_batch::CodeBatchable, date_iter::DateIterable, date_range::DateRangeShardable,
+            },
+            types::issue_code::serialize_issue_code_param,
+        },
+    },
+    IntoIssueCode, IntoQueryDate, IssueCode, JQuantsBuilder, JQuantsError, JQuantsSendBuilder,
+    Paginatable, ParseIssueCodeError, QueryDate,
+};
+
+/// Asserts that serializing and deserializing the value again yields the same value.
+pub(crate) fn assert_round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_string(value).unwrap();
+    let deserialized: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(&deserialized, value, "round trip through {json}");
+}
+
+/// Response of [`MockBuilder`].
+#[derive(Debug, Clone, PartialEq, Deserialize, crate::Paginated)]
+pub(crate) struct MockResponse {
+    #[paginated(items)]
+    pub(crate) items: Vec<String>,
+    #[paginated(pagination_key)]
+    pub(crate) pagination_key: Option<String>,
+}
+
+/// Pages of a query. An `Err` page fails with `JQuantsError::BugError`.
+pub(crate) type MockPages = Vec<Result<Vec<String>, String>>;
+
+/// Builder that serves the pages returned by a function of its query params.
+///
+/// The pagination key is the index of the page.
+/// Invalid issue codes and dates fail on serializing the params like the real builders.
+#[derive(Clone, Serialize)]
+pub(crate) struct MockBuilder {
+    #[serde(skip)]
+    pages: Arc<dyn Fn(&MockBuilder) -> MockPages + Send + Sync>,
+    #[serde(skip)]
+    delay: Option<Duration>,
+    /// Number of the requests sent
+    #[serde(skip)]
+    pub(crate) requests: Arc<AtomicUsize>,
+    #[serde(skip)]
+    in_flight: Arc<AtomicUsize>,
+    /// Maximum number of the requests in flight at the same time
+    #[serde(skip)]
+    pub(crate) max_in_flight: Arc<AtomicUsize>,
+
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    pub(crate) code: Option<Result<IssueCode, ParseIssueCodeError>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) date: Option<QueryDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) from: Option<QueryDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) to: Option<QueryDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pagination_key: Option<String>,
+}
+
+impl MockBuilder {
+    /// Serve the pages returned by `pages` for the query params.
+    pub(crate) fn new(pages: impl Fn(&MockBuilder) -> MockPages + Send + Sync + 'static) -> Self {
+        Self {
+            pages: Arc::new(pages),
+            delay: None,
+            requests: Arc::new(AtomicUsize::new(0)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            max_in_flight: Arc::new(AtomicUsize::new(0)),
+            code: None,
+            date: None,
+            from: None,
+            to: None,
+            pagination_key: None,
+        }
+    }
+
+    /// Serve the same pages for any query params.
+    pub(crate) fn with_pages<T: ToString>(pages: Vec<Result<Vec<T>, &str>>) -> Self {
+        let pages: MockPages = pages
+            .into_iter()
+            .map(|page| {
+                page.map(|items| items.iter().map(T::to_string).collect())
+                    .map_err(str::to_string)
+            })
+            .collect();
+        Self::new(move |_| pages.clone())
+    }
+
+    /// Wait for `delay` in each request.
+    pub(crate) fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Set issue code.
+    pub(crate) fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+
+    /// Set date.
+    pub(crate) fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
+        self
+    }
+}
+
+impl JQuantsBuilder<MockResponse> for MockBuilder {
+    async fn send(self) -> Result<MockResponse, JQuantsError> {
+        self.send_ref().await
+    }
+
+    async fn send_ref(&self) -> Result<MockResponse, JQuantsError> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        build_request_url("mock", self)?;
+
+        let pages = (self.pages)(self);
+        let index = self
+            .pagination_key
+            .as_ref()
+            .map_or(0, |key| key.parse::<usize>().unwrap());
+        let items = match pages.get(index) {
+            Some(page) => page.clone().map_err(JQuantsError::BugError)?,
+            None => vec![],
+        };
+        let pagination_key = (index + 1 < pages.len()).then(|| (index + 1).to_string());
+
+        Ok(MockResponse {
+            items,
+            pagination_key,
+        })
+    }
+}
+
+impl JQuantsSendBuilder<MockResponse> for MockBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl Future<Output = Result<MockResponse, JQuantsError>> + Send {
+        self.send_ref()
+    }
+}
+
+impl Paginatable<MockResponse> for MockBuilder {
+    fn pagination_key(mut self, pagination_key: impl Into<String>) -> Self {
+        self.pagination_key = Some(pagination_key.into());
+        self
+    }
+}
+
+impl CodeBatchable<MockResponse> for MockBuilder {
+    fn with_code(self, code: impl IntoIssueCode) -> Self {
+        self.code(code)
+    }
+}
+
+impl DateIterable<MockResponse> for MockBuilder {
+    fn with_date(self, date: impl IntoQueryDate) -> Self {
+        self.date(date)
+    }
+}
+
+impl DateRangeShardable<MockResponse> for MockBuilder {
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
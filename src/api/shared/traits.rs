@@ -1,4 +1,8 @@
 //! Shared traits for the APIs.
 
 pub mod builder;
+pub mod code_batch;
+pub mod date_iter;
+pub mod date_range;
 pub mod pagination;
+pub mod request_preview;
@@ -9,7 +9,27 @@ pub trait JQuantsBuilder<R: DeserializeOwned + fmt::Debug> {
     /// Send the request.
     fn send(self) -> impl std::future::Future<Output = Result<R, crate::JQuantsError>>;
 
+    /// Validate the combination of parameters without sending the request.
+    ///
+    /// This is also called by [`JQuantsBuilder::send_ref`],
+    /// so an invalid builder fails with [`crate::JQuantsError::InvalidParameters`] before the request.
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        Ok(())
+    }
+
     /// Send the request without consuming ownership.
     /// Use only when reusing the builder.
     fn send_ref(&self) -> impl std::future::Future<Output = Result<R, crate::JQuantsError>>;
 }
+
+/// Trait for API builders whose requests can be sent from a spawned task.
+///
+/// The futures of [`JQuantsBuilder`] are not required to be `Send`, so the helpers spawning requests
+/// (e.g. [`crate::Paginatable::fetch_pages_stream_prefetch`]) require this trait instead.
+/// All the builders of this crate implement it.
+pub trait JQuantsSendBuilder<R: DeserializeOwned + fmt::Debug>: JQuantsBuilder<R> {
+    /// Same as [`JQuantsBuilder::send_ref`], but the future is `Send`.
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<R, crate::JQuantsError>> + Send;
+}
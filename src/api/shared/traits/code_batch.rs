@@ -0,0 +1,131 @@
+//! Multi-code batch fetch module.
+//!
+//! Fetch the same query for many issue codes with bounded concurrency.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::future::Future;
+
+use futures::{stream, StreamExt};
+use serde::de::DeserializeOwned;
+
+use super::pagination::{HasPaginationKey, MergePage, Paginatable};
+use crate::{IntoIssueCode, JQuantsError};
+
+/// Results of a batch fetch keyed by issue code.
+///
+/// Valid codes are keyed in the 5-character form, and invalid codes are keyed as given.
+/// Each code has its own result, so a failure of one code does not abort the others.
+pub type CodeBatchResults<R> = BTreeMap<String, Result<R, JQuantsError>>;
+
+/// Trait for paginatable builders that have an issue code.
+pub trait CodeBatchable<R: DeserializeOwned + fmt::Debug + HasPaginationKey + MergePage>:
+    Paginatable<R>
+{
+    /// Set issue code (e.g. 27800 or 2780)
+    fn with_code(self, code: impl IntoIssueCode) -> Self;
+
+    /// Fetch all pages for each issue code and merge them per code.
+    ///
+    /// The other params of the builder are shared by all requests.
+    /// At most `concurrency` codes are fetched at the same time. `concurrency` of 0 is treated as 1.
+    /// Duplicate codes, including the 4-character and 5-character forms of the same code, are fetched only once.
+    /// An invalid code fails without sending a request.
+    fn fetch_codes_batch(
+        self,
+        codes: impl IntoIterator<Item = impl IntoIssueCode>,
+        concurrency: usize,
+    ) -> impl Future<Output = CodeBatchResults<R>> {
+        let codes: BTreeSet<String> = codes
+            .into_iter()
+            .map(|code| match code.into_issue_code() {
+                Ok(code) => code.to_string(),
+                Err(e) => e.value().to_string(),
+            })
+            .collect();
+
+        async move {
+            tracing::debug!("Fetching {} codes in batch.", codes.len());
+
+            stream::iter(codes)
+                .map(|code| {
+                    let builder = self.clone().with_code(code.clone());
+                    async move {
+                        let result = builder.fetch_all_and_merge().await;
+                        if let Err(e) = &result {
+                            tracing::warn!("Failed to fetch code {code}: {e}");
+                        }
+                        (code, result)
+                    }
+                })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::shared::test_utils::MockBuilder;
+
+    /// Builder that returns two pages per code, or an error for code "99990".
+    fn builder() -> MockBuilder {
+        MockBuilder::new(|builder| {
+            let code = builder.code.clone().unwrap().unwrap();
+            let date = builder.date.clone().unwrap();
+            if code.as_str() == "99990" {
+                return vec![Err("failure".to_string())];
+            }
+            vec![
+                Ok(vec![format!("{code}:{date}:1")]),
+                Ok(vec![format!("{code}:{date}:2")]),
+            ]
+        })
+        .date("2024-08-01")
+    }
+
+    #[tokio::test]
+    async fn test_fetch_codes_batch() {
+        let results = builder()
+            .fetch_codes_batch(["86970", "99990", "7203", "8697", "869"], 2)
+            .await;
+
+        assert_eq!(
+            results.keys().collect::<Vec<_>>(),
+            vec!["72030", "869", "86970", "99990"]
+        );
+        assert_eq!(
+            results["86970"].as_ref().unwrap().items,
+            vec!["86970:2024-08-01:1", "86970:2024-08-01:2"]
+        );
+        assert_eq!(
+            results["72030"].as_ref().unwrap().items,
+            vec!["72030:2024-08-01:1", "72030:2024-08-01:2"]
+        );
+        assert!(matches!(results["99990"], Err(JQuantsError::BugError(_))));
+        assert!(matches!(
+            results["869"],
+            Err(JQuantsError::InvalidQueryParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_plan_client_batch_reports_invalid_codes_per_code() {
+        use crate::{DailyStockPricesApi, JQuantsPlanClient, JQuantsStandardPlanClient};
+
+        let client = JQuantsStandardPlanClient::new_from_refresh_token("refresh-token".to_string());
+        let results = client
+            .get_daily_stock_prices_batch(["869", "ABCDEF", "86-97"], |builder| builder, 2)
+            .await;
+
+        assert_eq!(
+            results.keys().collect::<Vec<_>>(),
+            vec!["86-97", "869", "ABCDEF"]
+        );
+        assert!(results
+            .values()
+            .all(|result| matches!(result, Err(JQuantsError::InvalidQueryParameter(_)))));
+    }
+}
@@ -0,0 +1,134 @@
+//! Date iterating module.
+//!
+//! Fetch a date-only query for each trading day in a range.
+
+use std::fmt;
+use std::future::Future;
+
+use async_stream::try_stream;
+use futures::{stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use super::{
+    builder::JQuantsBuilder,
+    pagination::{HasPaginationKey, MergePage, Paginatable},
+};
+use crate::{IntoQueryDate, JQuantsError, QueryDate, TradingCalendarBuilder};
+
+/// Trait for paginatable builders that take a single date.
+pub trait DateIterable<R: DeserializeOwned + fmt::Debug + HasPaginationKey + MergePage>:
+    Paginatable<R>
+{
+    /// Set the date of data (e.g., "20210901" or "2021-09-01")
+    fn with_date(self, date: impl IntoQueryDate) -> Self;
+
+    /// Fetch each date with pagination in order.
+    ///
+    /// The pages of a date are merged, so one response is yielded per date.
+    /// The other params of the builder are shared by all dates.
+    fn fetch_dates_stream(
+        self,
+        dates: impl IntoIterator<Item = impl IntoQueryDate>,
+    ) -> impl stream::Stream<Item = Result<R, JQuantsError>> {
+        let dates: Vec<QueryDate> = dates
+            .into_iter()
+            .map(IntoQueryDate::into_query_date)
+            .collect();
+
+        let stream = try_stream! {
+            for date in dates {
+                tracing::debug!("Fetching date {date}.");
+                yield self.clone().with_date(date).fetch_all_and_merge().await?;
+            }
+        };
+
+        Box::pin(stream)
+    }
+
+    /// Fetch each trading day of `calendar` with pagination in order.
+    ///
+    /// The trading days are looked up by sending `calendar`, and non-business days are skipped.
+    /// See [`DateIterable::fetch_dates_stream`] for the responses.
+    fn fetch_trading_days_stream(
+        self,
+        calendar: TradingCalendarBuilder,
+    ) -> impl stream::Stream<Item = Result<R, JQuantsError>> {
+        let stream = try_stream! {
+            let calendar = calendar.send().await?;
+            let dates = calendar.trading_dates();
+            tracing::debug!("Fetching {} trading days.", dates.len());
+
+            let responses = self.fetch_dates_stream(dates);
+            for await response in responses {
+                yield response?;
+            }
+        };
+
+        Box::pin(stream)
+    }
+
+    /// Fetch each trading day of `calendar` with pagination and merge them.
+    fn fetch_trading_days_and_merge(
+        self,
+        calendar: TradingCalendarBuilder,
+    ) -> impl Future<Output = Result<R, JQuantsError>> {
+        async {
+            let responses = self.fetch_trading_days_stream(calendar).try_collect().await;
+            R::merge_page(responses)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::api::shared::test_utils::{MockBuilder, MockResponse};
+
+    /// Builder that returns two pages per date, or an error for "2024-01-03".
+    fn builder() -> MockBuilder {
+        MockBuilder::new(|builder| {
+            let date = builder.date.clone().unwrap().to_string();
+            if date == "2024-01-03" {
+                return vec![Err("failure".to_string())];
+            }
+            vec![
+                Ok(vec![format!("NK225F:{date}:1")]),
+                Ok(vec![format!("NK225F:{date}:2")]),
+            ]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_fetch_dates_stream() {
+        let responses: Vec<MockResponse> = builder()
+            .fetch_dates_stream(["2024-01-04", "2024-01-05"])
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            responses
+                .into_iter()
+                .map(|response| response.items)
+                .collect::<Vec<_>>(),
+            vec![
+                vec!["NK225F:2024-01-04:1", "NK225F:2024-01-04:2"],
+                vec!["NK225F:2024-01-05:1", "NK225F:2024-01-05:2"],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_dates_stream_error() {
+        let mut stream = builder().fetch_dates_stream(["2024-01-02", "2024-01-03", "2024-01-04"]);
+
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(matches!(
+            stream.next().await.unwrap(),
+            Err(JQuantsError::BugError(_))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+}
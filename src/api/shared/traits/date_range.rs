@@ -0,0 +1,152 @@
+//! Date range sharding module.
+//!
+//! Split a long `from`/`to` query into sub-ranges and fetch them concurrently.
+
+use std::fmt;
+use std::future::Future;
+
+use chrono::{Days, NaiveDate};
+use futures::{stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use super::pagination::{HasPaginationKey, MergePage, Paginatable};
+use crate::{IntoQueryDate, JQuantsError};
+
+/// Trait for paginatable builders that have a `from`/`to` date range.
+pub trait DateRangeShardable<R: DeserializeOwned + fmt::Debug + HasPaginationKey + MergePage>:
+    Paginatable<R>
+{
+    /// Set the starting point and the end point of data period (e.g. 20210901 or 2021-09-01)
+    fn date_range(self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self;
+
+    /// Fetch a date range by splitting it into shards of `shard_size`.
+    ///
+    /// Each shard is fetched with pagination and at most `concurrency` shards are fetched at the same time.
+    /// The shards do not overlap, so the merged response has no duplicates
+    /// and keeps the date order of the shards.
+    ///
+    /// `shard_size` of 0 days and `concurrency` of 0 are treated as 1.
+    /// If `from` is after `to`, an empty response is returned.
+    fn fetch_range_sharded(
+        self,
+        from: NaiveDate,
+        to: NaiveDate,
+        shard_size: Days,
+        concurrency: usize,
+    ) -> impl Future<Output = Result<R, JQuantsError>> {
+        async move {
+            let shards = split_date_range(from, to, shard_size);
+            tracing::debug!("Fetching {} shards from {from} to {to}.", shards.len());
+
+            let responses: Vec<R> = stream::iter(shards)
+                .map(|(shard_from, shard_to)| {
+                    self.clone()
+                        .date_range(shard_from, shard_to)
+                        .fetch_all_and_merge()
+                })
+                .buffered(concurrency.max(1))
+                .try_collect()
+                .await?;
+
+            R::merge_page(Ok(responses))
+        }
+    }
+}
+
+/// Split `from..=to` into non-overlapping ranges of `shard_size` days.
+fn split_date_range(
+    from: NaiveDate,
+    to: NaiveDate,
+    shard_size: Days,
+) -> Vec<(NaiveDate, NaiveDate)> {
+    let shard_size = if shard_size == Days::new(0) {
+        Days::new(1)
+    } else {
+        shard_size
+    };
+
+    let mut shards = Vec::new();
+    let mut shard_from = from;
+    while shard_from <= to {
+        let shard_to = shard_from
+            .checked_add_days(shard_size)
+            .and_then(|next| next.pred_opt())
+            .map_or(to, |end| end.min(to));
+        shards.push((shard_from, shard_to));
+
+        match shard_to.succ_opt() {
+            Some(next) => shard_from = next,
+            None => break,
+        }
+    }
+
+    shards
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::Ordering, time::Duration};
+
+    use super::*;
+    use crate::{api::shared::test_utils::MockBuilder, QueryDate};
+
+    /// Builder that returns every date in the range, two dates per page.
+    fn builder() -> MockBuilder {
+        MockBuilder::new(|builder| {
+            let from = builder.from.as_ref().and_then(QueryDate::date).unwrap();
+            let to = builder.to.as_ref().and_then(QueryDate::date).unwrap();
+            let dates: Vec<String> = from
+                .iter_days()
+                .take_while(|d| *d <= to)
+                .map(|d| d.to_string())
+                .collect();
+            dates.chunks(2).map(|page| Ok(page.to_vec())).collect()
+        })
+        .delay(Duration::from_millis(5))
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_split_date_range() {
+        let shards = split_date_range(date("2024-01-30"), date("2024-02-05"), Days::new(3));
+
+        assert_eq!(
+            shards,
+            vec![
+                (date("2024-01-30"), date("2024-02-01")),
+                (date("2024-02-02"), date("2024-02-04")),
+                (date("2024-02-05"), date("2024-02-05")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_date_range_empty() {
+        let shards = split_date_range(date("2024-02-05"), date("2024-01-30"), Days::new(3));
+
+        assert!(shards.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_range_sharded() {
+        let builder = builder();
+        let max_in_flight = builder.max_in_flight.clone();
+
+        let response = builder
+            .fetch_range_sharded(date("2024-01-01"), date("2024-01-20"), Days::new(7), 2)
+            .await
+            .unwrap();
+
+        let expected: Vec<String> = date("2024-01-01")
+            .iter_days()
+            .take(20)
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(response.items, expected);
+        assert_eq!(response.pagination_key, None);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+    }
+}
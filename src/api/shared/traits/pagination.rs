@@ -4,14 +4,15 @@
 
 use std::fmt;
 use std::future::Future;
+use std::sync::Arc;
 
 use async_stream::try_stream;
 use futures::stream;
 use futures::StreamExt;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::JQuantsBuilder;
 use crate::JQuantsError;
+use crate::{JQuantsBuilder, JQuantsSendBuilder};
 
 /// Trait for types that have a pagination key.
 pub trait HasPaginationKey {
@@ -19,6 +20,18 @@ pub trait HasPaginationKey {
     fn get_pagination_key(&self) -> Option<&str>;
 }
 
+/// Trait for paginated responses that contain a list of items.
+pub trait HasItems {
+    /// Type of a single item.
+    type Item;
+
+    /// Get the items of the page.
+    fn items(&self) -> &[Self::Item];
+
+    /// Take the items of the page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
 /// Trait for types that can merge pages.
 pub trait MergePage: Sized {
     /// Merge the pages.
@@ -27,13 +40,175 @@ pub trait MergePage: Sized {
     ) -> Result<Self, crate::JQuantsError>;
 }
 
+/// Serializable checkpoint of a pagination.
+///
+/// Save it to resume a long pagination after a failure with [`Paginatable::resume_from`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaginationCheckpoint {
+    /// Query parameters of the builder except the pagination key.
+    pub params: serde_json::Map<String, serde_json::Value>,
+    /// Pagination key of the next page to fetch.
+    ///
+    /// `None` means starting from the first page.
+    pub pagination_key: Option<String>,
+}
+
+/// Options for fetching pages.
+///
+/// # Example
+///
+/// ```ignore
+/// let options = PaginationOptions::new()
+///     .max_pages(10)
+///     .max_items(5000)
+///     .on_page(|index, item_count| println!("page {index}: {item_count} items"));
+/// ```
+#[derive(Clone, Default)]
+pub struct PaginationOptions {
+    /// Maximum number of pages to fetch.
+    max_pages: Option<usize>,
+    /// Maximum number of items to fetch.
+    max_items: Option<usize>,
+    /// Callback called with the page index and the number of items of the page.
+    on_page: Option<Arc<dyn Fn(usize, usize) + Send + Sync>>,
+}
+
+impl PaginationOptions {
+    /// Create new options without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of pages to fetch.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Set the maximum number of items to fetch.
+    ///
+    /// Fetching stops once the number of fetched items reaches the limit.
+    /// Pages are not split, so the last page may exceed the limit.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Set the progress callback.
+    ///
+    /// The callback is called for each fetched page with the page index (starting from 0)
+    /// and the number of items of the page.
+    pub fn on_page(mut self, on_page: impl Fn(usize, usize) + Send + Sync + 'static) -> Self {
+        self.on_page = Some(Arc::new(on_page));
+        self
+    }
+
+    /// Check if the limits are reached.
+    fn is_limit_reached(&self, page_count: usize, item_count: usize) -> bool {
+        self.max_pages.is_some_and(|max| page_count >= max)
+            || self.max_items.is_some_and(|max| item_count >= max)
+    }
+}
+
+impl fmt::Debug for PaginationOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PaginationOptions")
+            .field("max_pages", &self.max_pages)
+            .field("max_items", &self.max_items)
+            .field(
+                "on_page",
+                &self.on_page.as_ref().map(|_| "Fn(usize, usize)"),
+            )
+            .finish()
+    }
+}
+
+/// Pages fetched until an error occurred.
+#[derive(Debug)]
+pub struct PartialPages<R> {
+    /// Pages fetched successfully.
+    pub pages: Vec<R>,
+    /// Error that stopped the pagination.
+    ///
+    /// `None` if the pagination finished or reached the limits.
+    pub error: Option<JQuantsError>,
+}
+
+impl<R> PartialPages<R> {
+    /// Check if the pagination finished without errors.
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Convert into a result. Returns the error if any, discarding the fetched pages.
+    pub fn into_result(self) -> Result<Vec<R>, JQuantsError> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.pages),
+        }
+    }
+}
+
+/// Abort the task when dropped.
+struct AbortOnDrop(tokio::task::JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
 /// Trait for paginatable responses.
 pub trait Paginatable<R: DeserializeOwned + fmt::Debug + HasPaginationKey + MergePage>:
-    JQuantsBuilder<R> + Clone
+    JQuantsBuilder<R> + Clone + Serialize
 {
     /// Set the pagination key.
     fn pagination_key(self, pagination_key: impl Into<String>) -> Self;
 
+    /// Get the checkpoint of the current query params and pagination key.
+    fn checkpoint(&self) -> Result<PaginationCheckpoint, JQuantsError> {
+        let mut params = match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(params)) => params,
+            Ok(value) => {
+                return Err(JQuantsError::BugError(format!(
+                    "Builder params must be serialized as a map, but got: {value}"
+                )))
+            }
+            // Serializing the params fails only for an invalid date or issue code,
+            // which is reported in the same way as `send`.
+            Err(e) => return Err(JQuantsError::InvalidQueryParameter(e.to_string())),
+        };
+        let pagination_key = match params.remove("pagination_key") {
+            Some(serde_json::Value::String(key)) => Some(key),
+            _ => None,
+        };
+
+        Ok(PaginationCheckpoint {
+            params,
+            pagination_key,
+        })
+    }
+
+    /// Resume the pagination from the checkpoint.
+    ///
+    /// The builder must have the same query params as the checkpoint.
+    /// Otherwise, `JQuantsError::InvalidCheckpoint` is returned.
+    fn resume_from(self, checkpoint: &PaginationCheckpoint) -> Result<Self, JQuantsError> {
+        let current = self.checkpoint()?;
+        if current.params != checkpoint.params {
+            return Err(JQuantsError::InvalidCheckpoint(format!(
+                "The query params do not match. Builder: {}, Checkpoint: {}",
+                serde_json::Value::Object(current.params),
+                serde_json::Value::Object(checkpoint.params.clone()),
+            )));
+        }
+
+        match &checkpoint.pagination_key {
+            Some(key) => Ok(self.pagination_key(key)),
+            None => Ok(self),
+        }
+    }
+
     /// Fetch the pages stream.
     fn fetch_pages_stream(self) -> impl stream::Stream<Item = Result<R, JQuantsError>> {
         let stream = try_stream! {
@@ -57,6 +232,140 @@ pub trait Paginatable<R: DeserializeOwned + fmt::Debug + HasPaginationKey + Merg
         Box::pin(stream)
     }
 
+    /// Fetch the pages stream with prefetching.
+    ///
+    /// The next page is requested in a background task as soon as the pagination key of the current page is known,
+    /// while the consumer is still processing the current page.
+    /// At most `depth` pages (including the one being requested) are fetched ahead of the consumer.
+    /// `depth` of 0 is treated as 1.
+    ///
+    /// The background task is aborted when the stream is dropped.
+    fn fetch_pages_stream_prefetch(
+        self,
+        depth: usize,
+    ) -> impl stream::Stream<Item = Result<R, JQuantsError>>
+    where
+        Self: JQuantsSendBuilder<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let (tx, rx) = tokio::sync::mpsc::channel(depth.max(1));
+
+        let task = tokio::spawn(async move {
+            let mut builder = self;
+
+            loop {
+                // Wait for a free slot before requesting so that buffered pages never exceed `depth`.
+                let Ok(permit) = tx.reserve().await else {
+                    tracing::debug!("Prefetch stream was dropped. Stopping prefetch.");
+                    break;
+                };
+
+                let result = builder.send_ref_spawnable().await;
+                let next_pagination_key = match &result {
+                    Ok(response) => response.get_pagination_key().map(|key| key.to_string()),
+                    Err(_) => None,
+                };
+                permit.send(result);
+
+                match next_pagination_key {
+                    Some(key) => builder = builder.pagination_key(key),
+                    None => break,
+                }
+            }
+        });
+
+        let stream = stream::unfold((rx, AbortOnDrop(task)), |(mut rx, task)| async move {
+            rx.recv().await.map(|result| (result, (rx, task)))
+        });
+
+        Box::pin(stream)
+    }
+
+    /// Fetch the pages stream with the checkpoint of the next page.
+    ///
+    /// The checkpoint is `None` after the last page.
+    fn fetch_pages_stream_with_checkpoint(
+        self,
+    ) -> impl stream::Stream<Item = Result<(R, Option<PaginationCheckpoint>), JQuantsError>> {
+        let stream = try_stream! {
+            let params = self.checkpoint()?.params;
+            let mut builder = self.clone();
+
+            loop {
+                let response = builder.send_ref().await?;
+                let next_pagination_key = response.get_pagination_key().map(|key| key.to_string());
+                if let Some(key) = next_pagination_key {
+                    builder = builder.pagination_key(key.clone());
+                    let checkpoint = PaginationCheckpoint {
+                        params: params.clone(),
+                        pagination_key: Some(key),
+                    };
+
+                    yield (response, Some(checkpoint));
+                    continue;
+                } else {
+                    yield (response, None);
+                    break;
+                }
+            }
+        };
+
+        Box::pin(stream)
+    }
+
+    /// Fetch the items stream.
+    ///
+    /// Yields the items one by one across page boundaries.
+    fn fetch_items_stream(self) -> impl stream::Stream<Item = Result<R::Item, JQuantsError>>
+    where
+        R: HasItems,
+    {
+        let stream = try_stream! {
+            let mut pages = Box::pin(self.fetch_pages_stream());
+
+            while let Some(page) = pages.next().await {
+                for item in page?.into_items() {
+                    yield item;
+                }
+            }
+        };
+
+        Box::pin(stream)
+    }
+
+    /// Fetch the pages stream with the limits and the progress callback.
+    fn fetch_pages_stream_with_options(
+        self,
+        options: PaginationOptions,
+    ) -> impl stream::Stream<Item = Result<R, JQuantsError>>
+    where
+        R: HasItems,
+    {
+        let stream = try_stream! {
+            let mut pages = Box::pin(self.fetch_pages_stream());
+            let mut page_count = 0;
+            let mut item_count = 0;
+
+            while !options.is_limit_reached(page_count, item_count) {
+                let Some(page) = pages.next().await else {
+                    break;
+                };
+                let page = page?;
+
+                let page_item_count = page.items().len();
+                if let Some(on_page) = &options.on_page {
+                    on_page(page_count, page_item_count);
+                }
+                page_count += 1;
+                item_count += page_item_count;
+
+                yield page;
+            }
+        };
+
+        Box::pin(stream)
+    }
+
     /// Fetch all pages.
     fn fetch_all(self) -> impl Future<Output = Result<Vec<R>, JQuantsError>> {
         async {
@@ -72,6 +381,43 @@ pub trait Paginatable<R: DeserializeOwned + fmt::Debug + HasPaginationKey + Merg
         }
     }
 
+    /// Fetch all pages with the limits and the progress callback.
+    fn fetch_all_with_options(
+        self,
+        options: PaginationOptions,
+    ) -> impl Future<Output = Result<Vec<R>, JQuantsError>>
+    where
+        R: HasItems,
+    {
+        async { self.fetch_all_partial(options).await.into_result() }
+    }
+
+    /// Fetch all pages and keep the pages fetched before an error.
+    ///
+    /// Unlike `fetch_all`, the fetched pages are returned together with the error that stopped the pagination.
+    fn fetch_all_partial(self, options: PaginationOptions) -> impl Future<Output = PartialPages<R>>
+    where
+        R: HasItems,
+    {
+        async {
+            let mut stream = Box::pin(self.fetch_pages_stream_with_options(options));
+            let mut partial = PartialPages {
+                pages: Vec::new(),
+                error: None,
+            };
+            while let Some(result) = stream.next().await {
+                match result {
+                    Ok(page) => partial.pages.push(page),
+                    Err(e) => {
+                        partial.error = Some(e);
+                        break;
+                    }
+                }
+            }
+            partial
+        }
+    }
+
     /// Fetch all pages and merge them.
     fn fetch_all_and_merge(self) -> impl Future<Output = Result<R, JQuantsError>> {
         async {
@@ -80,3 +426,200 @@ pub trait Paginatable<R: DeserializeOwned + fmt::Debug + HasPaginationKey + Merg
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::Ordering, Arc};
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::api::shared::test_utils::{MockBuilder, MockResponse};
+
+    #[tokio::test]
+    async fn test_fetch_items_stream() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1, 2]), Ok(vec![]), Ok(vec![3])]);
+
+        let items: Vec<String> = builder
+            .fetch_items_stream()
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(items, vec!["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_items_stream_error() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1, 2]), Err("failure")]);
+
+        let items: Vec<Result<String, JQuantsError>> = builder.fetch_items_stream().collect().await;
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap(), "1");
+        assert_eq!(items[1].as_ref().unwrap(), "2");
+        assert!(matches!(items[2], Err(JQuantsError::BugError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_checkpoint() {
+        let pages = vec![Ok(vec![1]), Err("failure"), Ok(vec![3])];
+        let builder = MockBuilder::with_pages(pages.clone()).code("86970");
+
+        let results: Vec<_> = builder.fetch_pages_stream_with_checkpoint().collect().await;
+        assert_eq!(results.len(), 2);
+        let (_, checkpoint) = results[0].as_ref().unwrap();
+        assert!(results[1].is_err());
+
+        let saved = serde_json::to_string(checkpoint.as_ref().unwrap()).unwrap();
+        let checkpoint: PaginationCheckpoint = serde_json::from_str(&saved).unwrap();
+        assert_eq!(checkpoint.pagination_key.as_deref(), Some("1"));
+
+        let pages = vec![Ok(vec![1]), Ok(vec![2]), Ok(vec![3])];
+        let builder = MockBuilder::with_pages(pages)
+            .code("86970")
+            .resume_from(&checkpoint)
+            .unwrap();
+        let response = builder.fetch_all_and_merge().await.unwrap();
+
+        assert_eq!(response.items, vec!["2", "3"]);
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint_params_mismatch() {
+        let checkpoint = MockBuilder::with_pages::<u32>(vec![])
+            .code("86970")
+            .pagination_key("1")
+            .checkpoint()
+            .unwrap();
+
+        let result = MockBuilder::with_pages::<u32>(vec![])
+            .code("13010")
+            .resume_from(&checkpoint);
+
+        assert!(matches!(result, Err(JQuantsError::InvalidCheckpoint(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_with_max_pages() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1]), Ok(vec![2]), Ok(vec![3])]);
+        let requests = builder.requests.clone();
+
+        let pages = builder
+            .fetch_all_with_options(PaginationOptions::new().max_pages(2))
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_with_max_items() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1, 2]), Ok(vec![3, 4]), Ok(vec![5])]);
+
+        let pages = builder
+            .fetch_all_with_options(PaginationOptions::new().max_items(3))
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_on_page() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1, 2]), Ok(vec![]), Ok(vec![3])]);
+        let progress = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let options = PaginationOptions::new().on_page({
+            let progress = progress.clone();
+            move |index, item_count| progress.lock().unwrap().push((index, item_count))
+        });
+        builder.fetch_all_with_options(options).await.unwrap();
+
+        assert_eq!(*progress.lock().unwrap(), vec![(0, 2), (1, 0), (2, 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_partial() {
+        let builder =
+            MockBuilder::with_pages(vec![Ok(vec![1]), Ok(vec![2]), Err("failure"), Ok(vec![4])]);
+
+        let partial = builder.fetch_all_partial(PaginationOptions::new()).await;
+
+        assert!(!partial.is_complete());
+        assert_eq!(
+            partial
+                .pages
+                .iter()
+                .map(|p| p.items[0].as_str())
+                .collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+        assert!(matches!(partial.error, Some(JQuantsError::BugError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_stream_prefetch() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1]), Ok(vec![2]), Ok(vec![3])]);
+
+        let pages: Vec<MockResponse> = builder
+            .fetch_pages_stream_prefetch(2)
+            .map(|page| page.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(
+            pages.into_iter().flat_map(|p| p.items).collect::<Vec<_>>(),
+            vec!["1", "2", "3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_stream_prefetch_backpressure() {
+        let builder = MockBuilder::with_pages((0..10).map(|i| Ok(vec![i])).collect());
+        let requests = builder.requests.clone();
+
+        let mut stream = Box::pin(builder.fetch_pages_stream_prefetch(2));
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.items, vec!["0"]);
+
+        // The consumer holds the first page. Only `depth` more pages are prefetched.
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_stream_prefetch_error() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1]), Err("failure"), Ok(vec![3])]);
+
+        let results: Vec<_> = builder.fetch_pages_stream_prefetch(4).collect().await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(JQuantsError::BugError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_and_merge_keeps_page_order() {
+        let builder = MockBuilder::with_pages(vec![Ok(vec![1, 2]), Ok(vec![3]), Ok(vec![4, 5])]);
+
+        let response = builder.fetch_all_and_merge().await.unwrap();
+
+        assert_eq!(response.items, vec!["1", "2", "3", "4", "5"]);
+        assert_eq!(response.pagination_key, None);
+    }
+
+    #[test]
+    fn test_merge_page_empty() {
+        let response = MockResponse::merge_page(Ok(vec![])).unwrap();
+
+        assert_eq!(
+            response,
+            MockResponse {
+                items: vec![],
+                pagination_key: None,
+            }
+        );
+    }
+}
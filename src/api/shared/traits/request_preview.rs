@@ -0,0 +1,81 @@
+//! Request preview module.
+//!
+//! Render a builder to the request without sending it.
+
+use serde::Serialize;
+
+use crate::{api::build_request_url, JQuantsError};
+
+/// Trait for builders that can render the request without sending it.
+pub trait RequestPreview: Serialize {
+    /// Path of the endpoint (e.g. "prices/daily_quotes").
+    const PATH: &'static str;
+
+    /// Get the full URL with the query string that the builder requests.
+    ///
+    /// This uses the same serialization as sending the request,
+    /// so an invalid date or issue code fails with [`JQuantsError::InvalidQueryParameter`].
+    /// The combination of parameters is not validated.
+    fn to_request_url(&self) -> Result<String, JQuantsError> {
+        build_request_url(Self::PATH, self)
+    }
+
+    /// Get the key/value pairs of the query string in the order of the request.
+    fn to_query_pairs(&self) -> Result<Vec<(String, String)>, JQuantsError> {
+        let query = serde_urlencoded::to_string(self)
+            .map_err(|e| JQuantsError::InvalidQueryParameter(e.to_string()))?;
+        serde_urlencoded::from_str(&query).map_err(|e| JQuantsError::BugError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        FinancialStatementDetailsApi, IndicesApi, JQuantsPlanClient, JQuantsPremiumPlanClient,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_to_request_url() {
+        let client = JQuantsPremiumPlanClient::new_from_refresh_token("refresh-token".to_string());
+
+        let builder = client
+            .get_financial_statement_details()
+            .code("8697")
+            .date("20240801");
+        assert_eq!(
+            builder.to_request_url().unwrap(),
+            "https://api.jquants.com/v1/fins/fs_details?code=86970&date=2024-08-01"
+        );
+        assert_eq!(
+            builder.to_query_pairs().unwrap(),
+            vec![
+                ("code".to_string(), "86970".to_string()),
+                ("date".to_string(), "2024-08-01".to_string()),
+            ]
+        );
+
+        let builder = client.get_indices();
+        assert_eq!(
+            builder.to_request_url().unwrap(),
+            "https://api.jquants.com/v1/indices"
+        );
+        assert_eq!(builder.to_query_pairs().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_to_request_url_invalid_date() {
+        let client = JQuantsPremiumPlanClient::new_from_refresh_token("refresh-token".to_string());
+
+        let builder = client.get_indices().date("2024-13-01");
+        assert!(matches!(
+            builder.to_request_url(),
+            Err(JQuantsError::InvalidQueryParameter(_))
+        ));
+        assert!(matches!(
+            builder.to_query_pairs(),
+            Err(JQuantsError::InvalidQueryParameter(_))
+        ));
+    }
+}
@@ -3,11 +3,18 @@
 pub mod accounting_period;
 pub mod amount_per_share;
 pub mod central_contract_month_flag;
+pub mod date_time;
+pub mod decimal;
 pub mod dividend;
 pub mod emergency_margin_trigger_division;
+pub mod extra_fields;
+pub mod financial_figure;
+pub mod fiscal_quarter;
+pub mod fiscal_year_end;
 pub mod futures_code;
 pub mod holiday_division;
 pub mod index_code;
+pub mod issue_code;
 pub mod issue_type;
 pub mod margin_code;
 pub mod market_code;
@@ -15,8 +22,10 @@ pub mod options_code;
 pub mod payable_date;
 pub mod price_limit;
 pub mod put_call_division;
+pub mod query_date;
 pub mod section_name;
 pub mod sector17_code;
 pub mod sector33_code;
 pub mod type_of_document;
 pub mod underlying_sso;
+pub mod xbrl_statements;
@@ -4,6 +4,8 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants;
+
 /// Accounting period.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(from = "String", into = "String")]
@@ -46,7 +48,11 @@ impl From<&str> for AccountingPeriod {
 }
 impl From<String> for AccountingPeriod {
     fn from(s: String) -> Self {
-        Self::from(s.as_str())
+        let value = Self::from(s.as_str());
+        if let Self::Unknown(unknown) = &value {
+            unknown_variants::record::<Self>(unknown);
+        }
+        value
     }
 }
 impl From<AccountingPeriod> for String {
@@ -7,12 +7,14 @@ use serde::{
     Deserialize, Deserializer, Serialize,
 };
 
+use super::decimal::ResponseDecimal;
+
 /// Represents an amount per share.
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(into = "String")]
 pub enum AmountPerShare {
     /// A number.
-    Number(f64),
+    Number(ResponseDecimal),
     /// "-": Undetermined
     Undetermined,
     /// "": Not applicable
@@ -47,7 +49,21 @@ impl<'de> Deserialize<'de> for AmountPerShare {
             where
                 E: de::Error,
             {
-                Ok(AmountPerShare::Number(value))
+                self.visit_str(&value.to_string())
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<AmountPerShare, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&value.to_string())
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<AmountPerShare, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&value.to_string())
             }
 
             fn visit_str<E>(self, value: &str) -> Result<AmountPerShare, E>
@@ -58,7 +74,7 @@ impl<'de> Deserialize<'de> for AmountPerShare {
                     "-" => Ok(AmountPerShare::Undetermined),
                     "" => Ok(AmountPerShare::NotApplicable),
                     _ => value
-                        .parse::<f64>()
+                        .parse::<ResponseDecimal>()
                         .map(AmountPerShare::Number)
                         .map_err(|_| E::custom(format!("Invalid number: {}", value))),
                 }
@@ -80,27 +96,37 @@ impl AmountPerShare {
     }
 
     /// Returns the number if the variant is `Number`.
-    pub fn into_number(self) -> Option<f64> {
+    pub fn into_number(self) -> Option<ResponseDecimal> {
         match self {
             AmountPerShare::Number(value) => Some(value),
             _ => None,
         }
     }
+
+    /// Converts the number into `Decimal` if the variant is `Number`.
+    #[cfg(feature = "decimal")]
+    pub fn number_decimal(&self) -> Option<Result<rust_decimal::Decimal, rust_decimal::Error>> {
+        match self {
+            AmountPerShare::Number(value) => Some(super::decimal::to_decimal(value)),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::api::shared::test_utils::assert_round_trip;
     use crate::shared::types::amount_per_share::AmountPerShare;
 
     #[test]
     fn test_serialize() {
-        let amount_per_share = AmountPerShare::Number(1.5);
+        let amount_per_share = AmountPerShare::Number("1.5".parse().unwrap());
         let serialized = serde_plain::to_string(&amount_per_share).unwrap();
         assert_eq!(serialized, "1.5");
 
-        let amount_per_share = AmountPerShare::Number(1.0);
+        let amount_per_share = AmountPerShare::Number("12.345".parse().unwrap());
         let serialized = serde_plain::to_string(&amount_per_share).unwrap();
-        assert_eq!(serialized, "1");
+        assert_eq!(serialized, "12.345");
 
         let amount_per_share = AmountPerShare::Undetermined;
         let serialized = serde_plain::to_string(&amount_per_share).unwrap();
@@ -110,4 +136,17 @@ mod tests {
         let serialized = serde_plain::to_string(&amount_per_share).unwrap();
         assert_eq!(serialized, "");
     }
+
+    #[test]
+    fn test_round_trip() {
+        for amount_per_share in [
+            AmountPerShare::Number("1.5".parse().unwrap()),
+            AmountPerShare::Number("0.1".parse().unwrap()),
+            AmountPerShare::Number("12345.678".parse().unwrap()),
+            AmountPerShare::Undetermined,
+            AmountPerShare::NotApplicable,
+        ] {
+            assert_round_trip(&amount_per_share);
+        }
+    }
 }
@@ -2,7 +2,9 @@
 
 use std::str::FromStr;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::api::shared::unknown_variants;
 
 /// Central contract month flag.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
@@ -27,6 +29,18 @@ impl FromStr for CentralContractMonthFlag {
         }
     }
 }
+
+impl CentralContractMonthFlag {
+    /// Get the flag value (e.g. "1").
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Others => "0",
+            Self::CentralContractMonth => "1",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
 impl From<&str> for CentralContractMonthFlag {
     fn from(s: &str) -> Self {
         Self::from_str(s).expect("Failed to parse CentralContractMonthFlag")
@@ -34,6 +48,37 @@ impl From<&str> for CentralContractMonthFlag {
 }
 impl From<String> for CentralContractMonthFlag {
     fn from(s: String) -> Self {
-        Self::from(s.as_str())
+        let value = Self::from(s.as_str());
+        if let Self::Unknown(unknown) = &value {
+            unknown_variants::record::<Self>(unknown);
+        }
+        value
+    }
+}
+
+impl Serialize for CentralContractMonthFlag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_central_contract_month_flag_round_trip() {
+        for (value, flag) in [
+            ("0", CentralContractMonthFlag::Others),
+            ("1", CentralContractMonthFlag::CentralContractMonth),
+            ("2", CentralContractMonthFlag::Unknown("2".to_string())),
+        ] {
+            let json = format!("\"{value}\"");
+            assert_eq!(
+                serde_json::from_str::<CentralContractMonthFlag>(&json).unwrap(),
+                flag
+            );
+            assert_eq!(serde_json::to_string(&flag).unwrap(), json);
+        }
     }
 }
@@ -0,0 +1,76 @@
+//! Date and time of responses.
+//!
+//! Dates and times are kept as the strings of the API (e.g. "2024-08-01" or "12:00:00").
+//! With the `chrono-types` feature, the response items also get `<field>_parsed()` accessors,
+//! which parse the fields into chrono types.
+
+/// Date of responses (e.g. "2024-08-01").
+pub type ResponseDate = String;
+
+/// Time of responses in JST (e.g. "12:00:00" or "09:21").
+pub type ResponseTime = String;
+
+/// Parse a date of responses.
+#[cfg(feature = "chrono-types")]
+pub(crate) fn parse_date(value: &str) -> Result<chrono::NaiveDate, chrono::ParseError> {
+    value.parse()
+}
+
+/// Parse a time of responses.
+#[cfg(feature = "chrono-types")]
+pub(crate) fn parse_time(value: &str) -> Result<chrono::NaiveTime, chrono::ParseError> {
+    value.parse()
+}
+
+#[cfg(all(test, feature = "chrono-types"))]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+    use jquants_api_client_derive::ResponseAccessors;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::api::shared::deserialize_utils::empty_string_or_null_as_none;
+
+    #[derive(Debug, Deserialize, ResponseAccessors)]
+    struct Item {
+        date: ResponseDate,
+        time: ResponseTime,
+        #[serde(deserialize_with = "empty_string_or_null_as_none")]
+        optional_date: Option<ResponseDate>,
+    }
+
+    #[test]
+    fn test_parsed_accessors() {
+        let item: Item =
+            serde_json::from_str(r#"{"date": "2024-08-01", "time": "09:21", "optional_date": ""}"#)
+                .unwrap();
+        assert_eq!(item.date, "2024-08-01");
+        assert_eq!(
+            item.date_parsed(),
+            Ok(NaiveDate::from_ymd_opt(2024, 8, 1).unwrap())
+        );
+        assert_eq!(
+            item.time_parsed(),
+            Ok(NaiveTime::from_hms_opt(9, 21, 0).unwrap())
+        );
+        assert_eq!(item.optional_date_parsed(), Ok(None));
+
+        let item: Item = serde_json::from_str(
+            r#"{"date": "2024-08-01", "time": "12:00:00", "optional_date": "2024-09-13"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            item.time_parsed(),
+            Ok(NaiveTime::from_hms_opt(12, 0, 0).unwrap())
+        );
+        assert_eq!(
+            item.optional_date_parsed(),
+            Ok(NaiveDate::from_ymd_opt(2024, 9, 13))
+        );
+
+        let item: Item =
+            serde_json::from_str(r#"{"date": "-", "time": "12:00:00", "optional_date": null}"#)
+                .unwrap();
+        assert!(item.date_parsed().is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! Decimal numbers of responses.
+//!
+//! Prices, turnover values, dividend amounts and financial figures are deserialized into `f64`.
+//! With the `decimal` feature, the response items also get `<field>_decimal()` accessors,
+//! which convert the fields into `rust_decimal::Decimal`.
+//!
+//! The values go through `f64`, so about 15 significant digits are kept. The accessors convert
+//! them from their shortest representation, so `12.345` becomes exactly `12.345`.
+
+/// Decimal number of responses (e.g. prices or amounts of money).
+pub type ResponseDecimal = f64;
+
+/// Convert a decimal number of responses into `Decimal`.
+#[cfg(feature = "decimal")]
+pub(crate) fn to_decimal(value: &f64) -> Result<rust_decimal::Decimal, rust_decimal::Error> {
+    parse_decimal(&value.to_string())
+}
+
+/// Parse a decimal number of responses from its string (e.g. "12.345" or "1.5E10").
+#[cfg(feature = "decimal")]
+pub(crate) fn parse_decimal(value: &str) -> Result<rust_decimal::Decimal, rust_decimal::Error> {
+    let value = value.trim();
+    value
+        .parse()
+        .or_else(|_| rust_decimal::Decimal::from_scientific(value))
+}
+
+#[cfg(all(test, feature = "decimal"))]
+mod tests {
+    use jquants_api_client_derive::ResponseAccessors;
+    use rust_decimal::Decimal;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::api::shared::deserialize_utils::deserialize_number_or_none;
+
+    #[derive(Debug, Deserialize, ResponseAccessors)]
+    struct Item {
+        price: ResponseDecimal,
+        #[serde(deserialize_with = "deserialize_number_or_none")]
+        optional_price: Option<ResponseDecimal>,
+    }
+
+    #[test]
+    fn test_decimal_accessors() {
+        let item: Item =
+            serde_json::from_str(r#"{"price": 12.345, "optional_price": ""}"#).unwrap();
+        assert_eq!(item.price, 12.345);
+        assert_eq!(item.price_decimal(), Ok(Decimal::new(12345, 3)));
+        assert_eq!(item.optional_price_decimal(), Ok(None));
+
+        let item: Item = serde_json::from_str(
+            r#"{"price": 100529000000, "optional_price": "1234567890123.45"}"#,
+        )
+        .unwrap();
+        assert_eq!(item.price_decimal(), Ok(Decimal::new(100529000000, 0)));
+        assert_eq!(
+            item.optional_price_decimal(),
+            Ok(Some(Decimal::new(123456789012345, 2)))
+        );
+
+        let item = Item {
+            price: f64::NAN,
+            optional_price: None,
+        };
+        assert!(item.price_decimal().is_err());
+    }
+}
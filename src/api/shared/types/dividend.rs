@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Code stands for dividend status
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum DevidendStatucCode {
@@ -15,7 +17,10 @@ pub enum DevidendStatucCode {
     #[serde(rename = "3")]
     Delete,
     /// Unknown reference status code
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "deserialize_unknown::<_, DevidendStatucCode>"
+    )]
     Unknown(String),
 }
 
@@ -29,7 +34,10 @@ pub enum DividendInterimFinalCode {
     #[serde(rename = "2")]
     Final,
     /// Unknown dividend interim/final code
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "deserialize_unknown::<_, DividendInterimFinalCode>"
+    )]
     Unknown(String),
 }
 
@@ -43,7 +51,10 @@ pub enum DividendForecastResultCode {
     #[serde(rename = "2")]
     Forecast,
     /// Unknown dividend forecast result code
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "deserialize_unknown::<_, DividendForecastResultCode>"
+    )]
     Unknown(String),
 }
 
@@ -63,6 +74,27 @@ pub enum DividendCommemorativeSpecialCode {
     #[serde(rename = "3")]
     Both,
     /// Unknown dividend commemorative/special code
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "deserialize_unknown::<_, DividendCommemorativeSpecialCode>"
+    )]
     Unknown(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
+
+    #[test]
+    fn test_dividend_codes_round_trip() {
+        assert_round_trip(&DevidendStatucCode::Revised);
+        assert_round_trip(&DevidendStatucCode::Unknown("9".to_string()));
+        assert_round_trip(&DividendInterimFinalCode::Final);
+        assert_round_trip(&DividendInterimFinalCode::Unknown("9".to_string()));
+        assert_round_trip(&DividendForecastResultCode::Forecast);
+        assert_round_trip(&DividendForecastResultCode::Unknown("9".to_string()));
+        assert_round_trip(&DividendCommemorativeSpecialCode::Both);
+        assert_round_trip(&DividendCommemorativeSpecialCode::Unknown("9".to_string()));
+    }
+}
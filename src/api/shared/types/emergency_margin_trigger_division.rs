@@ -2,7 +2,9 @@
 
 use std::str::FromStr;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::api::shared::unknown_variants;
 
 /// Emergency margin trigger division.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
@@ -27,6 +29,18 @@ impl FromStr for EmergencyMarginTriggerDivision {
         }
     }
 }
+
+impl EmergencyMarginTriggerDivision {
+    /// Get the division value (e.g. "001").
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Triggered => "001",
+            Self::Calculated => "002",
+            Self::Unknown(s) => s,
+        }
+    }
+}
+
 impl From<&str> for EmergencyMarginTriggerDivision {
     fn from(s: &str) -> Self {
         Self::from_str(s).expect("Failed to parse EmergencyMarginTriggerDivision")
@@ -34,6 +48,40 @@ impl From<&str> for EmergencyMarginTriggerDivision {
 }
 impl From<String> for EmergencyMarginTriggerDivision {
     fn from(s: String) -> Self {
-        Self::from(s.as_str())
+        let value = Self::from(s.as_str());
+        if let Self::Unknown(unknown) = &value {
+            unknown_variants::record::<Self>(unknown);
+        }
+        value
+    }
+}
+
+impl Serialize for EmergencyMarginTriggerDivision {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emergency_margin_trigger_division_round_trip() {
+        for (value, division) in [
+            ("001", EmergencyMarginTriggerDivision::Triggered),
+            ("002", EmergencyMarginTriggerDivision::Calculated),
+            (
+                "003",
+                EmergencyMarginTriggerDivision::Unknown("003".to_string()),
+            ),
+        ] {
+            let json = format!("\"{value}\"");
+            assert_eq!(
+                serde_json::from_str::<EmergencyMarginTriggerDivision>(&json).unwrap(),
+                division
+            );
+            assert_eq!(serde_json::to_string(&division).unwrap(), json);
+        }
     }
 }
@@ -0,0 +1,9 @@
+//! Fields of response items not known to this crate.
+
+use std::collections::BTreeMap;
+
+/// Fields of a response item not known to this crate, keyed by the field name of the API.
+///
+/// Fields newly added to the API are kept here (with the `extra-fields` feature)
+/// until the crate supports them.
+pub type ExtraFields = BTreeMap<String, serde_json::Value>;
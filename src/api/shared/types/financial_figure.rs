@@ -0,0 +1,203 @@
+//! Represents a figure of financial statements.
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use super::decimal::ResponseDecimal;
+
+/// Represents a figure of financial statements (e.g. net sales or earnings per share).
+///
+/// The raw string of the API is kept, so it is accessible with [`FinancialFigure::as_raw`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(into = "String")]
+pub enum FinancialFigure {
+    /// A number.
+    Number {
+        /// The parsed number.
+        value: ResponseDecimal,
+        /// The raw string (e.g. "26.0").
+        raw: String,
+    },
+    /// "-": Undetermined
+    Undetermined,
+    /// "": Not applicable
+    NotApplicable,
+    /// A value that is not a number.
+    Unknown(String),
+}
+
+impl FinancialFigure {
+    /// Returns the raw string of the API.
+    pub fn as_raw(&self) -> &str {
+        match self {
+            FinancialFigure::Number { raw, .. } => raw,
+            FinancialFigure::Undetermined => "-",
+            FinancialFigure::NotApplicable => "",
+            FinancialFigure::Unknown(raw) => raw,
+        }
+    }
+
+    /// Returns the number if the variant is `Number`.
+    pub fn number(&self) -> Option<ResponseDecimal> {
+        match self {
+            FinancialFigure::Number { value, .. } => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Parses the raw string into `Decimal` if the variant is `Number`.
+    ///
+    /// The raw string is parsed, so the number is exact even beyond the precision of `f64`.
+    #[cfg(feature = "decimal")]
+    pub fn number_decimal(&self) -> Option<Result<rust_decimal::Decimal, rust_decimal::Error>> {
+        match self {
+            FinancialFigure::Number { raw, .. } => Some(super::decimal::parse_decimal(raw)),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for FinancialFigure {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => FinancialFigure::Undetermined,
+            "" => FinancialFigure::NotApplicable,
+            _ => match s.parse::<ResponseDecimal>() {
+                Ok(value) => FinancialFigure::Number {
+                    value,
+                    raw: s.to_string(),
+                },
+                Err(_) => FinancialFigure::Unknown(s.to_string()),
+            },
+        })
+    }
+}
+
+impl From<FinancialFigure> for String {
+    fn from(figure: FinancialFigure) -> String {
+        match figure {
+            FinancialFigure::Number { raw, .. } => raw,
+            FinancialFigure::Unknown(raw) => raw,
+            figure => figure.as_raw().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for FinancialFigure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_raw())
+    }
+}
+
+impl<'de> Deserialize<'de> for FinancialFigure {
+    fn deserialize<D>(deserializer: D) -> Result<FinancialFigure, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FinancialFigureVisitor;
+
+        impl Visitor<'_> for FinancialFigureVisitor {
+            type Value = FinancialFigure;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter
+                    .write_str("a number, a string representing a number, '-', or an empty string")
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<FinancialFigure, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&value.to_string())
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<FinancialFigure, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&value.to_string())
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<FinancialFigure, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(&value.to_string())
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<FinancialFigure, E>
+            where
+                E: de::Error,
+            {
+                Ok(value.parse().unwrap_or_else(|e: Infallible| match e {}))
+            }
+        }
+
+        deserializer.deserialize_any(FinancialFigureVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
+
+    #[test]
+    fn test_deserialize() {
+        let figures: Vec<FinancialFigure> =
+            serde_json::from_str(r#"["26.0", "-", "", "N/A", 1.5, 100]"#).unwrap();
+        assert_eq!(
+            figures,
+            vec![
+                FinancialFigure::Number {
+                    value: "26.0".parse().unwrap(),
+                    raw: "26.0".to_string()
+                },
+                FinancialFigure::Undetermined,
+                FinancialFigure::NotApplicable,
+                FinancialFigure::Unknown("N/A".to_string()),
+                FinancialFigure::Number {
+                    value: "1.5".parse().unwrap(),
+                    raw: "1.5".to_string()
+                },
+                FinancialFigure::Number {
+                    value: "100".parse().unwrap(),
+                    raw: "100".to_string()
+                },
+            ]
+        );
+        assert_eq!(figures[0].number(), "26.0".parse().ok());
+        assert_eq!(figures[1].number(), None);
+    }
+
+    #[test]
+    fn test_serialize_keeps_raw() {
+        for raw in ["26.0", "-", "", "N/A", "100529000000"] {
+            let figure: FinancialFigure = raw.parse().unwrap();
+            assert_eq!(figure.as_raw(), raw);
+            assert_eq!(serde_plain::to_string(&figure).unwrap(), raw);
+            assert_round_trip(&figure);
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_number_decimal() {
+        use rust_decimal::Decimal;
+
+        let figure: FinancialFigure = "1234567890123456.78".parse().unwrap();
+        assert_eq!(
+            figure.number_decimal(),
+            Some(Ok(Decimal::new(123456789012345678, 2)))
+        );
+        let figure: FinancialFigure = "1.5E3".parse().unwrap();
+        assert_eq!(figure.number_decimal(), Some(Ok(Decimal::new(1500, 0))));
+        assert_eq!(FinancialFigure::Undetermined.number_decimal(), None);
+    }
+}
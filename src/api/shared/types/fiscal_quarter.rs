@@ -0,0 +1,58 @@
+//! Fiscal quarter of earnings announcements.
+
+use std::{convert::Infallible, str::FromStr};
+
+use crate::api::shared::ja_text;
+
+/// Fiscal quarter of earnings announcements (e.g. "第１四半期").
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FiscalQuarter {
+    /// 第1四半期: First quarter
+    Q1,
+    /// 第2四半期: Second quarter
+    Q2,
+    /// 第3四半期: Third quarter
+    Q3,
+    /// 本決算 (第4四半期): Full year
+    FullYear,
+    /// Unknown value.
+    Unknown(String),
+}
+
+impl FromStr for FiscalQuarter {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match ja_text::normalize(s).as_str() {
+            "第1四半期" | "1Q" => Self::Q1,
+            "第2四半期" | "2Q" => Self::Q2,
+            "第3四半期" | "3Q" => Self::Q3,
+            "第4四半期" | "4Q" | "本決算" | "通期" => Self::FullYear,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<&str> for FiscalQuarter {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|e: Infallible| match e {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fiscal_quarter_from_str() {
+        assert_eq!(FiscalQuarter::from("第１四半期"), FiscalQuarter::Q1);
+        assert_eq!(FiscalQuarter::from("第2四半期"), FiscalQuarter::Q2);
+        assert_eq!(FiscalQuarter::from("第３四半期"), FiscalQuarter::Q3);
+        assert_eq!(FiscalQuarter::from("第4四半期"), FiscalQuarter::FullYear);
+        assert_eq!(FiscalQuarter::from("本決算"), FiscalQuarter::FullYear);
+        assert_eq!(
+            FiscalQuarter::from("中間"),
+            FiscalQuarter::Unknown("中間".to_string())
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! End of fiscal year of earnings announcements.
+
+use std::{fmt, str::FromStr};
+
+use crate::api::shared::ja_text;
+
+/// End of fiscal year as a month and a day (e.g. "9月30日").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FiscalYearEnd {
+    /// Month (1-12).
+    pub month: u32,
+    /// Day of the month (1-31).
+    pub day: u32,
+}
+
+impl FromStr for FiscalYearEnd {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = ja_text::normalize(s);
+        let (month, day) = normalized
+            .strip_suffix('日')
+            .and_then(|s| s.split_once('月'))
+            .ok_or_else(|| format!("Invalid fiscal year end: {s}"))?;
+        let month: u32 = month
+            .parse()
+            .map_err(|_| format!("Invalid month of fiscal year end: {s}"))?;
+        let day: u32 = day
+            .parse()
+            .map_err(|_| format!("Invalid day of fiscal year end: {s}"))?;
+
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(format!("Fiscal year end is out of range: {s}"));
+        }
+
+        Ok(Self { month, day })
+    }
+}
+
+impl fmt::Display for FiscalYearEnd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}月{}日", self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fiscal_year_end_from_str() {
+        assert_eq!(
+            "9月30日".parse::<FiscalYearEnd>(),
+            Ok(FiscalYearEnd { month: 9, day: 30 })
+        );
+        assert_eq!(
+            "３月３１日".parse::<FiscalYearEnd>(),
+            Ok(FiscalYearEnd { month: 3, day: 31 })
+        );
+        assert_eq!(FiscalYearEnd { month: 3, day: 31 }.to_string(), "3月31日");
+        assert!("13月1日".parse::<FiscalYearEnd>().is_err());
+        assert!("-".parse::<FiscalYearEnd>().is_err());
+    }
+}
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Futures codes.
 ///
 /// [See Reference](https://jpx.gitbook.io/j-quants-en/api-reference/futures/derivativeproductcategory)
@@ -86,6 +88,6 @@ pub enum FuturesCode {
     TOA3MF,
 
     /// Handles unexpected or unknown futures codes.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, FuturesCode>")]
     Unknown(String),
 }
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Holiday division
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum HolidayDivision {
@@ -19,7 +21,10 @@ pub enum HolidayDivision {
     NonBusinessDaysWithHolidayTrading,
 
     /// Unknown holiday division
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "deserialize_unknown::<_, HolidayDivision>"
+    )]
     Unknown(String),
 }
 
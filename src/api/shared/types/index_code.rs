@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Indices codes.
 ///
 /// [See Reference](https://jpx.gitbook.io/j-quants-en/api-reference/indices/indices-codes)
@@ -469,6 +471,6 @@ pub enum IndexCode {
     REITRetailLogisticsOthersIndex,
 
     /// Handles unexpected or unknown index codes.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, IndexCode>")]
     Unknown(String),
 }
@@ -0,0 +1,182 @@
+//! Issue code.
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+/// Issue code in the 5-character form (e.g. "86970").
+///
+/// Parses either the 4-character form (e.g. "8697") or the 5-character form.
+/// The 4-character form is normalized by appending `0`, which is the code of common stock.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IssueCode(String);
+
+impl IssueCode {
+    /// Get the 5-character form (e.g. "86970").
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the 4-character form (e.g. "8697").
+    pub fn short(&self) -> &str {
+        &self.0[..4]
+    }
+}
+
+impl FromStr for IssueCode {
+    type Err = ParseIssueCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(ParseIssueCodeError(s.to_string()));
+        }
+
+        let code = s.to_ascii_uppercase();
+        match code.len() {
+            4 => Ok(Self(code + "0")),
+            5 => Ok(Self(code)),
+            _ => Err(ParseIssueCodeError(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for IssueCode {
+    type Error = ParseIssueCodeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for IssueCode {
+    type Error = ParseIssueCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for IssueCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for IssueCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssueCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Error of parsing an issue code.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid issue code: {0:?}. Expected 4 or 5 alphanumeric characters")]
+pub struct ParseIssueCodeError(String);
+
+impl ParseIssueCodeError {
+    /// Get the value that failed to parse.
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Trait for types that can be used as an issue code of query parameters.
+pub trait IntoIssueCode {
+    /// Convert into an issue code.
+    fn into_issue_code(self) -> Result<IssueCode, ParseIssueCodeError>;
+}
+
+impl IntoIssueCode for IssueCode {
+    fn into_issue_code(self) -> Result<IssueCode, ParseIssueCodeError> {
+        Ok(self)
+    }
+}
+
+impl IntoIssueCode for &IssueCode {
+    fn into_issue_code(self) -> Result<IssueCode, ParseIssueCodeError> {
+        Ok(self.clone())
+    }
+}
+
+impl IntoIssueCode for Result<IssueCode, ParseIssueCodeError> {
+    fn into_issue_code(self) -> Result<IssueCode, ParseIssueCodeError> {
+        self
+    }
+}
+
+impl IntoIssueCode for &str {
+    fn into_issue_code(self) -> Result<IssueCode, ParseIssueCodeError> {
+        self.parse()
+    }
+}
+
+impl IntoIssueCode for String {
+    fn into_issue_code(self) -> Result<IssueCode, ParseIssueCodeError> {
+        self.parse()
+    }
+}
+
+impl IntoIssueCode for &String {
+    fn into_issue_code(self) -> Result<IssueCode, ParseIssueCodeError> {
+        self.parse()
+    }
+}
+
+/// Serialize an issue code of query parameters.
+///
+/// An invalid issue code fails, so no request is sent.
+pub(crate) fn serialize_issue_code_param<S: Serializer>(
+    code: &Option<Result<IssueCode, ParseIssueCodeError>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match code {
+        Some(Ok(code)) => code.serialize(serializer),
+        Some(Err(e)) => Err(serde::ser::Error::custom(e)),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let code: IssueCode = "8697".parse().unwrap();
+        assert_eq!(code.as_str(), "86970");
+        assert_eq!(code.short(), "8697");
+        assert_eq!(code, "86970".parse().unwrap());
+
+        let preferred: IssueCode = "25935".parse().unwrap();
+        assert_eq!(preferred.to_string(), "25935");
+        assert_eq!(preferred.short(), "2593");
+
+        let alphanumeric: IssueCode = "130a".parse().unwrap();
+        assert_eq!(alphanumeric.as_str(), "130A0");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for value in ["", "869", "869700", "8697-", "８６９７"] {
+            let err = value.parse::<IssueCode>().unwrap_err();
+            assert_eq!(err.value(), value);
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let code: IssueCode = serde_json::from_str(r#""7203""#).unwrap();
+        assert_eq!(code.as_str(), "72030");
+        assert_eq!(serde_json::to_string(&code).unwrap(), r#""72030""#);
+
+        assert!(serde_json::from_str::<IssueCode>(r#""72""#).is_err());
+    }
+}
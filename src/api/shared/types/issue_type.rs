@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Represents the classification of an issue.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum IssueType {
@@ -16,6 +18,6 @@ pub enum IssueType {
     Other,
 
     /// Unknown issue type
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, IssueType>")]
     Unknown(String),
 }
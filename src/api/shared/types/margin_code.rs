@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Margin codes.
 ///
 /// [See Reference](https://jpx.gitbook.io/j-quants-en/api-reference/listed_info)
@@ -20,6 +22,6 @@ pub enum MarginCode {
     OtherIssues,
 
     /// Handles unexpected or unknown margin codes.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, MarginCode>")]
     Unknown(String),
 }
@@ -2,6 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
+use crate::api::shared::ja_text;
+
 /// Represents the market codes.
 ///
 /// See: https://jpx.gitbook.io/j-quants-en/api-reference/listed_info/marketcode
@@ -68,11 +72,25 @@ pub enum MarketCode {
     Growth,
 
     /// Unknown market segment code.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, MarketCode>")]
     Unknown(String),
 }
 
 impl MarketCode {
+    /// All known market segments.
+    const KNOWN: [MarketCode; 10] = [
+        MarketCode::TSEFirstSection,
+        MarketCode::TSESecondSection,
+        MarketCode::Mothers,
+        MarketCode::TokyoProMarket,
+        MarketCode::JasdaqStandard,
+        MarketCode::JasdaqGrowth,
+        MarketCode::Others,
+        MarketCode::Prime,
+        MarketCode::Standard,
+        MarketCode::Growth,
+    ];
+
     /// Returns the English name corresponding to the market segment code.
     ///
     /// The document's English expressions are hardcoded.
@@ -92,6 +110,42 @@ impl MarketCode {
             MarketCode::Unknown(_) => "Unknown",
         }
     }
+
+    /// Returns the Japanese name corresponding to the market segment code.
+    ///
+    /// The document's Japanese expressions are hardcoded.
+    /// See: https://jpx.gitbook.io/j-quants-ja/api-reference/listed_info/marketcode
+    pub fn ja_name(&self) -> &'static str {
+        match self {
+            MarketCode::TSEFirstSection => "東証一部",
+            MarketCode::TSESecondSection => "東証二部",
+            MarketCode::Mothers => "マザーズ",
+            MarketCode::TokyoProMarket => "東証 PRO Market",
+            MarketCode::JasdaqStandard => "JASDAQ スタンダード",
+            MarketCode::JasdaqGrowth => "JASDAQ グロース",
+            MarketCode::Others => "その他",
+            MarketCode::Prime => "プライム",
+            MarketCode::Standard => "スタンダード",
+            MarketCode::Growth => "グロース",
+            MarketCode::Unknown(_) => "不明",
+        }
+    }
+
+    /// Finds the market segment by its Japanese name (e.g. "マザーズ" or "東証プライム").
+    ///
+    /// The "東証" prefix, white spaces and full-width characters are ignored.
+    pub fn from_ja_name(name: &str) -> Option<Self> {
+        fn key(name: &str) -> String {
+            let name = ja_text::normalize(name);
+            name.strip_prefix("東証").unwrap_or(&name).to_string()
+        }
+
+        let name = key(name);
+        Self::KNOWN
+            .iter()
+            .find(|code| key(code.ja_name()) == name)
+            .cloned()
+    }
 }
 
 // Example usage
@@ -134,4 +188,29 @@ mod tests {
             MarketCode::Unknown("0114".to_string())
         ); // Unknown code mapped to Unknown
     }
+
+    #[test]
+    fn test_from_ja_name() {
+        assert_eq!(
+            MarketCode::from_ja_name("マザーズ"),
+            Some(MarketCode::Mothers)
+        );
+        assert_eq!(
+            MarketCode::from_ja_name("東証マザーズ"),
+            Some(MarketCode::Mothers)
+        );
+        assert_eq!(
+            MarketCode::from_ja_name("東証プライム"),
+            Some(MarketCode::Prime)
+        );
+        assert_eq!(
+            MarketCode::from_ja_name("東証一部"),
+            Some(MarketCode::TSEFirstSection)
+        );
+        assert_eq!(
+            MarketCode::from_ja_name("ＪＡＳＤＡＱスタンダード"),
+            Some(MarketCode::JasdaqStandard)
+        );
+        assert_eq!(MarketCode::from_ja_name("名証"), None);
+    }
 }
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Options codes.
 ///
 /// [See Reference](Add_your_reference_URL_here)
@@ -38,6 +40,6 @@ pub enum OptionsCode {
     NK225MWE,
 
     /// Handles unexpected or unknown options codes.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, OptionsCode>")]
     Unknown(String),
 }
@@ -7,12 +7,14 @@ use serde::{
     Deserialize, Deserializer, Serialize,
 };
 
+use super::date_time::ResponseDate;
+
 /// Represents a payable date.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 #[serde(into = "String")]
 pub enum PayableDate {
-    /// A date string.
-    Date(String),
+    /// A date (e.g. "2024-08-01").
+    Date(ResponseDate),
     /// "-": Undetermined
     Undetermined,
     /// "": Not applicable
@@ -22,7 +24,7 @@ pub enum PayableDate {
 impl From<PayableDate> for String {
     fn from(payable_date: PayableDate) -> String {
         match payable_date {
-            PayableDate::Date(value) => value,
+            PayableDate::Date(value) => value.to_string(),
             PayableDate::Undetermined => "-".to_string(),
             PayableDate::NotApplicable => "".to_string(),
         }
@@ -50,7 +52,7 @@ impl<'de> Deserialize<'de> for PayableDate {
                 match value {
                     "-" => Ok(PayableDate::Undetermined),
                     "" => Ok(PayableDate::NotApplicable),
-                    _ => Ok(PayableDate::Date(value.to_string())),
+                    _ => value.parse().map(PayableDate::Date).map_err(E::custom),
                 }
             }
 
@@ -76,26 +78,36 @@ impl PayableDate {
         }
     }
 
-    /// Returns the date string if the payable date is a date.
-    pub fn into_date(self) -> Option<String> {
+    /// Returns the date if the payable date is a date.
+    pub fn into_date(self) -> Option<ResponseDate> {
         match self {
             PayableDate::Date(date) => Some(date),
             _ => None,
         }
     }
+
+    /// Parse the date if the payable date is a date.
+    #[cfg(feature = "chrono-types")]
+    pub fn date_parsed(&self) -> Option<Result<chrono::NaiveDate, chrono::ParseError>> {
+        match self {
+            PayableDate::Date(date) => Some(super::date_time::parse_date(date)),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::api::shared::test_utils::assert_round_trip;
     use crate::shared::types::payable_date::PayableDate;
 
     #[test]
     fn test_serialize() {
-        let amount_per_share = PayableDate::Date("2021-01-01".to_string());
+        let amount_per_share = PayableDate::Date("2021-01-01".parse().unwrap());
         let serialized = serde_plain::to_string(&amount_per_share).unwrap();
         assert_eq!(serialized, "2021-01-01");
 
-        let amount_per_share = PayableDate::Date("2022-03-04".to_string());
+        let amount_per_share = PayableDate::Date("2022-03-04".parse().unwrap());
         let serialized = serde_plain::to_string(&amount_per_share).unwrap();
         assert_eq!(serialized, "2022-03-04");
 
@@ -107,4 +119,34 @@ mod tests {
         let serialized = serde_plain::to_string(&amount_per_share).unwrap();
         assert_eq!(serialized, "");
     }
+
+    #[test]
+    fn test_round_trip() {
+        for payable_date in [
+            PayableDate::Date("2021-01-01".parse().unwrap()),
+            PayableDate::Undetermined,
+            PayableDate::NotApplicable,
+        ] {
+            assert_round_trip(&payable_date);
+        }
+    }
+
+    #[cfg(feature = "chrono-types")]
+    #[test]
+    fn test_date_parsed() {
+        use chrono::NaiveDate;
+
+        let payable_date: PayableDate = serde_json::from_str("\"2023-03-15\"").unwrap();
+        assert_eq!(payable_date, PayableDate::Date("2023-03-15".to_string()));
+        assert_eq!(
+            payable_date.date_parsed(),
+            Some(Ok(NaiveDate::from_ymd_opt(2023, 3, 15).unwrap()))
+        );
+
+        assert_eq!(PayableDate::Undetermined.date_parsed(), None);
+        assert_eq!(PayableDate::NotApplicable.date_parsed(), None);
+
+        let invalid: PayableDate = serde_json::from_str("\"2023-13-01\"").unwrap();
+        assert!(matches!(invalid.date_parsed(), Some(Err(_))));
+    }
 }
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Upper or Lower price limit.
 ///
 /// [See Reference](https://jpx.gitbook.io/j-quants-en/api-reference/daily_quotes)
@@ -16,6 +18,6 @@ pub enum PriceLimit {
     Hit,
 
     /// Unknown price limit.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, PriceLimit>")]
     Unknown(String),
 }
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Put/Call division.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PutCallDivision {
@@ -12,6 +14,9 @@ pub enum PutCallDivision {
     #[serde(rename = "2")]
     Call,
     /// Unknown value.
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "deserialize_unknown::<_, PutCallDivision>"
+    )]
     Unknown(String),
 }
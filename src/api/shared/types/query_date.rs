@@ -0,0 +1,186 @@
+//! Date of query parameters.
+
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, TimeZone};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Date of a query parameter.
+///
+/// Valid dates are normalized to `YYYY-MM-DD`.
+/// An invalid date is kept as is and fails on sending the request with [`crate::JQuantsError::InvalidQueryParameter`],
+/// so no request is sent to the server.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryDate(Result<NaiveDate, String>);
+
+impl QueryDate {
+    /// Parse `YYYYMMDD` or `YYYY-MM-DD`.
+    pub fn parse(value: &str) -> Self {
+        let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .filter(|_| value.len() == 10)
+            .or_else(|| {
+                NaiveDate::parse_from_str(value, "%Y%m%d")
+                    .ok()
+                    .filter(|_| value.len() == 8 && value.bytes().all(|b| b.is_ascii_digit()))
+            });
+
+        Self(date.ok_or_else(|| value.to_string()))
+    }
+
+    /// Get the date if it is valid.
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.0.as_ref().ok().copied()
+    }
+
+    /// Whether the date is valid.
+    pub fn is_valid(&self) -> bool {
+        self.0.is_ok()
+    }
+}
+
+impl fmt::Display for QueryDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            Err(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl Serialize for QueryDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Ok(_) => serializer.collect_str(self),
+            Err(value) => Err(serde::ser::Error::custom(format!(
+                "Invalid date: {value:?}. Expected YYYYMMDD or YYYY-MM-DD"
+            ))),
+        }
+    }
+}
+
+/// Only a valid date is deserialized, so an invalid date fails on loading instead of on sending.
+impl<'de> Deserialize<'de> for QueryDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let date = Self::parse(&value);
+        if date.is_valid() {
+            Ok(date)
+        } else {
+            Err(serde::de::Error::custom(format!(
+                "Invalid date: {value:?}. Expected YYYYMMDD or YYYY-MM-DD"
+            )))
+        }
+    }
+}
+
+/// Trait for types that can be used as a date of query parameters.
+pub trait IntoQueryDate {
+    /// Convert into a query date.
+    fn into_query_date(self) -> QueryDate;
+}
+
+impl IntoQueryDate for QueryDate {
+    fn into_query_date(self) -> QueryDate {
+        self
+    }
+}
+
+impl IntoQueryDate for NaiveDate {
+    fn into_query_date(self) -> QueryDate {
+        QueryDate(Ok(self))
+    }
+}
+
+/// The date in the time zone of the `DateTime` is used.
+impl<Tz: TimeZone> IntoQueryDate for DateTime<Tz> {
+    fn into_query_date(self) -> QueryDate {
+        QueryDate(Ok(self.date_naive()))
+    }
+}
+
+impl IntoQueryDate for &str {
+    fn into_query_date(self) -> QueryDate {
+        QueryDate::parse(self)
+    }
+}
+
+impl IntoQueryDate for String {
+    fn into_query_date(self) -> QueryDate {
+        QueryDate::parse(&self)
+    }
+}
+
+impl IntoQueryDate for &String {
+    fn into_query_date(self) -> QueryDate {
+        QueryDate::parse(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, Utc};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_valid() {
+        assert_eq!("2024-08-01".into_query_date().to_string(), "2024-08-01");
+        assert_eq!("20240801".into_query_date().to_string(), "2024-08-01");
+        assert_eq!(
+            "20240801".to_string().into_query_date(),
+            "2024-08-01".into_query_date()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for value in [
+            "2024-13-01",
+            "2024-02-30",
+            "20241301",
+            "2024/08/01",
+            "2024-8-1",
+            "+2024801",
+            "",
+        ] {
+            let date = value.into_query_date();
+            assert!(!date.is_valid(), "{value}");
+            assert_eq!(date.to_string(), value);
+        }
+    }
+
+    #[test]
+    fn test_chrono_dates() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 1).unwrap();
+        assert_eq!(date.into_query_date().to_string(), "2024-08-01");
+
+        let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let datetime = Utc.with_ymd_and_hms(2024, 7, 31, 20, 0, 0).unwrap();
+        assert_eq!(datetime.into_query_date().to_string(), "2024-07-31");
+        assert_eq!(
+            datetime.with_timezone(&jst).into_query_date().to_string(),
+            "2024-08-01"
+        );
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&"20240801".into_query_date()).unwrap(),
+            r#""2024-08-01""#
+        );
+
+        let err = serde_json::to_string(&"2024-13-01".into_query_date()).unwrap_err();
+        assert!(err.to_string().contains("2024-13-01"));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let date: QueryDate = serde_json::from_str(r#""20240801""#).unwrap();
+        assert_eq!(date, "2024-08-01".into_query_date());
+
+        let err = serde_json::from_str::<QueryDate>(r#""2024-13-01""#).unwrap_err();
+        assert!(err.to_string().contains("2024-13-01"));
+    }
+}
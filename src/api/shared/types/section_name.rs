@@ -4,6 +4,8 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Section name
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SectionName {
@@ -56,7 +58,7 @@ pub enum SectionName {
     TokyoNagoyaStockExchange,
 
     /// Unknown section name.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, SectionName>")]
     Unknown(String),
 }
 
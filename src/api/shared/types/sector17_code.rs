@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Represents the 17 sector codes
 ///
 /// See: https://jpx.gitbook.io/j-quants-ja/api-reference/listed_info/sector17code
@@ -118,7 +120,7 @@ pub enum Sector17Code {
     /// Unknown code
     /// Takes this value if new code is added to the API.
     /// Please use this value until enum variants are added via library updates.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, Sector17Code>")]
     Unknown(String),
 }
 
@@ -2,6 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
+use crate::api::shared::ja_text;
+
 /// Represents the 33 sector codes.
 ///
 /// See: https://jpx.gitbook.io/j-quants-ja/api-reference/listed_info/sector33code
@@ -214,11 +218,49 @@ pub enum Sector33Code {
     /// Unknown code
     /// Takes this value if new code is added to the API.
     /// Please use this value until enum variants are added via library updates.
-    #[serde(untagged)]
+    #[serde(untagged, deserialize_with = "deserialize_unknown::<_, Sector33Code>")]
     Unknown(String),
 }
 
 impl Sector33Code {
+    /// All known sectors.
+    const KNOWN: [Sector33Code; 34] = [
+        Sector33Code::FisheryAgricultureForestry,
+        Sector33Code::Mining,
+        Sector33Code::Construction,
+        Sector33Code::Foods,
+        Sector33Code::TextilesApparels,
+        Sector33Code::PulpPaper,
+        Sector33Code::Chemicals,
+        Sector33Code::Pharmaceutical,
+        Sector33Code::OilCoalProducts,
+        Sector33Code::RubberProducts,
+        Sector33Code::GlassCeramicsProducts,
+        Sector33Code::IronSteel,
+        Sector33Code::NonferrousMetals,
+        Sector33Code::MetalProducts,
+        Sector33Code::Machinery,
+        Sector33Code::ElectricAppliances,
+        Sector33Code::TransportationEquipment,
+        Sector33Code::PrecisionInstruments,
+        Sector33Code::OtherProducts,
+        Sector33Code::ElectricPowerGas,
+        Sector33Code::LandTransportation,
+        Sector33Code::MarineTransportation,
+        Sector33Code::AirTransportation,
+        Sector33Code::WarehousingHarborTransportationServices,
+        Sector33Code::InformationCommunication,
+        Sector33Code::WholesaleTrade,
+        Sector33Code::RetailTrade,
+        Sector33Code::Banks,
+        Sector33Code::SecuritiesCommodityFutures,
+        Sector33Code::Insurance,
+        Sector33Code::OtherFinancingBusiness,
+        Sector33Code::RealEstate,
+        Sector33Code::Services,
+        Sector33Code::Other,
+    ];
+
     /// English name of the sector.
     ///
     /// The document's English expressions are hardcoded.
@@ -264,6 +306,61 @@ impl Sector33Code {
             Sector33Code::Unknown(_) => "Unknown",
         }
     }
+
+    /// Japanese name of the sector.
+    ///
+    /// The document's Japanese expressions are hardcoded.
+    /// See: https://jpx.gitbook.io/j-quants-ja/api-reference/listed_info/sector33code
+    pub fn ja_name(&self) -> &'static str {
+        match self {
+            Sector33Code::FisheryAgricultureForestry => "水産・農林業",
+            Sector33Code::Mining => "鉱業",
+            Sector33Code::Construction => "建設業",
+            Sector33Code::Foods => "食料品",
+            Sector33Code::TextilesApparels => "繊維製品",
+            Sector33Code::PulpPaper => "パルプ・紙",
+            Sector33Code::Chemicals => "化学",
+            Sector33Code::Pharmaceutical => "医薬品",
+            Sector33Code::OilCoalProducts => "石油･石炭製品",
+            Sector33Code::RubberProducts => "ゴム製品",
+            Sector33Code::GlassCeramicsProducts => "ガラス･土石製品",
+            Sector33Code::IronSteel => "鉄鋼",
+            Sector33Code::NonferrousMetals => "非鉄金属",
+            Sector33Code::MetalProducts => "金属製品",
+            Sector33Code::Machinery => "機械",
+            Sector33Code::ElectricAppliances => "電気機器",
+            Sector33Code::TransportationEquipment => "輸送用機器",
+            Sector33Code::PrecisionInstruments => "精密機器",
+            Sector33Code::OtherProducts => "その他製品",
+            Sector33Code::ElectricPowerGas => "電気･ガス業",
+            Sector33Code::LandTransportation => "陸運業",
+            Sector33Code::MarineTransportation => "海運業",
+            Sector33Code::AirTransportation => "空運業",
+            Sector33Code::WarehousingHarborTransportationServices => "倉庫･運輸関連業",
+            Sector33Code::InformationCommunication => "情報･通信業",
+            Sector33Code::WholesaleTrade => "卸売業",
+            Sector33Code::RetailTrade => "小売業",
+            Sector33Code::Banks => "銀行業",
+            Sector33Code::SecuritiesCommodityFutures => "証券･商品先物取引業",
+            Sector33Code::Insurance => "保険業",
+            Sector33Code::OtherFinancingBusiness => "その他金融業",
+            Sector33Code::RealEstate => "不動産業",
+            Sector33Code::Services => "サービス業",
+            Sector33Code::Other => "その他",
+            Sector33Code::Unknown(_) => "不明",
+        }
+    }
+
+    /// Finds the sector by its Japanese name (e.g. "情報・通信業").
+    ///
+    /// Full-width and half-width middle dots are not distinguished.
+    pub fn from_ja_name(name: &str) -> Option<Self> {
+        let name = ja_text::normalize(name);
+        Self::KNOWN
+            .iter()
+            .find(|code| ja_text::normalize(code.ja_name()) == name)
+            .cloned()
+    }
 }
 
 #[cfg(test)]
@@ -309,6 +406,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_ja_name() {
+        assert_eq!(
+            Sector33Code::from_ja_name("情報・通信業"),
+            Some(Sector33Code::InformationCommunication)
+        );
+        assert_eq!(
+            Sector33Code::from_ja_name("情報･通信業"),
+            Some(Sector33Code::InformationCommunication)
+        );
+        assert_eq!(
+            Sector33Code::from_ja_name("その他"),
+            Some(Sector33Code::Other)
+        );
+        assert_eq!(Sector33Code::from_ja_name("製造業"), None);
+    }
+
     #[test]
     fn test_en() {
         assert_eq!(
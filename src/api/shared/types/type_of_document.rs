@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::shared::unknown_variants::deserialize_unknown;
+
 /// Types of documents.
 ///
 /// [See Reference](https://jpx.gitbook.io/j-quants-en/api-reference/statements/typeofdocument)
@@ -268,6 +270,9 @@ pub enum TypeOfDocument {
     REITEarnForecastRevision,
 
     /// Handles unexpected or unknown document types.
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "deserialize_unknown::<_, TypeOfDocument>"
+    )]
     Unknown(String),
 }
@@ -2,13 +2,38 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::issue_code::IssueCode;
+
 /// Underlying SSO type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum UnderlyingSSO {
     /// "-": Other.
     #[serde(rename = "-")]
     Other,
-    /// Issue codes of security options.
+    /// Issue code of the underlying security of securities options.
     #[serde(untagged)]
-    IssueCode,
+    IssueCode(IssueCode),
+}
+
+impl From<IssueCode> for UnderlyingSSO {
+    fn from(code: IssueCode) -> Self {
+        Self::IssueCode(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_underlying_sso_deserialize() {
+        let other: UnderlyingSSO = serde_json::from_str(r#""-""#).unwrap();
+        assert_eq!(other, UnderlyingSSO::Other);
+
+        let code: UnderlyingSSO = serde_json::from_str(r#""72030""#).unwrap();
+        assert_eq!(
+            code,
+            UnderlyingSSO::IssueCode(IssueCode::try_from("72030").unwrap())
+        );
+    }
 }
@@ -0,0 +1,340 @@
+//! Typed views of XBRL items of financial statement details.
+//!
+//! The financial statement details API returns XBRL items keyed by English labels,
+//! which differ by the accounting standard (e.g. "Net sales", "Revenue (IFRS)" or
+//! "Revenues (US GAAP)"). The views below resolve the label variants of JGAAP, IFRS and
+//! US GAAP into normalized fields. Items that are not covered are still available in the raw map.
+
+use jquants_api_client_derive::ResponseAccessors;
+use std::collections::HashMap;
+
+use super::decimal::ResponseDecimal;
+
+/// Finds the first label that exists in the map and has a numeric value.
+fn find(map: &HashMap<String, String>, labels: &[&str]) -> Option<ResponseDecimal> {
+    labels
+        .iter()
+        .filter_map(|label| map.get(*label))
+        .find_map(|value| value.trim().parse().ok())
+}
+
+/// Income statement items resolved from the XBRL labels.
+#[derive(Debug, Clone, Default, PartialEq, ResponseAccessors)]
+pub struct IncomeStatement {
+    /// Net sales or revenue
+    pub net_sales: Option<ResponseDecimal>,
+    /// Operating profit
+    pub operating_profit: Option<ResponseDecimal>,
+    /// Ordinary profit (JGAAP only)
+    pub ordinary_profit: Option<ResponseDecimal>,
+    /// Profit before income taxes
+    pub profit_before_tax: Option<ResponseDecimal>,
+    /// Profit
+    pub profit: Option<ResponseDecimal>,
+    /// Profit attributable to owners of parent
+    pub profit_attributable_to_owners_of_parent: Option<ResponseDecimal>,
+    /// Basic earnings per share
+    pub basic_earnings_per_share: Option<ResponseDecimal>,
+    /// Diluted earnings per share
+    pub diluted_earnings_per_share: Option<ResponseDecimal>,
+}
+
+impl IncomeStatement {
+    /// Resolve the income statement items from the raw XBRL map.
+    pub fn from_map(map: &HashMap<String, String>) -> Self {
+        Self {
+            net_sales: find(
+                map,
+                &[
+                    "Net sales",
+                    "Operating revenue",
+                    "Revenue (IFRS)",
+                    "Net sales (IFRS)",
+                    "Revenues (US GAAP)",
+                    "Net sales (US GAAP)",
+                ],
+            ),
+            operating_profit: find(
+                map,
+                &[
+                    "Operating profit (loss)",
+                    "Operating income",
+                    "Operating profit (loss) (IFRS)",
+                    "Operating income (loss) (US GAAP)",
+                ],
+            ),
+            ordinary_profit: find(map, &["Ordinary profit (loss)", "Ordinary income"]),
+            profit_before_tax: find(
+                map,
+                &[
+                    "Profit (loss) before income taxes",
+                    "Income before income taxes",
+                    "Profit (loss) before tax from continuing operations (IFRS)",
+                    "Profit (loss) before tax (IFRS)",
+                    "Income (loss) before income taxes (US GAAP)",
+                ],
+            ),
+            profit: find(
+                map,
+                &[
+                    "Profit (loss)",
+                    "Net income",
+                    "Profit (loss) (IFRS)",
+                    "Net income (loss) (US GAAP)",
+                ],
+            ),
+            profit_attributable_to_owners_of_parent: find(
+                map,
+                &[
+                    "Profit (loss) attributable to owners of parent",
+                    "Net income attributable to owners of parent",
+                    "Profit (loss) attributable to owners of parent (IFRS)",
+                    "Net income (loss) attributable to owners of parent (US GAAP)",
+                ],
+            ),
+            basic_earnings_per_share: find(
+                map,
+                &[
+                    "Basic earnings (loss) per share",
+                    "Net income per share",
+                    "Basic earnings (loss) per share (IFRS)",
+                    "Basic earnings (loss) per share (US GAAP)",
+                ],
+            ),
+            diluted_earnings_per_share: find(
+                map,
+                &[
+                    "Diluted earnings per share",
+                    "Diluted earnings (loss) per share (IFRS)",
+                    "Diluted earnings (loss) per share (US GAAP)",
+                ],
+            ),
+        }
+    }
+}
+
+/// Balance sheet items resolved from the XBRL labels.
+#[derive(Debug, Clone, Default, PartialEq, ResponseAccessors)]
+pub struct BalanceSheet {
+    /// Total assets
+    pub total_assets: Option<ResponseDecimal>,
+    /// Current assets
+    pub current_assets: Option<ResponseDecimal>,
+    /// Non-current assets
+    pub non_current_assets: Option<ResponseDecimal>,
+    /// Total liabilities
+    pub total_liabilities: Option<ResponseDecimal>,
+    /// Current liabilities
+    pub current_liabilities: Option<ResponseDecimal>,
+    /// Non-current liabilities
+    pub non_current_liabilities: Option<ResponseDecimal>,
+    /// Equity (net assets)
+    pub equity: Option<ResponseDecimal>,
+    /// Equity attributable to owners of parent (IFRS and US GAAP)
+    pub equity_attributable_to_owners_of_parent: Option<ResponseDecimal>,
+    /// Shareholders' equity (JGAAP)
+    ///
+    /// Excludes accumulated other comprehensive income,
+    /// so it is not comparable with the equity attributable to owners of parent.
+    pub shareholders_equity: Option<ResponseDecimal>,
+}
+
+impl BalanceSheet {
+    /// Resolve the balance sheet items from the raw XBRL map.
+    pub fn from_map(map: &HashMap<String, String>) -> Self {
+        Self {
+            total_assets: find(
+                map,
+                &[
+                    "Assets",
+                    "Total assets",
+                    "Assets (IFRS)",
+                    "Total assets (US GAAP)",
+                ],
+            ),
+            current_assets: find(
+                map,
+                &[
+                    "Current assets",
+                    "Current assets (IFRS)",
+                    "Current assets (US GAAP)",
+                ],
+            ),
+            non_current_assets: find(
+                map,
+                &[
+                    "Non-current assets",
+                    "Non-current assets (IFRS)",
+                    "Non-current assets (US GAAP)",
+                ],
+            ),
+            total_liabilities: find(
+                map,
+                &[
+                    "Liabilities",
+                    "Total liabilities",
+                    "Liabilities (IFRS)",
+                    "Total liabilities (US GAAP)",
+                ],
+            ),
+            current_liabilities: find(
+                map,
+                &[
+                    "Current liabilities",
+                    "Current liabilities (IFRS)",
+                    "Current liabilities (US GAAP)",
+                ],
+            ),
+            non_current_liabilities: find(
+                map,
+                &[
+                    "Non-current liabilities",
+                    "Non-current liabilities (IFRS)",
+                    "Non-current liabilities (US GAAP)",
+                ],
+            ),
+            equity: find(
+                map,
+                &[
+                    "Net assets",
+                    "Equity (IFRS)",
+                    "Equity (US GAAP)",
+                    "Total equity (US GAAP)",
+                ],
+            ),
+            equity_attributable_to_owners_of_parent: find(
+                map,
+                &[
+                    "Equity attributable to owners of parent (IFRS)",
+                    "Equity attributable to owners of parent (US GAAP)",
+                ],
+            ),
+            shareholders_equity: find(map, &["Shareholders' equity"]),
+        }
+    }
+}
+
+/// Cash flow statement items resolved from the XBRL labels.
+#[derive(Debug, Clone, Default, PartialEq, ResponseAccessors)]
+pub struct CashFlowStatement {
+    /// Net cash provided by (used in) operating activities
+    pub operating_cash_flow: Option<ResponseDecimal>,
+    /// Net cash provided by (used in) investing activities
+    pub investing_cash_flow: Option<ResponseDecimal>,
+    /// Net cash provided by (used in) financing activities
+    pub financing_cash_flow: Option<ResponseDecimal>,
+    /// Cash and cash equivalents at the end of the period
+    pub cash_and_cash_equivalents: Option<ResponseDecimal>,
+}
+
+impl CashFlowStatement {
+    /// Resolve the cash flow statement items from the raw XBRL map.
+    pub fn from_map(map: &HashMap<String, String>) -> Self {
+        Self {
+            operating_cash_flow: find(
+                map,
+                &[
+                    "Net cash provided by (used in) operating activities",
+                    "Net cash provided by (used in) operating activities (IFRS)",
+                    "Net cash provided by (used in) operating activities (US GAAP)",
+                ],
+            ),
+            investing_cash_flow: find(
+                map,
+                &[
+                    "Net cash provided by (used in) investing activities",
+                    "Net cash provided by (used in) investing activities (IFRS)",
+                    "Net cash provided by (used in) investing activities (US GAAP)",
+                ],
+            ),
+            financing_cash_flow: find(
+                map,
+                &[
+                    "Net cash provided by (used in) financing activities",
+                    "Net cash provided by (used in) financing activities (IFRS)",
+                    "Net cash provided by (used in) financing activities (US GAAP)",
+                ],
+            ),
+            cash_and_cash_equivalents: find(
+                map,
+                &[
+                    "Cash and cash equivalents",
+                    "Cash and cash equivalents (IFRS)",
+                    "Cash and cash equivalents (US GAAP)",
+                ],
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_resolve_label_variants() {
+        let jgaap = map(&[
+            ("Net sales", "1000"),
+            ("Operating profit (loss)", "100"),
+            ("Ordinary profit (loss)", "110"),
+            ("Total assets", "9000"),
+            ("Shareholders' equity", "4500"),
+            ("Net assets", "5000"),
+        ]);
+        let ifrs = map(&[
+            ("Revenue (IFRS)", "1000"),
+            ("Operating profit (loss) (IFRS)", "100.0"),
+            ("Equity (IFRS)", "5000"),
+            ("Equity attributable to owners of parent (IFRS)", "4800"),
+        ]);
+        let us_gaap = map(&[
+            ("Revenues (US GAAP)", "1000"),
+            ("Operating income (loss) (US GAAP)", "100"),
+            ("Equity (US GAAP)", "5000"),
+        ]);
+
+        for map in [&jgaap, &ifrs, &us_gaap] {
+            let income_statement = IncomeStatement::from_map(map);
+            assert_eq!(income_statement.net_sales, "1000".parse().ok());
+            assert_eq!(income_statement.operating_profit, "100".parse().ok());
+            assert_eq!(BalanceSheet::from_map(map).equity, "5000".parse().ok());
+        }
+        assert_eq!(
+            IncomeStatement::from_map(&jgaap).ordinary_profit,
+            "110".parse().ok()
+        );
+        assert_eq!(IncomeStatement::from_map(&ifrs).ordinary_profit, None);
+
+        let balance_sheet = BalanceSheet::from_map(&jgaap);
+        assert_eq!(balance_sheet.total_assets, "9000".parse().ok());
+        assert_eq!(balance_sheet.shareholders_equity, "4500".parse().ok());
+        assert_eq!(balance_sheet.equity_attributable_to_owners_of_parent, None);
+
+        let balance_sheet = BalanceSheet::from_map(&ifrs);
+        assert_eq!(
+            balance_sheet.equity_attributable_to_owners_of_parent,
+            "4800".parse().ok()
+        );
+        assert_eq!(balance_sheet.shareholders_equity, None);
+    }
+
+    #[test]
+    fn test_skip_non_numeric_values() {
+        let map = map(&[("Net sales", "-"), ("Revenue (IFRS)", "1000")]);
+        assert_eq!(
+            IncomeStatement::from_map(&map).net_sales,
+            "1000".parse().ok()
+        );
+        assert_eq!(
+            CashFlowStatement::from_map(&map),
+            CashFlowStatement::default()
+        );
+    }
+}
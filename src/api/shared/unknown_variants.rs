@@ -0,0 +1,160 @@
+//! Unknown variants of enums encountered while deserializing responses.
+//!
+//! Enums of codes have an `Unknown(String)` variant, so new codes of the API don't break
+//! deserialization. [`UnknownVariantMode`] decides whether those values are accepted silently,
+//! rejected, or collected into an [`UnknownVariantReport`].
+
+use std::{cell::RefCell, collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Deserializer};
+
+/// How unknown variants of enums are handled when deserializing responses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum UnknownVariantMode {
+    /// Unknown variants are deserialized into `Unknown(String)` silently.
+    #[default]
+    Lenient,
+    /// Responses containing unknown variants fail with [`crate::JQuantsError::UnknownVariants`].
+    Strict,
+    /// Unknown variants are deserialized into `Unknown(String)` and collected into the report of the client.
+    Diagnostics,
+}
+
+/// An unknown variant of an enum.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnknownVariant {
+    /// Name of the enum (e.g. "HolidayDivision").
+    pub type_name: &'static str,
+    /// Value of the API (e.g. "4").
+    pub value: String,
+}
+
+impl fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({:?})", self.type_name, self.value)
+    }
+}
+
+/// Report of unknown variants with the number of occurrences.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnknownVariantReport {
+    variants: BTreeMap<UnknownVariant, usize>,
+}
+
+impl UnknownVariantReport {
+    /// Returns `true` if no unknown variant is reported.
+    pub fn is_empty(&self) -> bool {
+        self.variants.is_empty()
+    }
+
+    /// Iterate over the unknown variants and their number of occurrences.
+    pub fn iter(&self) -> impl Iterator<Item = (&UnknownVariant, usize)> {
+        self.variants
+            .iter()
+            .map(|(variant, count)| (variant, *count))
+    }
+
+    /// Get the number of occurrences of a value of an enum.
+    pub fn count(&self, type_name: &str, value: &str) -> usize {
+        self.iter()
+            .find(|(variant, _)| variant.type_name == type_name && variant.value == value)
+            .map_or(0, |(_, count)| count)
+    }
+
+    /// Merge another report into this report.
+    pub fn merge(&mut self, other: UnknownVariantReport) {
+        for (variant, count) in other.variants {
+            *self.variants.entry(variant).or_default() += count;
+        }
+    }
+}
+
+impl fmt::Display for UnknownVariantReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (variant, count)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{variant} x{count}")?;
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    static COLLECTED: RefCell<Option<UnknownVariantReport>> = const { RefCell::new(None) };
+}
+
+/// Record an unknown variant of `T` if unknown variants are being collected on this thread.
+pub(crate) fn record<T>(value: &str) {
+    COLLECTED.with(|collected| {
+        if let Some(report) = collected.borrow_mut().as_mut() {
+            let type_name = std::any::type_name::<T>();
+            let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+            *report
+                .variants
+                .entry(UnknownVariant {
+                    type_name,
+                    value: value.to_string(),
+                })
+                .or_default() += 1;
+        }
+    });
+}
+
+/// Deserialize the string of an `Unknown(String)` variant of `T` and record it.
+pub(crate) fn deserialize_unknown<'de, D, T>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    record::<T>(&value);
+    Ok(value)
+}
+
+/// Run `f` while collecting the unknown variants recorded on this thread.
+///
+/// `f` must not yield to other tasks, e.g. it must be a synchronous deserialization.
+pub(crate) fn collect<R>(f: impl FnOnce() -> R) -> (R, UnknownVariantReport) {
+    let previous = COLLECTED.with(|collected| {
+        collected
+            .borrow_mut()
+            .replace(UnknownVariantReport::default())
+    });
+    let result = f();
+    let report = COLLECTED.with(|collected| {
+        std::mem::replace(&mut *collected.borrow_mut(), previous).unwrap_or_default()
+    });
+    (result, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HolidayDivision, OptionsCode};
+
+    #[test]
+    fn test_collect_unknown_variants() {
+        let (divisions, report) = collect(|| {
+            serde_json::from_str::<Vec<HolidayDivision>>(r#"["1", "4", "4", "5"]"#).unwrap()
+        });
+        assert_eq!(divisions[1], HolidayDivision::Unknown("4".to_string()));
+        assert_eq!(report.count("HolidayDivision", "4"), 2);
+        assert_eq!(report.count("HolidayDivision", "5"), 1);
+        assert_eq!(report.count("HolidayDivision", "1"), 0);
+        assert_eq!(
+            report.to_string(),
+            r#"HolidayDivision("4") x2, HolidayDivision("5") x1"#
+        );
+
+        let (_, report) = collect(|| serde_json::from_str::<OptionsCode>(r#""NEWOP""#).unwrap());
+        assert_eq!(report.count("OptionsCode", "NEWOP"), 1);
+    }
+
+    #[test]
+    fn test_not_recorded_outside_collect() {
+        serde_json::from_str::<HolidayDivision>(r#""4""#).unwrap();
+        let (_, report) = collect(|| ());
+        assert!(report.is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+//! Client-side validation of query parameter combinations.
+//!
+//! The checks mirror the constraints in the API reference, so a conflicting builder
+//! fails with [`JQuantsError::InvalidParameters`] before any request is sent.
+
+use crate::JQuantsError;
+
+use super::types::query_date::QueryDate;
+
+/// Require at least one of the named parameters to be set.
+///
+/// `params` is a list of the parameter name and whether it is set.
+pub(crate) fn require_any(params: &[(&str, bool)]) -> Result<(), JQuantsError> {
+    if params.iter().any(|(_, is_set)| *is_set) {
+        return Ok(());
+    }
+
+    let names = params
+        .iter()
+        .map(|(name, _)| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(" or ");
+    Err(JQuantsError::InvalidParameters(format!(
+        "{names} is required"
+    )))
+}
+
+/// Reject `date` combined with `from`/`to`, because the server ignores `date` in that case.
+pub(crate) fn date_excludes_range(
+    date: Option<&QueryDate>,
+    from: Option<&QueryDate>,
+    to: Option<&QueryDate>,
+) -> Result<(), JQuantsError> {
+    if date.is_some() && (from.is_some() || to.is_some()) {
+        return Err(JQuantsError::InvalidParameters(
+            "`date` cannot be combined with `from`/`to`".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reject `from` after `to`.
+///
+/// Invalid dates are skipped here and reported as [`JQuantsError::InvalidQueryParameter`].
+pub(crate) fn range_is_ordered(
+    from: Option<&QueryDate>,
+    to: Option<&QueryDate>,
+) -> Result<(), JQuantsError> {
+    let (Some(from), Some(to)) = (from.and_then(QueryDate::date), to.and_then(QueryDate::date))
+    else {
+        return Ok(());
+    };
+
+    if from > to {
+        return Err(JQuantsError::InvalidParameters(format!(
+            "`from` ({from}) is after `to` ({to})"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoQueryDate;
+
+    use super::*;
+
+    #[test]
+    fn test_require_any() {
+        assert!(require_any(&[("code", true), ("date", false)]).is_ok());
+
+        match require_any(&[("code", false), ("date", false)]) {
+            Err(JQuantsError::InvalidParameters(message)) => {
+                assert_eq!(message, "`code` or `date` is required")
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_date_excludes_range() {
+        let date = "2024-08-01".into_query_date();
+        assert!(date_excludes_range(Some(&date), None, None).is_ok());
+        assert!(date_excludes_range(None, Some(&date), Some(&date)).is_ok());
+        assert!(matches!(
+            date_excludes_range(Some(&date), Some(&date), None),
+            Err(JQuantsError::InvalidParameters(_))
+        ));
+        assert!(matches!(
+            date_excludes_range(Some(&date), None, Some(&date)),
+            Err(JQuantsError::InvalidParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_range_is_ordered() {
+        let from = "2024-08-01".into_query_date();
+        let to = "2024-08-31".into_query_date();
+        let invalid = "2024-13-01".into_query_date();
+        assert!(range_is_ordered(Some(&from), Some(&to)).is_ok());
+        assert!(range_is_ordered(Some(&from), Some(&from)).is_ok());
+        assert!(range_is_ordered(Some(&from), None).is_ok());
+        assert!(range_is_ordered(Some(&invalid), Some(&from)).is_ok());
+
+        match range_is_ordered(Some(&to), Some(&from)) {
+            Err(JQuantsError::InvalidParameters(message)) => {
+                assert_eq!(message, "`from` (2024-08-31) is after `to` (2024-08-01)")
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
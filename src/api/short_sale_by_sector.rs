@@ -1,16 +1,29 @@
 //! Short Sale Value and Ratio by Sector API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use crate::Sector33Code;
 
 use super::{
-    shared::traits::{
-        builder::JQuantsBuilder,
-        pagination::{HasPaginationKey, MergePage, Paginatable},
+    shared::{
+        traits::{
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            date_range::DateRangeShardable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
+        },
+        types::{
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
+            query_date::{IntoQueryDate, QueryDate},
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Short Sale Value and Ratio by Sector API.
 #[derive(Clone, Serialize)]
@@ -23,26 +36,50 @@ pub struct ShortSaleBySectorBuilder {
     sector33code: Option<Sector33Code>,
     /// Starting point of data period (e.g. "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g. "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
     /// Date of data (e.g. "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for ShortSaleBySectorBuilder {
+    const PATH: &'static str = "markets/short_selling";
+}
+
 impl JQuantsBuilder<ShortSaleBySectorResponse> for ShortSaleBySectorBuilder {
     async fn send(self) -> Result<ShortSaleBySectorResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[
+            ("sector33code", self.sector33code.is_some()),
+            ("date", self.date.is_some()),
+        ])?;
+        validation::date_excludes_range(self.date.as_ref(), self.from.as_ref(), self.to.as_ref())?;
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<ShortSaleBySectorResponse, crate::JQuantsError> {
-        self.client.inner.get("markets/short_selling", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<ShortSaleBySectorResponse> for ShortSaleBySectorBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<ShortSaleBySectorResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -53,6 +90,14 @@ impl Paginatable<ShortSaleBySectorResponse> for ShortSaleBySectorBuilder {
     }
 }
 
+impl DateRangeShardable<ShortSaleBySectorResponse> for ShortSaleBySectorBuilder {
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
 impl ShortSaleBySectorBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -73,20 +118,20 @@ impl ShortSaleBySectorBuilder {
     }
 
     /// Set starting point of data period (e.g. "20210901" or "2021-09-01")
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g. "20210907" or "2021-09-07")
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 
     /// Set date of data (e.g. "20210907" or "2021-09-07")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
@@ -104,41 +149,22 @@ pub trait ShortSaleBySectorApi: JQuantsPlanClient {
 /// Short Sale Value and Ratio by Sector response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/short_selling)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct ShortSaleBySectorResponse {
     /// List of short selling data
+    #[paginated(items)]
     pub short_selling: Vec<ShortSaleBySectorItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for ShortSaleBySectorResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for ShortSaleBySectorResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.short_selling.extend(p.short_selling);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single short selling data item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct ShortSaleBySectorItem {
     /// Date (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// 33-sector code
     #[serde(rename = "Sector33Code")]
@@ -146,20 +172,26 @@ pub struct ShortSaleBySectorItem {
 
     /// Trading value of long selling
     #[serde(rename = "SellingExcludingShortSellingTurnoverValue")]
-    pub selling_excluding_short_selling_turnover_value: f64,
+    pub selling_excluding_short_selling_turnover_value: ResponseDecimal,
 
     /// Value of short sales with price restrictions
     #[serde(rename = "ShortSellingWithRestrictionsTurnoverValue")]
-    pub short_selling_with_restrictions_turnover_value: f64,
+    pub short_selling_with_restrictions_turnover_value: ResponseDecimal,
 
     /// Value of short sales without price restrictions
     #[serde(rename = "ShortSellingWithoutRestrictionsTurnoverValue")]
-    pub short_selling_without_restrictions_turnover_value: f64,
+    pub short_selling_without_restrictions_turnover_value: ResponseDecimal,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_short_sale_by_sector_response() {
@@ -179,13 +211,17 @@ mod tests {
         "#;
 
         let response: ShortSaleBySectorResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = ShortSaleBySectorResponse {
             short_selling: vec![ShortSaleBySectorItem {
-                date: "2022-10-25".to_string(),
+                date: "2022-10-25".parse().unwrap(),
                 sector33code: Sector33Code::FisheryAgricultureForestry,
-                selling_excluding_short_selling_turnover_value: 1333126400.0,
-                short_selling_with_restrictions_turnover_value: 787355200.0,
-                short_selling_without_restrictions_turnover_value: 149084300.0,
+                selling_excluding_short_selling_turnover_value: "1333126400.0".parse().unwrap(),
+                short_selling_with_restrictions_turnover_value: "787355200.0".parse().unwrap(),
+                short_selling_without_restrictions_turnover_value: "149084300.0".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -210,13 +246,17 @@ mod tests {
         "#;
 
         let response: ShortSaleBySectorResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = ShortSaleBySectorResponse {
             short_selling: vec![ShortSaleBySectorItem {
-                date: "2022-10-25".to_string(),
+                date: "2022-10-25".parse().unwrap(),
                 sector33code: Sector33Code::FisheryAgricultureForestry,
-                selling_excluding_short_selling_turnover_value: 1333126400.0,
-                short_selling_with_restrictions_turnover_value: 787355200.0,
-                short_selling_without_restrictions_turnover_value: 149084300.0,
+                selling_excluding_short_selling_turnover_value: "1333126400.0".parse().unwrap(),
+                short_selling_with_restrictions_turnover_value: "787355200.0".parse().unwrap(),
+                short_selling_without_restrictions_turnover_value: "149084300.0".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -249,21 +289,31 @@ mod tests {
         "#;
 
         let response: ShortSaleBySectorResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = ShortSaleBySectorResponse {
             short_selling: vec![
                 ShortSaleBySectorItem {
-                    date: "2022-10-18".to_string(),
+                    date: "2022-10-18".parse().unwrap(),
                     sector33code: Sector33Code::FisheryAgricultureForestry,
-                    selling_excluding_short_selling_turnover_value: 1300000000.0,
-                    short_selling_with_restrictions_turnover_value: 780000000.0,
-                    short_selling_without_restrictions_turnover_value: 150000000.0,
+                    selling_excluding_short_selling_turnover_value: "1300000000.0".parse().unwrap(),
+                    short_selling_with_restrictions_turnover_value: "780000000.0".parse().unwrap(),
+                    short_selling_without_restrictions_turnover_value: "150000000.0"
+                        .parse()
+                        .unwrap(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 ShortSaleBySectorItem {
-                    date: "2022-10-25".to_string(),
+                    date: "2022-10-25".parse().unwrap(),
                     sector33code: Sector33Code::FisheryAgricultureForestry,
-                    selling_excluding_short_selling_turnover_value: 1333126400.0,
-                    short_selling_with_restrictions_turnover_value: 787355200.0,
-                    short_selling_without_restrictions_turnover_value: 149084300.0,
+                    selling_excluding_short_selling_turnover_value: "1333126400.0".parse().unwrap(),
+                    short_selling_with_restrictions_turnover_value: "787355200.0".parse().unwrap(),
+                    short_selling_without_restrictions_turnover_value: "149084300.0"
+                        .parse()
+                        .unwrap(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value1.value2.".to_string()),
@@ -281,6 +331,8 @@ mod tests {
         "#;
 
         let response: ShortSaleBySectorResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = ShortSaleBySectorResponse {
             short_selling: vec![],
             pagination_key: None,
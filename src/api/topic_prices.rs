@@ -1,14 +1,27 @@
 //! TOPIX Prices (OHLC) API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    shared::traits::{
-        builder::JQuantsBuilder,
-        pagination::{HasPaginationKey, MergePage, Paginatable},
+    shared::{
+        traits::{
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            date_range::DateRangeShardable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
+        },
+        types::{
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
+            query_date::{IntoQueryDate, QueryDate},
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for TOPIX Prices (OHLC) API.
 #[derive(Clone, Serialize)]
@@ -18,26 +31,46 @@ pub struct TopixPricesBuilder {
 
     /// Starting point of data period (e.g., "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g., "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
     /// Date of data (e.g., "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for TopixPricesBuilder {
+    const PATH: &'static str = "indices/topix";
+}
+
 impl JQuantsBuilder<TopixPricesResponse> for TopixPricesBuilder {
     async fn send(self) -> Result<TopixPricesResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::date_excludes_range(self.date.as_ref(), self.from.as_ref(), self.to.as_ref())?;
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<TopixPricesResponse, crate::JQuantsError> {
-        self.client.inner.get("indices/topix", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<TopixPricesResponse> for TopixPricesBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<TopixPricesResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -48,6 +81,14 @@ impl Paginatable<TopixPricesResponse> for TopixPricesBuilder {
     }
 }
 
+impl DateRangeShardable<TopixPricesResponse> for TopixPricesBuilder {
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
 impl TopixPricesBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -61,20 +102,20 @@ impl TopixPricesBuilder {
     }
 
     /// Set starting point of data period (e.g., "20210901" or "2021-09-01")
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g., "20210907" or "2021-09-07")
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 
     /// Set date of data (e.g., "20210907" or "2021-09-07")
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 }
@@ -92,62 +133,49 @@ pub trait TopixPricesApi: JQuantsPlanClient {
 /// TOPIX Prices (OHLC) response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/topix)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct TopixPricesResponse {
     /// List of TOPIX prices data
+    #[paginated(items)]
     pub topix: Vec<TopixPriceItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for TopixPricesResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for TopixPricesResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.topix.extend(p.topix);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single TOPIX price (OHLC) data item.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct TopixPriceItem {
     /// Trade date (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Open Price
     #[serde(rename = "Open")]
-    pub open: f64,
+    pub open: ResponseDecimal,
 
     /// High Price
     #[serde(rename = "High")]
-    pub high: f64,
+    pub high: ResponseDecimal,
 
     /// Low Price
     #[serde(rename = "Low")]
-    pub low: f64,
+    pub low: ResponseDecimal,
 
     /// Close Price
     #[serde(rename = "Close")]
-    pub close: f64,
+    pub close: ResponseDecimal,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_topix_prices_response() {
@@ -167,13 +195,17 @@ mod tests {
         "#;
 
         let response: TopixPricesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TopixPricesResponse {
             topix: vec![TopixPriceItem {
-                date: "2022-06-28".to_string(),
-                open: 1885.52,
-                high: 1907.38,
-                low: 1885.32,
-                close: 1907.38,
+                date: "2022-06-28".parse().unwrap(),
+                open: "1885.52".parse().unwrap(),
+                high: "1907.38".parse().unwrap(),
+                low: "1885.32".parse().unwrap(),
+                close: "1907.38".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -198,13 +230,17 @@ mod tests {
         "#;
 
         let response: TopixPricesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TopixPricesResponse {
             topix: vec![TopixPriceItem {
-                date: "2022-06-28".to_string(),
-                open: 1885.52,
-                high: 1907.38,
-                low: 1885.32,
-                close: 1907.38,
+                date: "2022-06-28".parse().unwrap(),
+                open: "1885.52".parse().unwrap(),
+                high: "1907.38".parse().unwrap(),
+                low: "1885.32".parse().unwrap(),
+                close: "1907.38".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -237,21 +273,27 @@ mod tests {
         "#;
 
         let response: TopixPricesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TopixPricesResponse {
             topix: vec![
                 TopixPriceItem {
-                    date: "2022-06-27".to_string(),
-                    open: 1850.50,
-                    high: 1875.75,
-                    low: 1845.00,
-                    close: 1860.25,
+                    date: "2022-06-27".parse().unwrap(),
+                    open: "1850.50".parse().unwrap(),
+                    high: "1875.75".parse().unwrap(),
+                    low: "1845.00".parse().unwrap(),
+                    close: "1860.25".parse().unwrap(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 TopixPriceItem {
-                    date: "2022-06-28".to_string(),
-                    open: 1885.52,
-                    high: 1907.38,
-                    low: 1885.32,
-                    close: 1907.38,
+                    date: "2022-06-28".parse().unwrap(),
+                    open: "1885.52".parse().unwrap(),
+                    high: "1907.38".parse().unwrap(),
+                    low: "1885.32".parse().unwrap(),
+                    close: "1907.38".parse().unwrap(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value1.value2.".to_string()),
@@ -269,6 +311,8 @@ mod tests {
         "#;
 
         let response: TopixPricesResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TopixPricesResponse {
             topix: vec![],
             pagination_key: None,
@@ -1,17 +1,28 @@
 //! Trading by Type of Investors API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            date_range::DateRangeShardable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
-        types::section_name::SectionName,
+        types::{
+            date_time::ResponseDate,
+            decimal::ResponseDecimal,
+            query_date::{IntoQueryDate, QueryDate},
+            section_name::SectionName,
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Trading by Type of Investors API.
 #[derive(Clone, Serialize)]
@@ -24,23 +35,42 @@ pub struct TradingByInvestorTypeBuilder {
     section: Option<SectionName>,
     /// Starting point of data period (e.g. 20210901 or 2021-09-01)
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g. 20210907 or 2021-09-07)
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for TradingByInvestorTypeBuilder {
+    const PATH: &'static str = "markets/trades_spec";
+}
+
 impl JQuantsBuilder<TradingByInvestorTypeResponse> for TradingByInvestorTypeBuilder {
     async fn send(self) -> Result<TradingByInvestorTypeResponse, crate::JQuantsError> {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<TradingByInvestorTypeResponse, crate::JQuantsError> {
-        self.client.inner.get("markets/trades_spec", self).await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<TradingByInvestorTypeResponse> for TradingByInvestorTypeBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<TradingByInvestorTypeResponse, crate::JQuantsError>>
+           + Send {
+        self.send_ref()
     }
 }
 
@@ -51,6 +81,14 @@ impl Paginatable<TradingByInvestorTypeResponse> for TradingByInvestorTypeBuilder
     }
 }
 
+impl DateRangeShardable<TradingByInvestorTypeResponse> for TradingByInvestorTypeBuilder {
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
 impl TradingByInvestorTypeBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -70,14 +108,14 @@ impl TradingByInvestorTypeBuilder {
     }
 
     /// Set starting point of data period (e.g. 20210901 or 2021-09-01)
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g. 20210907 or 2021-09-07)
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 }
@@ -95,49 +133,30 @@ pub trait TradingByInvestorTypeApi: JQuantsPlanClient {
 /// Trading by Type of Investors response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/trades_spec)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct TradingByInvestorTypeResponse {
     /// List of trades specifications
+    #[paginated(items)]
     pub trades_spec: Vec<TradingByInvestorTypeItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for TradingByInvestorTypeResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for TradingByInvestorTypeResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged.trades_spec.extend(p.trades_spec);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Trades Specification.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct TradingByInvestorTypeItem {
     /// Published Date (YY-MM-DD)
     #[serde(rename = "PublishedDate")]
-    pub published_date: String,
+    pub published_date: ResponseDate,
 
     /// Start Date (YY-MM-DD)
     #[serde(rename = "StartDate")]
-    pub start_date: String,
+    pub start_date: ResponseDate,
 
     /// End Date (YY-MM-DD)
     #[serde(rename = "EndDate")]
-    pub end_date: String,
+    pub end_date: ResponseDate,
 
     /// Section Name
     #[serde(rename = "Section")]
@@ -145,216 +164,222 @@ pub struct TradingByInvestorTypeItem {
 
     /// Proprietary Sales Value
     #[serde(rename = "ProprietarySales")]
-    pub proprietary_sales: f64,
+    pub proprietary_sales: ResponseDecimal,
 
     /// Proprietary Purchase Value
     #[serde(rename = "ProprietaryPurchases")]
-    pub proprietary_purchases: f64,
+    pub proprietary_purchases: ResponseDecimal,
 
     /// Proprietary Total Value
     #[serde(rename = "ProprietaryTotal")]
-    pub proprietary_total: f64,
+    pub proprietary_total: ResponseDecimal,
 
     /// Proprietary Balance Value
     #[serde(rename = "ProprietaryBalance")]
-    pub proprietary_balance: f64,
+    pub proprietary_balance: ResponseDecimal,
 
     /// Brokerage Sales Value
     #[serde(rename = "BrokerageSales")]
-    pub brokerage_sales: f64,
+    pub brokerage_sales: ResponseDecimal,
 
     /// Brokerage Purchase Value
     #[serde(rename = "BrokeragePurchases")]
-    pub brokerage_purchases: f64,
+    pub brokerage_purchases: ResponseDecimal,
 
     /// Brokerage Total Value
     #[serde(rename = "BrokerageTotal")]
-    pub brokerage_total: f64,
+    pub brokerage_total: ResponseDecimal,
 
     /// Brokerage Balance Value
     #[serde(rename = "BrokerageBalance")]
-    pub brokerage_balance: f64,
+    pub brokerage_balance: ResponseDecimal,
 
     /// Total Sales Value
     #[serde(rename = "TotalSales")]
-    pub total_sales: f64,
+    pub total_sales: ResponseDecimal,
 
     /// Total Purchase Value
     #[serde(rename = "TotalPurchases")]
-    pub total_purchases: f64,
+    pub total_purchases: ResponseDecimal,
 
     /// Total Value
     #[serde(rename = "TotalTotal")]
-    pub total_total: f64,
+    pub total_total: ResponseDecimal,
 
     /// Total Balance Value
     #[serde(rename = "TotalBalance")]
-    pub total_balance: f64,
+    pub total_balance: ResponseDecimal,
 
     /// Individuals Sales Value
     #[serde(rename = "IndividualsSales")]
-    pub individuals_sales: f64,
+    pub individuals_sales: ResponseDecimal,
 
     /// Individuals Purchase Value
     #[serde(rename = "IndividualsPurchases")]
-    pub individuals_purchases: f64,
+    pub individuals_purchases: ResponseDecimal,
 
     /// Individuals Total Value
     #[serde(rename = "IndividualsTotal")]
-    pub individuals_total: f64,
+    pub individuals_total: ResponseDecimal,
 
     /// Individuals Balance Value
     #[serde(rename = "IndividualsBalance")]
-    pub individuals_balance: f64,
+    pub individuals_balance: ResponseDecimal,
 
     /// Foreigners Sales Value
     #[serde(rename = "ForeignersSales")]
-    pub foreigners_sales: f64,
+    pub foreigners_sales: ResponseDecimal,
 
     /// Foreigners Purchase Value
     #[serde(rename = "ForeignersPurchases")]
-    pub foreigners_purchases: f64,
+    pub foreigners_purchases: ResponseDecimal,
 
     /// Foreigners Total Value
     #[serde(rename = "ForeignersTotal")]
-    pub foreigners_total: f64,
+    pub foreigners_total: ResponseDecimal,
 
     /// Foreigners Balance Value
     #[serde(rename = "ForeignersBalance")]
-    pub foreigners_balance: f64,
+    pub foreigners_balance: ResponseDecimal,
 
     /// Securities Companies Sales Value
     #[serde(rename = "SecuritiesCosSales")]
-    pub securities_cos_sales: f64,
+    pub securities_cos_sales: ResponseDecimal,
 
     /// Securities Companies Purchase Value
     #[serde(rename = "SecuritiesCosPurchases")]
-    pub securities_cos_purchases: f64,
+    pub securities_cos_purchases: ResponseDecimal,
 
     /// Securities Companies Total
     #[serde(rename = "SecuritiesCosTotal")]
-    pub securities_cos_total: f64,
+    pub securities_cos_total: ResponseDecimal,
 
     /// Securities Companies Balance Value
     #[serde(rename = "SecuritiesCosBalance")]
-    pub securities_cos_balance: f64,
+    pub securities_cos_balance: ResponseDecimal,
 
     /// Investment Trusts Sales Value
     #[serde(rename = "InvestmentTrustsSales")]
-    pub investment_trusts_sales: f64,
+    pub investment_trusts_sales: ResponseDecimal,
 
     /// Investment Trusts Purchase Value
     #[serde(rename = "InvestmentTrustsPurchases")]
-    pub investment_trusts_purchases: f64,
+    pub investment_trusts_purchases: ResponseDecimal,
 
     /// Investment Trusts Total Value
     #[serde(rename = "InvestmentTrustsTotal")]
-    pub investment_trusts_total: f64,
+    pub investment_trusts_total: ResponseDecimal,
 
     /// Investment Trusts Balance Value
     #[serde(rename = "InvestmentTrustsBalance")]
-    pub investment_trusts_balance: f64,
+    pub investment_trusts_balance: ResponseDecimal,
 
     /// Business Companies Sales Value
     #[serde(rename = "BusinessCosSales")]
-    pub business_cos_sales: f64,
+    pub business_cos_sales: ResponseDecimal,
 
     /// Business Companies Purchase Value
     #[serde(rename = "BusinessCosPurchases")]
-    pub business_cos_purchases: f64,
+    pub business_cos_purchases: ResponseDecimal,
 
     /// Business Companies Total Value
     #[serde(rename = "BusinessCosTotal")]
-    pub business_cos_total: f64,
+    pub business_cos_total: ResponseDecimal,
 
     /// Business Companies Balance Value
     #[serde(rename = "BusinessCosBalance")]
-    pub business_cos_balance: f64,
+    pub business_cos_balance: ResponseDecimal,
 
     /// Other Companies Sales Value
     #[serde(rename = "OtherCosSales")]
-    pub other_cos_sales: f64,
+    pub other_cos_sales: ResponseDecimal,
 
     /// Other Companies Purchase Value
     #[serde(rename = "OtherCosPurchases")]
-    pub other_cos_purchases: f64,
+    pub other_cos_purchases: ResponseDecimal,
 
     /// Other Companies Total Value
     #[serde(rename = "OtherCosTotal")]
-    pub other_cos_total: f64,
+    pub other_cos_total: ResponseDecimal,
 
     /// Other Companies Balance Value
     #[serde(rename = "OtherCosBalance")]
-    pub other_cos_balance: f64,
+    pub other_cos_balance: ResponseDecimal,
 
     /// Insurance Companies Sales Value
     #[serde(rename = "InsuranceCosSales")]
-    pub insurance_cos_sales: f64,
+    pub insurance_cos_sales: ResponseDecimal,
 
     /// Insurance Companies Purchase Value
     #[serde(rename = "InsuranceCosPurchases")]
-    pub insurance_cos_purchases: f64,
+    pub insurance_cos_purchases: ResponseDecimal,
 
     /// Insurance Companies Total Value
     #[serde(rename = "InsuranceCosTotal")]
-    pub insurance_cos_total: f64,
+    pub insurance_cos_total: ResponseDecimal,
 
     /// Insurance Companies Balance Value
     #[serde(rename = "InsuranceCosBalance")]
-    pub insurance_cos_balance: f64,
+    pub insurance_cos_balance: ResponseDecimal,
 
     /// City Banks Regional Banks Etc Sales Value
     #[serde(rename = "CityBKsRegionalBKsEtcSales")]
-    pub city_bks_regional_bks_etc_sales: f64,
+    pub city_bks_regional_bks_etc_sales: ResponseDecimal,
 
     /// City Banks Regional Banks Etc Purchase Value
     #[serde(rename = "CityBKsRegionalBKsEtcPurchases")]
-    pub city_bks_regional_bks_etc_purchases: f64,
+    pub city_bks_regional_bks_etc_purchases: ResponseDecimal,
 
     /// City Banks Regional Banks Etc Total Value
     #[serde(rename = "CityBKsRegionalBKsEtcTotal")]
-    pub city_bks_regional_bks_etc_total: f64,
+    pub city_bks_regional_bks_etc_total: ResponseDecimal,
 
     /// City Banks Regional Banks Etc Balance Value
     #[serde(rename = "CityBKsRegionalBKsEtcBalance")]
-    pub city_bks_regional_bks_etc_balance: f64,
+    pub city_bks_regional_bks_etc_balance: ResponseDecimal,
 
     /// Trust Banks Sales Value
     #[serde(rename = "TrustBanksSales")]
-    pub trust_banks_sales: f64,
+    pub trust_banks_sales: ResponseDecimal,
 
     /// Trust Banks Purchase Value
     #[serde(rename = "TrustBanksPurchases")]
-    pub trust_banks_purchases: f64,
+    pub trust_banks_purchases: ResponseDecimal,
 
     /// Trust Banks Total Value
     #[serde(rename = "TrustBanksTotal")]
-    pub trust_banks_total: f64,
+    pub trust_banks_total: ResponseDecimal,
 
     /// Trust Banks Balance Value
     #[serde(rename = "TrustBanksBalance")]
-    pub trust_banks_balance: f64,
+    pub trust_banks_balance: ResponseDecimal,
 
     /// Other Financial Institutions Sales Value
     #[serde(rename = "OtherFinancialInstitutionsSales")]
-    pub other_financial_institutions_sales: f64,
+    pub other_financial_institutions_sales: ResponseDecimal,
 
     /// Other Financial Institutions Purchase Value
     #[serde(rename = "OtherFinancialInstitutionsPurchases")]
-    pub other_financial_institutions_purchases: f64,
+    pub other_financial_institutions_purchases: ResponseDecimal,
 
     /// Other Financial Institutions Total Value
     #[serde(rename = "OtherFinancialInstitutionsTotal")]
-    pub other_financial_institutions_total: f64,
+    pub other_financial_institutions_total: ResponseDecimal,
 
     /// Other Financial Institutions Balance Value
     #[serde(rename = "OtherFinancialInstitutionsBalance")]
-    pub other_financial_institutions_balance: f64,
+    pub other_financial_institutions_balance: ResponseDecimal,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_trades_spec_response() {
@@ -425,64 +450,68 @@ mod tests {
         "#;
 
         let response: TradingByInvestorTypeResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TradingByInvestorTypeResponse {
             trades_spec: vec![TradingByInvestorTypeItem {
-                published_date: "2017-01-13".to_string(),
-                start_date: "2017-01-04".to_string(),
-                end_date: "2017-01-06".to_string(),
+                published_date: "2017-01-13".parse().unwrap(),
+                start_date: "2017-01-04".parse().unwrap(),
+                end_date: "2017-01-06".parse().unwrap(),
                 section: SectionName::FirstSection,
-                proprietary_sales: 1311271004.0,
-                proprietary_purchases: 1453326508.0,
-                proprietary_total: 2764597512.0,
-                proprietary_balance: 142055504.0,
-                brokerage_sales: 7165529005.0,
-                brokerage_purchases: 7030019854.0,
-                brokerage_total: 14195548859.0,
-                brokerage_balance: -135509151.0,
-                total_sales: 8476800009.0,
-                total_purchases: 8483346362.0,
-                total_total: 16960146371.0,
-                total_balance: 6546353.0,
-                individuals_sales: 1401711615.0,
-                individuals_purchases: 1161801155.0,
-                individuals_total: 2563512770.0,
-                individuals_balance: -239910460.0,
-                foreigners_sales: 5094891735.0,
-                foreigners_purchases: 5317151774.0,
-                foreigners_total: 10412043509.0,
-                foreigners_balance: 222260039.0,
-                securities_cos_sales: 76381455.0,
-                securities_cos_purchases: 61700100.0,
-                securities_cos_total: 138081555.0,
-                securities_cos_balance: -14681355.0,
-                investment_trusts_sales: 168705109.0,
-                investment_trusts_purchases: 124389642.0,
-                investment_trusts_total: 293094751.0,
-                investment_trusts_balance: -44315467.0,
-                business_cos_sales: 71217959.0,
-                business_cos_purchases: 63526641.0,
-                business_cos_total: 134744600.0,
-                business_cos_balance: -7691318.0,
-                other_cos_sales: 10745152.0,
-                other_cos_purchases: 15687836.0,
-                other_cos_total: 26432988.0,
-                other_cos_balance: 4942684.0,
-                insurance_cos_sales: 15926202.0,
-                insurance_cos_purchases: 9831555.0,
-                insurance_cos_total: 25757757.0,
-                insurance_cos_balance: -6094647.0,
-                city_bks_regional_bks_etc_sales: 10606789.0,
-                city_bks_regional_bks_etc_purchases: 8843871.0,
-                city_bks_regional_bks_etc_total: 19450660.0,
-                city_bks_regional_bks_etc_balance: -1762918.0,
-                trust_banks_sales: 292932297.0,
-                trust_banks_purchases: 245322795.0,
-                trust_banks_total: 538255092.0,
-                trust_banks_balance: -47609502.0,
-                other_financial_institutions_sales: 22410692.0,
-                other_financial_institutions_purchases: 21764485.0,
-                other_financial_institutions_total: 44175177.0,
-                other_financial_institutions_balance: -646207.0,
+                proprietary_sales: "1311271004.0".parse().unwrap(),
+                proprietary_purchases: "1453326508.0".parse().unwrap(),
+                proprietary_total: "2764597512.0".parse().unwrap(),
+                proprietary_balance: "142055504.0".parse().unwrap(),
+                brokerage_sales: "7165529005.0".parse().unwrap(),
+                brokerage_purchases: "7030019854.0".parse().unwrap(),
+                brokerage_total: "14195548859.0".parse().unwrap(),
+                brokerage_balance: "-135509151.0".parse().unwrap(),
+                total_sales: "8476800009.0".parse().unwrap(),
+                total_purchases: "8483346362.0".parse().unwrap(),
+                total_total: "16960146371.0".parse().unwrap(),
+                total_balance: "6546353.0".parse().unwrap(),
+                individuals_sales: "1401711615.0".parse().unwrap(),
+                individuals_purchases: "1161801155.0".parse().unwrap(),
+                individuals_total: "2563512770.0".parse().unwrap(),
+                individuals_balance: "-239910460.0".parse().unwrap(),
+                foreigners_sales: "5094891735.0".parse().unwrap(),
+                foreigners_purchases: "5317151774.0".parse().unwrap(),
+                foreigners_total: "10412043509.0".parse().unwrap(),
+                foreigners_balance: "222260039.0".parse().unwrap(),
+                securities_cos_sales: "76381455.0".parse().unwrap(),
+                securities_cos_purchases: "61700100.0".parse().unwrap(),
+                securities_cos_total: "138081555.0".parse().unwrap(),
+                securities_cos_balance: "-14681355.0".parse().unwrap(),
+                investment_trusts_sales: "168705109.0".parse().unwrap(),
+                investment_trusts_purchases: "124389642.0".parse().unwrap(),
+                investment_trusts_total: "293094751.0".parse().unwrap(),
+                investment_trusts_balance: "-44315467.0".parse().unwrap(),
+                business_cos_sales: "71217959.0".parse().unwrap(),
+                business_cos_purchases: "63526641.0".parse().unwrap(),
+                business_cos_total: "134744600.0".parse().unwrap(),
+                business_cos_balance: "-7691318.0".parse().unwrap(),
+                other_cos_sales: "10745152.0".parse().unwrap(),
+                other_cos_purchases: "15687836.0".parse().unwrap(),
+                other_cos_total: "26432988.0".parse().unwrap(),
+                other_cos_balance: "4942684.0".parse().unwrap(),
+                insurance_cos_sales: "15926202.0".parse().unwrap(),
+                insurance_cos_purchases: "9831555.0".parse().unwrap(),
+                insurance_cos_total: "25757757.0".parse().unwrap(),
+                insurance_cos_balance: "-6094647.0".parse().unwrap(),
+                city_bks_regional_bks_etc_sales: "10606789.0".parse().unwrap(),
+                city_bks_regional_bks_etc_purchases: "8843871.0".parse().unwrap(),
+                city_bks_regional_bks_etc_total: "19450660.0".parse().unwrap(),
+                city_bks_regional_bks_etc_balance: "-1762918.0".parse().unwrap(),
+                trust_banks_sales: "292932297.0".parse().unwrap(),
+                trust_banks_purchases: "245322795.0".parse().unwrap(),
+                trust_banks_total: "538255092.0".parse().unwrap(),
+                trust_banks_balance: "-47609502.0".parse().unwrap(),
+                other_financial_institutions_sales: "22410692.0".parse().unwrap(),
+                other_financial_institutions_purchases: "21764485.0".parse().unwrap(),
+                other_financial_institutions_total: "44175177.0".parse().unwrap(),
+                other_financial_institutions_balance: "-646207.0".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -558,64 +587,68 @@ mod tests {
         "#;
 
         let response: TradingByInvestorTypeResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TradingByInvestorTypeResponse {
             trades_spec: vec![TradingByInvestorTypeItem {
-                published_date: "2017-01-13".to_string(),
-                start_date: "2017-01-04".to_string(),
-                end_date: "2017-01-06".to_string(),
+                published_date: "2017-01-13".parse().unwrap(),
+                start_date: "2017-01-04".parse().unwrap(),
+                end_date: "2017-01-06".parse().unwrap(),
                 section: SectionName::FirstSection,
-                proprietary_sales: 1311271004.0,
-                proprietary_purchases: 1453326508.0,
-                proprietary_total: 2764597512.0,
-                proprietary_balance: 142055504.0,
-                brokerage_sales: 7165529005.0,
-                brokerage_purchases: 7030019854.0,
-                brokerage_total: 14195548859.0,
-                brokerage_balance: -135509151.0,
-                total_sales: 8476800009.0,
-                total_purchases: 8483346362.0,
-                total_total: 16960146371.0,
-                total_balance: 6546353.0,
-                individuals_sales: 1401711615.0,
-                individuals_purchases: 1161801155.0,
-                individuals_total: 2563512770.0,
-                individuals_balance: -239910460.0,
-                foreigners_sales: 5094891735.0,
-                foreigners_purchases: 5317151774.0,
-                foreigners_total: 10412043509.0,
-                foreigners_balance: 222260039.0,
-                securities_cos_sales: 76381455.0,
-                securities_cos_purchases: 61700100.0,
-                securities_cos_total: 138081555.0,
-                securities_cos_balance: -14681355.0,
-                investment_trusts_sales: 168705109.0,
-                investment_trusts_purchases: 124389642.0,
-                investment_trusts_total: 293094751.0,
-                investment_trusts_balance: -44315467.0,
-                business_cos_sales: 71217959.0,
-                business_cos_purchases: 63526641.0,
-                business_cos_total: 134744600.0,
-                business_cos_balance: -7691318.0,
-                other_cos_sales: 10745152.0,
-                other_cos_purchases: 15687836.0,
-                other_cos_total: 26432988.0,
-                other_cos_balance: 4942684.0,
-                insurance_cos_sales: 15926202.0,
-                insurance_cos_purchases: 9831555.0,
-                insurance_cos_total: 25757757.0,
-                insurance_cos_balance: -6094647.0,
-                city_bks_regional_bks_etc_sales: 10606789.0,
-                city_bks_regional_bks_etc_purchases: 8843871.0,
-                city_bks_regional_bks_etc_total: 19450660.0,
-                city_bks_regional_bks_etc_balance: -1762918.0,
-                trust_banks_sales: 292932297.0,
-                trust_banks_purchases: 245322795.0,
-                trust_banks_total: 538255092.0,
-                trust_banks_balance: -47609502.0,
-                other_financial_institutions_sales: 22410692.0,
-                other_financial_institutions_purchases: 21764485.0,
-                other_financial_institutions_total: 44175177.0,
-                other_financial_institutions_balance: -646207.0,
+                proprietary_sales: "1311271004.0".parse().unwrap(),
+                proprietary_purchases: "1453326508.0".parse().unwrap(),
+                proprietary_total: "2764597512.0".parse().unwrap(),
+                proprietary_balance: "142055504.0".parse().unwrap(),
+                brokerage_sales: "7165529005.0".parse().unwrap(),
+                brokerage_purchases: "7030019854.0".parse().unwrap(),
+                brokerage_total: "14195548859.0".parse().unwrap(),
+                brokerage_balance: "-135509151.0".parse().unwrap(),
+                total_sales: "8476800009.0".parse().unwrap(),
+                total_purchases: "8483346362.0".parse().unwrap(),
+                total_total: "16960146371.0".parse().unwrap(),
+                total_balance: "6546353.0".parse().unwrap(),
+                individuals_sales: "1401711615.0".parse().unwrap(),
+                individuals_purchases: "1161801155.0".parse().unwrap(),
+                individuals_total: "2563512770.0".parse().unwrap(),
+                individuals_balance: "-239910460.0".parse().unwrap(),
+                foreigners_sales: "5094891735.0".parse().unwrap(),
+                foreigners_purchases: "5317151774.0".parse().unwrap(),
+                foreigners_total: "10412043509.0".parse().unwrap(),
+                foreigners_balance: "222260039.0".parse().unwrap(),
+                securities_cos_sales: "76381455.0".parse().unwrap(),
+                securities_cos_purchases: "61700100.0".parse().unwrap(),
+                securities_cos_total: "138081555.0".parse().unwrap(),
+                securities_cos_balance: "-14681355.0".parse().unwrap(),
+                investment_trusts_sales: "168705109.0".parse().unwrap(),
+                investment_trusts_purchases: "124389642.0".parse().unwrap(),
+                investment_trusts_total: "293094751.0".parse().unwrap(),
+                investment_trusts_balance: "-44315467.0".parse().unwrap(),
+                business_cos_sales: "71217959.0".parse().unwrap(),
+                business_cos_purchases: "63526641.0".parse().unwrap(),
+                business_cos_total: "134744600.0".parse().unwrap(),
+                business_cos_balance: "-7691318.0".parse().unwrap(),
+                other_cos_sales: "10745152.0".parse().unwrap(),
+                other_cos_purchases: "15687836.0".parse().unwrap(),
+                other_cos_total: "26432988.0".parse().unwrap(),
+                other_cos_balance: "4942684.0".parse().unwrap(),
+                insurance_cos_sales: "15926202.0".parse().unwrap(),
+                insurance_cos_purchases: "9831555.0".parse().unwrap(),
+                insurance_cos_total: "25757757.0".parse().unwrap(),
+                insurance_cos_balance: "-6094647.0".parse().unwrap(),
+                city_bks_regional_bks_etc_sales: "10606789.0".parse().unwrap(),
+                city_bks_regional_bks_etc_purchases: "8843871.0".parse().unwrap(),
+                city_bks_regional_bks_etc_total: "19450660.0".parse().unwrap(),
+                city_bks_regional_bks_etc_balance: "-1762918.0".parse().unwrap(),
+                trust_banks_sales: "292932297.0".parse().unwrap(),
+                trust_banks_purchases: "245322795.0".parse().unwrap(),
+                trust_banks_total: "538255092.0".parse().unwrap(),
+                trust_banks_balance: "-47609502.0".parse().unwrap(),
+                other_financial_institutions_sales: "22410692.0".parse().unwrap(),
+                other_financial_institutions_purchases: "21764485.0".parse().unwrap(),
+                other_financial_institutions_total: "44175177.0".parse().unwrap(),
+                other_financial_institutions_balance: "-646207.0".parse().unwrap(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -1,11 +1,25 @@
 //! Trading Calendar API.
 
+use jquants_api_client_derive::ResponseAccessors;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    shared::{traits::builder::JQuantsBuilder, types::holiday_division::HolidayDivision},
+    shared::{
+        traits::{
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            request_preview::RequestPreview,
+        },
+        types::{
+            date_time::ResponseDate,
+            holiday_division::HolidayDivision,
+            query_date::{IntoQueryDate, QueryDate},
+        },
+        validation,
+    },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Trading Calendar API.
 #[derive(Clone, Serialize)]
@@ -18,10 +32,14 @@ pub struct TradingCalendarBuilder {
     holiday_division: Option<HolidayDivision>,
     /// Starting point of data period (e.g., "20210901" or "2021-09-01")
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g., "20210907" or "2021-09-07")
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
+}
+
+impl RequestPreview for TradingCalendarBuilder {
+    const PATH: &'static str = "markets/trading_calendar";
 }
 
 impl JQuantsBuilder<TradingCalendarResponse> for TradingCalendarBuilder {
@@ -29,11 +47,23 @@ impl JQuantsBuilder<TradingCalendarResponse> for TradingCalendarBuilder {
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(&self) -> Result<TradingCalendarResponse, crate::JQuantsError> {
-        self.client
-            .inner
-            .get("markets/trading_calendar", self)
-            .await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<TradingCalendarResponse> for TradingCalendarBuilder {
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<Output = Result<TradingCalendarResponse, crate::JQuantsError>> + Send
+    {
+        self.send_ref()
     }
 }
 
@@ -55,14 +85,14 @@ impl TradingCalendarBuilder {
     }
 
     /// Set starting point of data period (e.g., "20210901" or "2021-09-01")
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g., "20210907" or "2021-09-07")
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 }
@@ -80,27 +110,47 @@ pub trait TradingCalendarApi: JQuantsPlanClient {
 /// Trading Calendar response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/trading_calendar)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TradingCalendarResponse {
     /// List of trading calendar data
     pub trading_calendar: Vec<TradingCalendarItem>,
 }
 
+impl TradingCalendarResponse {
+    /// Get the dates except non-business days (YYYY-MM-DD).
+    ///
+    /// Half-day trading days and holiday trading days are included.
+    pub fn trading_dates(&self) -> Vec<ResponseDate> {
+        self.trading_calendar
+            .iter()
+            .filter(|item| item.holiday_division != HolidayDivision::NonBusinessDay)
+            .map(|item| &item.date)
+            .cloned()
+            .collect()
+    }
+}
+
 /// Represents a single trading calendar data.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct TradingCalendarItem {
     /// Trade date (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Holiday division
     #[serde(rename = "HolidayDivision")]
     pub holiday_division: HolidayDivision,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_trading_calendar_response() {
@@ -116,10 +166,14 @@ mod tests {
         "#;
 
         let response: TradingCalendarResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TradingCalendarResponse {
             trading_calendar: vec![TradingCalendarItem {
-                date: "2015-04-01".to_string(),
+                date: "2015-04-01".parse().unwrap(),
                 holiday_division: HolidayDivision::BusinessDay,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
         };
 
@@ -144,15 +198,21 @@ mod tests {
         "#;
 
         let response: TradingCalendarResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TradingCalendarResponse {
             trading_calendar: vec![
                 TradingCalendarItem {
-                    date: "2015-03-25".to_string(),
+                    date: "2015-03-25".parse().unwrap(),
                     holiday_division: HolidayDivision::HalfDayTrading,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 TradingCalendarItem {
-                    date: "2015-04-01".to_string(),
+                    date: "2015-04-01".parse().unwrap(),
                     holiday_division: HolidayDivision::BusinessDay,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
         };
@@ -160,6 +220,44 @@ mod tests {
         pretty_assertions::assert_eq!(response, expected_response);
     }
 
+    #[test]
+    fn test_trading_dates() {
+        let response = TradingCalendarResponse {
+            trading_calendar: vec![
+                TradingCalendarItem {
+                    date: "2024-01-01".parse().unwrap(),
+                    holiday_division: HolidayDivision::NonBusinessDay,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
+                },
+                TradingCalendarItem {
+                    date: "2024-01-04".parse().unwrap(),
+                    holiday_division: HolidayDivision::HalfDayTrading,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
+                },
+                TradingCalendarItem {
+                    date: "2024-01-05".parse().unwrap(),
+                    holiday_division: HolidayDivision::BusinessDay,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
+                },
+                TradingCalendarItem {
+                    date: "2024-01-08".parse().unwrap(),
+                    holiday_division: HolidayDivision::NonBusinessDaysWithHolidayTrading,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
+                },
+            ],
+        };
+
+        assert_eq!(
+            response.trading_dates(),
+            ["2024-01-04", "2024-01-05", "2024-01-08"]
+                .map(|date| date.parse::<ResponseDate>().unwrap())
+        );
+    }
+
     #[test]
     fn test_deserialize_trading_calendar_response_no_data() {
         let json = r#"
@@ -169,6 +267,8 @@ mod tests {
         "#;
 
         let response: TradingCalendarResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = TradingCalendarResponse {
             trading_calendar: vec![],
         };
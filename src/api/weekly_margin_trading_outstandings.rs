@@ -1,17 +1,31 @@
 //! Margin Trading Outstandings API.
 
+use jquants_api_client_derive::{Paginated, ResponseAccessors};
 use serde::{Deserialize, Serialize};
 
 use super::{
     shared::{
         traits::{
-            builder::JQuantsBuilder,
-            pagination::{HasPaginationKey, MergePage, Paginatable},
+            builder::{JQuantsBuilder, JQuantsSendBuilder},
+            code_batch::CodeBatchable,
+            date_range::DateRangeShardable,
+            pagination::Paginatable,
+            request_preview::RequestPreview,
         },
-        types::issue_type::IssueType,
+        types::{
+            date_time::ResponseDate,
+            issue_code::{
+                serialize_issue_code_param, IntoIssueCode, IssueCode, ParseIssueCodeError,
+            },
+            issue_type::IssueType,
+            query_date::{IntoQueryDate, QueryDate},
+        },
+        validation,
     },
     JQuantsApiClient, JQuantsPlanClient,
 };
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
 
 /// Builder for Margin Trading Outstandings API.
 #[derive(Clone, Serialize)]
@@ -20,23 +34,30 @@ pub struct WeeklyMarginTradingOutstandingsBuilder {
     client: JQuantsApiClient,
 
     /// Issue code (e.g. 27800 or 2780)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    code: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_issue_code_param"
+    )]
+    code: Option<Result<IssueCode, ParseIssueCodeError>>,
     /// Date of data (e.g. 20210907 or 2021-09-07)
     #[serde(skip_serializing_if = "Option::is_none")]
-    date: Option<String>,
+    date: Option<QueryDate>,
     /// Starting point of data period (e.g. 20210901 or 2021-09-01)
     #[serde(skip_serializing_if = "Option::is_none")]
-    from: Option<String>,
+    from: Option<QueryDate>,
     /// End point of data period (e.g. 20210907 or 2021-09-07)
     #[serde(skip_serializing_if = "Option::is_none")]
-    to: Option<String>,
+    to: Option<QueryDate>,
 
     /// Pagination key.
     #[serde(skip_serializing_if = "Option::is_none")]
     pagination_key: Option<String>,
 }
 
+impl RequestPreview for WeeklyMarginTradingOutstandingsBuilder {
+    const PATH: &'static str = "markets/weekly_margin_interest";
+}
+
 impl JQuantsBuilder<WeeklyMarginTradingOutstandingsResponse>
     for WeeklyMarginTradingOutstandingsBuilder
 {
@@ -44,13 +65,30 @@ impl JQuantsBuilder<WeeklyMarginTradingOutstandingsResponse>
         self.send_ref().await
     }
 
+    fn validate(&self) -> Result<(), crate::JQuantsError> {
+        validation::require_any(&[("code", self.code.is_some()), ("date", self.date.is_some())])?;
+        validation::date_excludes_range(self.date.as_ref(), self.from.as_ref(), self.to.as_ref())?;
+        validation::range_is_ordered(self.from.as_ref(), self.to.as_ref())?;
+        Ok(())
+    }
+
     async fn send_ref(
         &self,
     ) -> Result<WeeklyMarginTradingOutstandingsResponse, crate::JQuantsError> {
-        self.client
-            .inner
-            .get("markets/weekly_margin_interest", self)
-            .await
+        self.validate()?;
+        self.client.inner.get(Self::PATH, self).await
+    }
+}
+
+impl JQuantsSendBuilder<WeeklyMarginTradingOutstandingsResponse>
+    for WeeklyMarginTradingOutstandingsBuilder
+{
+    fn send_ref_spawnable(
+        &self,
+    ) -> impl std::future::Future<
+        Output = Result<WeeklyMarginTradingOutstandingsResponse, crate::JQuantsError>,
+    > + Send {
+        self.send_ref()
     }
 }
 
@@ -63,6 +101,25 @@ impl Paginatable<WeeklyMarginTradingOutstandingsResponse>
     }
 }
 
+impl DateRangeShardable<WeeklyMarginTradingOutstandingsResponse>
+    for WeeklyMarginTradingOutstandingsBuilder
+{
+    fn date_range(mut self, from: impl IntoQueryDate, to: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
+        self.to = Some(to.into_query_date());
+        self
+    }
+}
+
+impl CodeBatchable<WeeklyMarginTradingOutstandingsResponse>
+    for WeeklyMarginTradingOutstandingsBuilder
+{
+    fn with_code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
+        self
+    }
+}
+
 impl WeeklyMarginTradingOutstandingsBuilder {
     /// Create a new builder.
     pub(crate) fn new(client: JQuantsApiClient) -> Self {
@@ -77,26 +134,26 @@ impl WeeklyMarginTradingOutstandingsBuilder {
     }
 
     /// Set issue code (e.g. 27800 or 2780)
-    pub fn code(mut self, code: impl Into<String>) -> Self {
-        self.code = Some(code.into());
+    pub fn code(mut self, code: impl IntoIssueCode) -> Self {
+        self.code = Some(code.into_issue_code());
         self
     }
 
     /// Set date of data (e.g. 20210907 or 2021-09-07)
-    pub fn date(mut self, date: impl Into<String>) -> Self {
-        self.date = Some(date.into());
+    pub fn date(mut self, date: impl IntoQueryDate) -> Self {
+        self.date = Some(date.into_query_date());
         self
     }
 
     /// Set starting point of data period (e.g. 20210901 or 2021-09-01)
-    pub fn from(mut self, from: impl Into<String>) -> Self {
-        self.from = Some(from.into());
+    pub fn from(mut self, from: impl IntoQueryDate) -> Self {
+        self.from = Some(from.into_query_date());
         self
     }
 
     /// Set end point of data period (e.g. 20210907 or 2021-09-07)
-    pub fn to(mut self, to: impl Into<String>) -> Self {
-        self.to = Some(to.into());
+    pub fn to(mut self, to: impl IntoQueryDate) -> Self {
+        self.to = Some(to.into_query_date());
         self
     }
 }
@@ -114,47 +171,26 @@ pub trait WeeklyMarginTradingOutstandingsApi: JQuantsPlanClient {
 /// Margin Trading Outstandings response.
 ///
 /// See: [API Reference](https://jpx.gitbook.io/j-quants-en/api-reference/weekly_margin_interest)
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Paginated)]
 pub struct WeeklyMarginTradingOutstandingsResponse {
     /// List of weekly margin trading outstanding
+    #[paginated(items)]
     pub weekly_margin_interest: Vec<WeeklyMarginTradingOutstandingItem>,
     /// Pagination key for fetching next set of data
+    #[paginated(pagination_key)]
     pub pagination_key: Option<String>,
 }
 
-impl HasPaginationKey for WeeklyMarginTradingOutstandingsResponse {
-    fn get_pagination_key(&self) -> Option<&str> {
-        self.pagination_key.as_deref()
-    }
-}
-
-impl MergePage for WeeklyMarginTradingOutstandingsResponse {
-    fn merge_page(
-        page: Result<Vec<Self>, crate::JQuantsError>,
-    ) -> Result<Self, crate::JQuantsError> {
-        let mut page = page?;
-        let mut merged = page.pop().unwrap();
-        for p in page {
-            merged
-                .weekly_margin_interest
-                .extend(p.weekly_margin_interest);
-        }
-        merged.pagination_key = None;
-
-        Ok(merged)
-    }
-}
-
 /// Represents a single weekly margin trading outstanding.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ResponseAccessors)]
 pub struct WeeklyMarginTradingOutstandingItem {
     /// Record Date (YYYY-MM-DD)
     #[serde(rename = "Date")]
-    pub date: String,
+    pub date: ResponseDate,
 
     /// Issue code
     #[serde(rename = "Code")]
-    pub code: String,
+    pub code: IssueCode,
 
     /// Total margin trading (negotiable and standardized) weekend short positions
     #[serde(rename = "ShortMarginTradeVolume")]
@@ -183,11 +219,17 @@ pub struct WeeklyMarginTradingOutstandingItem {
     /// Issue Classifications
     #[serde(rename = "IssueType")]
     pub issue_type: IssueType,
+
+    /// Fields not known to this crate (e.g. fields newly added to the API)
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::shared::test_utils::assert_round_trip;
 
     #[test]
     fn test_deserialize_weekly_margin_trading_outstandings_response() {
@@ -211,10 +253,12 @@ mod tests {
         "#;
 
         let response: WeeklyMarginTradingOutstandingsResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = WeeklyMarginTradingOutstandingsResponse {
             weekly_margin_interest: vec![WeeklyMarginTradingOutstandingItem {
-                date: "2023-02-17".to_string(),
-                code: "13010".to_string(),
+                date: "2023-02-17".parse().unwrap(),
+                code: "13010".parse().unwrap(),
                 short_margin_trade_volume: 4100.0,
                 long_margin_trade_volume: 27600.0,
                 short_negotiable_margin_trade_volume: 1300.0,
@@ -222,6 +266,8 @@ mod tests {
                 short_standardized_margin_trade_volume: 2800.0,
                 long_standardized_margin_trade_volume: 20000.0,
                 issue_type: IssueType::Loan, // Assuming "2" corresponds to Loan
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: Some("value1.value2.".to_string()),
         };
@@ -250,10 +296,12 @@ mod tests {
         "#;
 
         let response: WeeklyMarginTradingOutstandingsResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = WeeklyMarginTradingOutstandingsResponse {
             weekly_margin_interest: vec![WeeklyMarginTradingOutstandingItem {
-                date: "2023-02-17".to_string(),
-                code: "13010".to_string(),
+                date: "2023-02-17".parse().unwrap(),
+                code: "13010".parse().unwrap(),
                 short_margin_trade_volume: 4100.0,
                 long_margin_trade_volume: 27600.0,
                 short_negotiable_margin_trade_volume: 1300.0,
@@ -261,6 +309,8 @@ mod tests {
                 short_standardized_margin_trade_volume: 2800.0,
                 long_standardized_margin_trade_volume: 20000.0,
                 issue_type: IssueType::Loan, // Assuming "2" corresponds to Loan
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             pagination_key: None,
         };
@@ -301,11 +351,13 @@ mod tests {
         "#;
 
         let response: WeeklyMarginTradingOutstandingsResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = WeeklyMarginTradingOutstandingsResponse {
             weekly_margin_interest: vec![
                 WeeklyMarginTradingOutstandingItem {
-                    date: "2023-02-10".to_string(),
-                    code: "13010".to_string(),
+                    date: "2023-02-10".parse().unwrap(),
+                    code: "13010".parse().unwrap(),
                     short_margin_trade_volume: 4000.0,
                     long_margin_trade_volume: 27000.0,
                     short_negotiable_margin_trade_volume: 1200.0,
@@ -313,10 +365,12 @@ mod tests {
                     short_standardized_margin_trade_volume: 2800.0,
                     long_standardized_margin_trade_volume: 19500.0,
                     issue_type: IssueType::Loan, // Assuming "2" corresponds to Loan
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
                 WeeklyMarginTradingOutstandingItem {
-                    date: "2023-02-17".to_string(),
-                    code: "13010".to_string(),
+                    date: "2023-02-17".parse().unwrap(),
+                    code: "13010".parse().unwrap(),
                     short_margin_trade_volume: 4100.0,
                     long_margin_trade_volume: 27600.0,
                     short_negotiable_margin_trade_volume: 1300.0,
@@ -324,6 +378,8 @@ mod tests {
                     short_standardized_margin_trade_volume: 2800.0,
                     long_standardized_margin_trade_volume: 20000.0,
                     issue_type: IssueType::Loan, // Assuming "2" corresponds to Loan
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
                 },
             ],
             pagination_key: Some("value1.value2.".to_string()),
@@ -341,6 +397,8 @@ mod tests {
         "#;
 
         let response: WeeklyMarginTradingOutstandingsResponse = serde_json::from_str(json).unwrap();
+
+        assert_round_trip(&response);
         let expected_response = WeeklyMarginTradingOutstandingsResponse {
             weekly_margin_interest: vec![],
             pagination_key: None,
@@ -1,4 +1,27 @@
 //! J-Quants API client module.
+
+/// Implement the API traits of a plan client and [`QueryApi`](crate::api::query::QueryApi).
+///
+/// A query is available exactly when the client implements the API trait of its endpoint.
+/// [`ListedIssueInfoApi`](crate::ListedIssueInfoApi) and [`DailyStockPricesApi`](crate::DailyStockPricesApi)
+/// are required by every plan and implemented separately for their plan-specific responses.
+macro_rules! impl_plan_apis {
+    ($client:ty { $($api:ident),* $(,)? }) => {
+        $(impl $api for $client {})*
+
+        impl $crate::api::query::QueryApi for $client {
+            fn is_available(&self, query: &$crate::api::query::Query) -> bool {
+                matches!(
+                    query,
+                    $crate::api::query::query_pattern!(ListedIssueInfoApi)
+                        | $crate::api::query::query_pattern!(DailyStockPricesApi)
+                        $(| $crate::api::query::query_pattern!($api))*
+                )
+            }
+        }
+    };
+}
+
 pub mod free_plan_client;
 pub mod light_plan_client;
 pub mod premium_plan_client;
@@ -34,6 +34,7 @@ use crate::{
 ///     let response = client.get_daily_stock_prices().fetch_all().await.unwrap();
 ///     let response = client.get_daily_stock_prices().fetch_all_and_merge().await.unwrap();
 ///     let stream = client.get_daily_stock_prices().fetch_pages_stream();
+///     let stream = client.get_daily_stock_prices().fetch_items_stream();
 ///
 ///     // Get trading calendar.
 ///     let response = client.get_trading_calendar().send().await.unwrap();
@@ -78,8 +79,8 @@ impl DailyStockPricesApi for JQuantsFreePlanClient {
     type Response = DailyStockPricesFreePlanResponse;
 }
 
-impl TradingCalendarApi for JQuantsFreePlanClient {}
-
-impl FinancialStatementsApi for JQuantsFreePlanClient {}
-
-impl EarningsCalendarApi for JQuantsFreePlanClient {}
+impl_plan_apis!(JQuantsFreePlanClient {
+    TradingCalendarApi,
+    FinancialStatementsApi,
+    EarningsCalendarApi,
+});
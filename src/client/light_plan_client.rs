@@ -35,6 +35,7 @@ use crate::{
 ///     let response = client.get_daily_stock_prices().fetch_all().await.unwrap();
 ///     let response = client.get_daily_stock_prices().fetch_all_and_merge().await.unwrap();
 ///     let stream = client.get_daily_stock_prices().fetch_pages_stream();
+///     let stream = client.get_daily_stock_prices().fetch_items_stream();
 ///
 ///     // Get trading by investor type.
 ///     let response = client.get_trading_by_investor_type().send().await.unwrap();
@@ -95,12 +96,10 @@ impl DailyStockPricesApi for JQuantsLightPlanClient {
     type Response = DailyStockPricesLightPlanResponse;
 }
 
-impl TradingByInvestorTypeApi for JQuantsLightPlanClient {}
-
-impl TradingCalendarApi for JQuantsLightPlanClient {}
-
-impl TopixPricesApi for JQuantsLightPlanClient {}
-
-impl FinancialStatementsApi for JQuantsLightPlanClient {}
-
-impl EarningsCalendarApi for JQuantsLightPlanClient {}
+impl_plan_apis!(JQuantsLightPlanClient {
+    TradingByInvestorTypeApi,
+    TradingCalendarApi,
+    TopixPricesApi,
+    FinancialStatementsApi,
+    EarningsCalendarApi,
+});
@@ -43,6 +43,7 @@ use crate::{
 ///     let response = client.get_daily_stock_prices().fetch_all().await.unwrap();
 ///     let response = client.get_daily_stock_prices().fetch_all_and_merge().await.unwrap();
 ///     let stream = client.get_daily_stock_prices().fetch_pages_stream();
+///     let stream = client.get_daily_stock_prices().fetch_items_stream();
 ///
 ///     // Get morning session stock prices.
 ///     let response = client.morning_session_stock_prices().send().await.unwrap();
@@ -175,32 +176,20 @@ impl DailyStockPricesApi for JQuantsPremiumPlanClient {
     type Response = DailyStockPricesPremiumPlanResponse;
 }
 
-impl MorningSessionStockPricesApi for JQuantsPremiumPlanClient {}
-
-impl TradingByInvestorTypeApi for JQuantsPremiumPlanClient {}
-
-impl WeeklyMarginTradingOutstandingsApi for JQuantsPremiumPlanClient {}
-
-impl ShortSaleBySectorApi for JQuantsPremiumPlanClient {}
-
-impl BreakdownTradingDataApi for JQuantsPremiumPlanClient {}
-
-impl TradingCalendarApi for JQuantsPremiumPlanClient {}
-
-impl IndicesApi for JQuantsPremiumPlanClient {}
-
-impl TopixPricesApi for JQuantsPremiumPlanClient {}
-
-impl FinancialStatementsApi for JQuantsPremiumPlanClient {}
-
-impl FinancialStatementDetailsApi for JQuantsPremiumPlanClient {}
-
-impl CashDividendDataApi for JQuantsPremiumPlanClient {}
-
-impl EarningsCalendarApi for JQuantsPremiumPlanClient {}
-
-impl IndexOptionPricesApi for JQuantsPremiumPlanClient {}
-
-impl FuturesPricesApi for JQuantsPremiumPlanClient {}
-
-impl OptionsPricesApi for JQuantsPremiumPlanClient {}
+impl_plan_apis!(JQuantsPremiumPlanClient {
+    MorningSessionStockPricesApi,
+    TradingByInvestorTypeApi,
+    WeeklyMarginTradingOutstandingsApi,
+    ShortSaleBySectorApi,
+    BreakdownTradingDataApi,
+    TradingCalendarApi,
+    IndicesApi,
+    TopixPricesApi,
+    FinancialStatementsApi,
+    FinancialStatementDetailsApi,
+    CashDividendDataApi,
+    EarningsCalendarApi,
+    IndexOptionPricesApi,
+    FuturesPricesApi,
+    OptionsPricesApi,
+});
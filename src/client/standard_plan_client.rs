@@ -38,6 +38,7 @@ use crate::{
 ///     let response = client.get_daily_stock_prices().fetch_all().await.unwrap();
 ///     let response = client.get_daily_stock_prices().fetch_all_and_merge().await.unwrap();
 ///     let stream = client.get_daily_stock_prices().fetch_pages_stream();
+///     let stream = client.get_daily_stock_prices().fetch_items_stream();
 ///
 ///     // Get trading by investor type.
 ///     let response = client.get_trading_by_investor_type().send().await.unwrap();
@@ -130,20 +131,14 @@ impl DailyStockPricesApi for JQuantsStandardPlanClient {
     type Response = DailyStockPricesStandardPlanResponse;
 }
 
-impl TradingByInvestorTypeApi for JQuantsStandardPlanClient {}
-
-impl WeeklyMarginTradingOutstandingsApi for JQuantsStandardPlanClient {}
-
-impl ShortSaleBySectorApi for JQuantsStandardPlanClient {}
-
-impl TradingCalendarApi for JQuantsStandardPlanClient {}
-
-impl IndicesApi for JQuantsStandardPlanClient {}
-
-impl TopixPricesApi for JQuantsStandardPlanClient {}
-
-impl FinancialStatementsApi for JQuantsStandardPlanClient {}
-
-impl EarningsCalendarApi for JQuantsStandardPlanClient {}
-
-impl IndexOptionPricesApi for JQuantsStandardPlanClient {}
+impl_plan_apis!(JQuantsStandardPlanClient {
+    TradingByInvestorTypeApi,
+    WeeklyMarginTradingOutstandingsApi,
+    ShortSaleBySectorApi,
+    TradingCalendarApi,
+    IndicesApi,
+    TopixPricesApi,
+    FinancialStatementsApi,
+    EarningsCalendarApi,
+    IndexOptionPricesApi,
+});
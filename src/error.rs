@@ -3,7 +3,9 @@
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::api::shared::responses::error_response::JQuantsErrorResponse;
+use crate::api::shared::{
+    responses::error_response::JQuantsErrorResponse, unknown_variants::UnknownVariantReport,
+};
 
 /// Common Error response
 #[derive(Debug, Deserialize)]
@@ -61,15 +63,42 @@ pub enum JQuantsError {
     },
 
     /// Response format is not JQuants error response.
-    #[error("Invalid response format. Status code: {status_code}, Response body: {body}")]
+    ///
+    /// The body is truncated in `Display`, but the full body is kept in `body`.
+    #[error(
+        "Invalid response format. Status code: {status_code}, Error: {source}, Response body: {}",
+        truncate_body(.body)
+    )]
     InvalidResponseFormat {
         /// HTTP status code
         status_code: u16,
 
         /// Response body
         body: String,
+
+        /// Where and why the response body failed to be deserialized
+        source: DeserializeError,
     },
 
+    /// The response contains unknown variants of enums in the strict mode.
+    ///
+    /// See [`crate::UnknownVariantMode::Strict`].
+    #[error("Unknown variants in response: {0}")]
+    UnknownVariants(UnknownVariantReport),
+
+    /// The pagination checkpoint does not match the builder.
+    #[error("Invalid pagination checkpoint: {0}")]
+    InvalidCheckpoint(String),
+
+    /// A query parameter such as a date or an issue code is invalid. No request is sent.
+    #[error("{0}")]
+    InvalidQueryParameter(String),
+
+    /// The combination of query parameters is invalid, e.g. `from` is after `to`
+    /// or a required parameter is missing. No request is sent.
+    #[error("Invalid parameters: {0}")]
+    InvalidParameters(String),
+
     /// HTTP request error
     #[error("HTTP request error: {0}")]
     ReqwestError(#[from] reqwest::Error),
@@ -78,3 +107,82 @@ pub enum JQuantsError {
     #[error("BUG: {0}. Please report this issue.")]
     BugError(String),
 }
+
+/// Describes where and why a response body failed to be deserialized.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{path}: {message}")]
+pub struct DeserializeError {
+    /// JSON path to the value that failed (e.g. `statements[312].ForecastProfit`).
+    ///
+    /// "." means the root of the body.
+    pub path: String,
+
+    /// Error message of serde (e.g. `invalid type: ... at line 1 column 42`).
+    pub message: String,
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for DeserializeError {
+    fn from(error: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Self {
+            path: error.path().to_string(),
+            message: error.inner().to_string(),
+        }
+    }
+}
+
+/// Maximum number of characters of a response body shown in error messages.
+const MAX_DISPLAYED_BODY_CHARS: usize = 1000;
+
+/// Truncate a response body for error messages.
+fn truncate_body(body: &str) -> String {
+    match body.char_indices().nth(MAX_DISPLAYED_BODY_CHARS) {
+        Some((end, _)) => format!("{}... ({} bytes in total)", &body[..end], body.len()),
+        None => body.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_response_format_truncates_body() {
+        let body = format!(r#"{{"statements": [{}]}}"#, "0".repeat(2000));
+        let error = JQuantsError::InvalidResponseFormat {
+            status_code: 200,
+            body: body.clone(),
+            source: DeserializeError {
+                path: "statements[0]".to_string(),
+                message: "invalid type".to_string(),
+            },
+        };
+
+        let message = error.to_string();
+        assert!(message.starts_with(
+            r#"Invalid response format. Status code: 200, Error: statements[0]: invalid type, Response body: {"statements": [000"#
+        ));
+        assert!(message.ends_with(&format!("... ({} bytes in total)", body.len())));
+        assert!(message.len() < body.len());
+
+        match error {
+            JQuantsError::InvalidResponseFormat { body: full, .. } => assert_eq!(full, body),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_response_format_keeps_short_body() {
+        let error = JQuantsError::InvalidResponseFormat {
+            status_code: 502,
+            body: "Bad Gateway".to_string(),
+            source: DeserializeError {
+                path: ".".to_string(),
+                message: "expected value at line 1 column 1".to_string(),
+            },
+        };
+        assert_eq!(
+            error.to_string(),
+            "Invalid response format. Status code: 502, Error: .: expected value at line 1 column 1, Response body: Bad Gateway"
+        );
+    }
+}
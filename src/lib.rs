@@ -1,5 +1,8 @@
 //! This crate provides an API client for JQuants.
 
+// Allow the derive macros to refer to this crate as `::jquants_api_client` from inside.
+extern crate self as jquants_api_client;
+
 pub mod api;
 pub mod client;
 pub mod error;
@@ -18,15 +21,21 @@ pub use api::indicies::*;
 pub use api::listed_issue_info::*;
 pub use api::morning_session_stock_prices::*;
 pub use api::options_prices::*;
+pub use api::query::*;
 pub use api::shared::{
-    auth::{id_token::*, refresh_token::*},
+    auth::{id_token::*, refresh_token::*, secret::*, token_status::*},
     responses::error_response::*,
-    traits::{builder::*, pagination::*},
+    traits::{
+        builder::*, code_batch::*, date_iter::*, date_range::*, pagination::*, request_preview::*,
+    },
     types::{
-        accounting_period::*, dividend::*, futures_code::*, holiday_division::*, index_code::*,
+        accounting_period::*, dividend::*, extra_fields::*, financial_figure::*, fiscal_quarter::*,
+        fiscal_year_end::*, futures_code::*, holiday_division::*, index_code::*, issue_code::*,
         issue_type::*, margin_code::MarginCode, market_code::*, options_code::*, price_limit::*,
-        section_name::*, sector17_code::*, sector33_code::*, type_of_document::*,
+        query_date::*, section_name::*, sector17_code::*, sector33_code::*, type_of_document::*,
+        xbrl_statements::*,
     },
+    unknown_variants::{UnknownVariant, UnknownVariantMode, UnknownVariantReport},
 };
 pub use api::short_sale_by_sector::*;
 pub use api::topic_prices::*;
@@ -38,4 +47,5 @@ pub use client::{
     free_plan_client::JQuantsFreePlanClient, light_plan_client::JQuantsLightPlanClient,
     premium_plan_client::JQuantsPremiumPlanClient, standard_plan_client::JQuantsStandardPlanClient,
 };
-pub use error::JQuantsError;
+pub use error::{DeserializeError, JQuantsError};
+pub use jquants_api_client_derive::Paginated;
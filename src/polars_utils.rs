@@ -7,6 +7,10 @@ use polars::{
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::api::shared::types::decimal::ResponseDecimal;
+#[cfg(feature = "extra-fields")]
+use crate::api::shared::types::extra_fields::ExtraFields;
+
 #[derive(Error, Debug)]
 pub enum IntoPolarsError {
     #[error("Error converting to Polars: {0}")]
@@ -40,6 +44,60 @@ pub fn build_categorical_series<T: Serialize>(
     Ok(builder.finish().into_series())
 }
 
+/// Builds a `Float64` column of decimal numbers.
+#[cfg(not(feature = "decimal"))]
+pub fn build_decimal_column<T: Into<Option<ResponseDecimal>>>(
+    name: &str,
+    values: Vec<T>,
+) -> Column {
+    let values: Vec<Option<f64>> = values.into_iter().map(Into::into).collect();
+    Column::new(name.into(), values)
+}
+
+/// Builds a `Decimal` column of decimal numbers.
+///
+/// The numbers are converted from their shortest representation, and a number that `Decimal`
+/// cannot represent (e.g. NaN) becomes a missing value.
+#[cfg(feature = "decimal")]
+pub fn build_decimal_column<T: Into<Option<ResponseDecimal>>>(
+    name: &str,
+    values: Vec<T>,
+) -> Column {
+    use crate::api::shared::types::decimal::to_decimal;
+
+    let values = values
+        .into_iter()
+        .map(|value| value.into().and_then(|value| to_decimal(&value).ok()))
+        .collect();
+    decimal_column(name, values)
+}
+
+/// Builds a `Decimal` column.
+///
+/// All values are rescaled to the largest scale of the values, so no digit is lost.
+#[cfg(feature = "decimal")]
+fn decimal_column(name: &str, values: Vec<Option<rust_decimal::Decimal>>) -> Column {
+    use polars::prelude::{Int128Chunked, NewChunkedArray};
+
+    let scale = values
+        .iter()
+        .flatten()
+        .map(|value| value.scale())
+        .max()
+        .unwrap_or(0);
+    let mantissas = values.into_iter().map(|value| {
+        value.map(|mut value| {
+            value.rescale(scale);
+            value.mantissa()
+        })
+    });
+
+    Int128Chunked::from_iter_options(name.into(), mantissas)
+        .into_decimal_unchecked(None, scale as usize)
+        .into_series()
+        .into()
+}
+
 pub fn hashmap_list_to_columns(map_list: Vec<HashMap<String, String>>) -> Vec<Column> {
     let mut all_keys = BTreeSet::new();
     for map in &map_list {
@@ -88,6 +146,19 @@ pub fn hashmap_list_to_columns(map_list: Vec<HashMap<String, String>>) -> Vec<Co
                 columns.push(col);
                 continue;
             }
+            // With the `decimal` feature, numbers are parsed from the strings into `Decimal`.
+            #[cfg(feature = "decimal")]
+            ColumnType::Float => {
+                use crate::api::shared::types::decimal::parse_decimal;
+
+                let col_data = map_list
+                    .iter()
+                    .map(|m| m.get(key).and_then(|s| parse_decimal(s).ok()))
+                    .collect();
+                columns.push(decimal_column(key, col_data));
+                continue;
+            }
+            #[cfg(not(feature = "decimal"))]
             ColumnType::Float => {
                 let col_data: Vec<Option<f64>> = map_list
                     .iter()
@@ -112,3 +183,26 @@ pub fn hashmap_list_to_columns(map_list: Vec<HashMap<String, String>>) -> Vec<Co
 
     columns
 }
+
+/// Builds columns of the fields not known to this crate.
+///
+/// Values are converted into strings (JSON for arrays and objects), and the column types
+/// are inferred as in [`hashmap_list_to_columns`]. `null` becomes a missing value.
+#[cfg(feature = "extra-fields")]
+pub fn extra_fields_to_columns(extras: Vec<ExtraFields>) -> Vec<Column> {
+    let map_list = extras
+        .into_iter()
+        .map(|extra| {
+            extra
+                .into_iter()
+                .filter_map(|(key, value)| match value {
+                    serde_json::Value::Null => None,
+                    serde_json::Value::String(s) => Some((key, s)),
+                    value => Some((key, value.to_string())),
+                })
+                .collect()
+        })
+        .collect();
+
+    hashmap_list_to_columns(map_list)
+}